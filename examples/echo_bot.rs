@@ -0,0 +1,122 @@
+//! Echo Bot Example
+//!
+//! A multi-workspace bot: OAuth installs are saved to a file store, events
+//! arrive over Socket Mode, and replies go out through the Web API using the
+//! bot token installed in each event's workspace.
+//!
+//! Usage:
+//!   # Print the install URL, then exchange the `code` Slack redirects with
+//!   SLACK_CLIENT_ID=... cargo run --example echo_bot -- install
+//!   SLACK_CLIENT_ID=... SLACK_CLIENT_SECRET=... \
+//!   cargo run --example echo_bot -- install <code>
+//!
+//!   # Run the bot
+//!   SLACK_APP_TOKEN=xapp-your-token cargo run --example echo_bot
+
+use regex::Regex;
+use slack_rs::oauth::installation_store::file::FileInstallationStore;
+use slack_rs::oauth::{AuthorizeUrlGenerator, InstallationStore, OAuthV2Response};
+use slack_rs::socket_mode::{SocketModeApp, SocketModeClient};
+use slack_rs::web::AsyncWebClient;
+use std::sync::Arc;
+
+const STORE_DIR: &str = "./data/installations";
+
+fn env(name: &str) -> String {
+    std::env::var(name).unwrap_or_else(|_| panic!("{} environment variable must be set", name))
+}
+
+/// Prints the install URL, or completes an install from its OAuth `code`.
+async fn install(
+    store: &FileInstallationStore,
+    code: Option<String>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let client_id = env("SLACK_CLIENT_ID");
+
+    let Some(code) = code else {
+        let url = AuthorizeUrlGenerator::new(&client_id)
+            .scopes(vec![
+                "app_mentions:read".to_string(),
+                "channels:history".to_string(),
+                "chat:write".to_string(),
+            ])
+            .generate("echo-bot", None);
+        println!("Install the app: {}", url);
+        return Ok(());
+    };
+
+    let response = AsyncWebClient::builder()
+        .build()
+        .oauth_v2_access(Some(serde_json::json!({
+            "client_id": client_id,
+            "client_secret": env("SLACK_CLIENT_SECRET"),
+            "code": code,
+        })))
+        .await?;
+    let oauth: OAuthV2Response = serde_json::from_value(response.data)?;
+    let installation = oauth
+        .to_installation()
+        .ok_or("oauth.v2.access returned no installation")?;
+    store.save(installation).await?;
+    println!("Installed.");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let store = FileInstallationStore::new(STORE_DIR);
+
+    let mut args = std::env::args().skip(1);
+    if args.next().as_deref() == Some("install") {
+        return install(&store, args.next()).await;
+    }
+
+    let app = SocketModeApp::new(
+        SocketModeClient::new(env("SLACK_APP_TOKEN")),
+        Arc::new(store),
+        AsyncWebClient::builder().build(),
+    );
+
+    // Reply in a thread to every mention
+    app.on_event("app_mention", |event, client| async move {
+        let (Some(channel), Some(ts)) = (event.channel, event.ts) else {
+            return Ok(());
+        };
+        client
+            .post_message(&channel)
+            .text(format!("You said: {}", event.text.unwrap_or_default()))
+            .thread_ts(ts)
+            .execute()
+            .await?;
+        Ok(())
+    })
+    .await;
+
+    // Repeat `echo ...` messages back to the channel
+    let echo = Regex::new(r"^echo (.+)$")?;
+    let pattern = echo.clone();
+    app.on_message_matching(pattern, move |event, client| {
+        let echo = echo.clone();
+        async move {
+            if event.raw.get("bot_id").is_some() {
+                return Ok(());
+            }
+            let (Some(channel), Some(text)) = (event.channel, event.text) else {
+                return Ok(());
+            };
+            if let Some(captures) = echo.captures(&text) {
+                client
+                    .post_message(&channel)
+                    .text(&captures[1])
+                    .execute()
+                    .await?;
+            }
+            Ok(())
+        }
+    })
+    .await;
+
+    println!("Echo bot running. Press Ctrl+C to stop.");
+    app.run().await?;
+    Ok(())
+}
@@ -98,8 +98,9 @@ pub mod client;
 pub mod models;
 
 // Re-export main types
-pub use client::{AsyncAuditLogsClient, AuditLogsClient};
+pub use client::{AsyncAuditLogsClient, AuditLogsClient, AUDIT_LOGS_BASE_URL};
 pub use models::{
-    Actor, App, AuditLogsResponse, Channel, Context, Details, Entity, Location, LogEntry,
-    LogsResponse, ResponseMetadata, User,
+    Actor, App, AuditAction, AuditLogFilter, AuditLogsResponse, AuditSchema, Channel, Context,
+    ConversationPref, Details, Entity, Location, LogEntry, LogsResponse, ResourcePermission,
+    ResponseMetadata, RetentionPolicy, User, ADMIN_ACTIONS,
 };
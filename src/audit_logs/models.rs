@@ -3,6 +3,7 @@
 //! This module contains the data structures representing audit log entries
 //! and related metadata from Slack's Enterprise Grid Audit Logs API.
 
+use crate::error::SlackError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -73,14 +74,129 @@ pub struct Context {
     pub app: Option<App>,
 }
 
+/// Scopes granted on a resource, as listed in `details.permissions`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ResourcePermission {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+}
+
+/// A channel message retention policy
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct RetentionPolicy {
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub policy_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_days: Option<i64>,
+}
+
+/// Who a channel posting or threading preference applies to
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ConversationPref {
+    /// Roles, e.g. `admin` or `ra` (regular accounts)
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub pref_type: Option<Vec<String>>,
+    /// Individual user IDs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<Vec<String>>,
+}
+
 /// Details of the audit log entry
 ///
-/// This uses a HashMap to capture any dynamic fields that may be present
-/// in the details depending on the action type.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// Which fields are present depends on the action; documented fields are
+/// typed and anything else is kept in `extra`.
+///
+/// See: https://api.slack.com/admins/audit-logs-call#the-details-object
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct Details {
+    /// Kind of change, e.g. the type of SSO or retention setting
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub detail_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Setting value before the change; strings, lists or objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_value: Option<serde_json::Value>,
+    /// Setting value after the change; strings, lists or objects
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// User who invited the entity to a channel or workspace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<User>,
+    /// User who removed the entity from a channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kicker: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_on: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub web_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub non_sso_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_internal_integration: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_token_rotation_enabled_app: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_owner_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub installer_user_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approver_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_previously_approved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granular_bot_token: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bot_scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<ResourcePermission>>,
+    /// Channel IDs affected by the action
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<String>>,
+    /// Workspace or organization a channel was shared to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_to: Option<Location>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origin_team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_team: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_external_limited: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_organization_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_organization_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_retention_policy: Option<RetentionPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_retention_policy: Option<RetentionPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub who_can_post: Option<ConversationPref>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_thread: Option<ConversationPref>,
+    /// Fields not covered above
     #[serde(flatten)]
-    pub fields: HashMap<String, serde_json::Value>,
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Channel information in an entity
@@ -134,6 +250,91 @@ pub struct LogEntry {
     pub details: Option<Details>,
 }
 
+/// Action names treated as administrative by [`LogEntry::is_admin_action`],
+/// besides `pref.*` setting changes
+pub const ADMIN_ACTIONS: &[&str] = &[
+    "role_change_to_admin",
+    "role_change_to_owner",
+    "role_change_to_user",
+    "role_change_to_guest",
+    "user_deactivated",
+    "user_reactivated",
+    "guest_deactivated",
+    "guest_reactivated",
+    "app_approved",
+    "app_restricted",
+    "app_removed_from_whitelist",
+    "app_installed",
+    "app_uninstalled",
+    "bot_token_upgraded",
+    "bot_token_downgraded",
+    "channel_retention_changed",
+    "file_retention_changed",
+    "custom_tos_accepted",
+    "corporate_exports_approved",
+    "corporate_exports_enabled",
+    "manual_export_started",
+    "scheduled_export_started",
+    "organization_created",
+    "organization_deleted",
+    "organization_accepted_migration",
+    "workspace_created",
+    "workspace_deleted",
+    "emoji_added",
+    "emoji_removed",
+];
+
+impl LogEntry {
+    /// Returns true for administrative actions: setting changes (`pref.*`)
+    /// and the actions in [`ADMIN_ACTIONS`].
+    pub fn is_admin_action(&self) -> bool {
+        match self.action.as_deref() {
+            Some(action) => action.starts_with("pref.") || ADMIN_ACTIONS.contains(&action),
+            None => false,
+        }
+    }
+
+    /// Returns true if the entry is for `action`.
+    pub fn is_action(&self, action: &str) -> bool {
+        self.action.as_deref() == Some(action)
+    }
+
+    /// ID of the user who performed the action.
+    pub fn actor_user_id(&self) -> Option<&str> {
+        self.actor.as_ref()?.user.as_ref()?.id.as_deref()
+    }
+
+    /// Type of the affected entity, e.g. `user`, `channel` or `app`.
+    pub fn entity_type(&self) -> Option<&str> {
+        self.entity.as_ref()?.entity_type.as_deref()
+    }
+
+    /// ID of the affected user, for user entities.
+    pub fn entity_user_id(&self) -> Option<&str> {
+        self.entity.as_ref()?.user.as_ref()?.id.as_deref()
+    }
+
+    /// ID of the affected channel, for channel entities.
+    pub fn entity_channel_id(&self) -> Option<&str> {
+        self.entity.as_ref()?.channel.as_ref()?.id.as_deref()
+    }
+
+    /// ID of the affected app, for app entities.
+    pub fn entity_app_id(&self) -> Option<&str> {
+        self.entity.as_ref()?.app.as_ref()?.id.as_deref()
+    }
+
+    /// IP address the action came from.
+    pub fn ip_address(&self) -> Option<&str> {
+        self.context.as_ref()?.ip_address.as_deref()
+    }
+
+    /// Reason given in the details, e.g. for a deactivation or removal.
+    pub fn reason(&self) -> Option<&str> {
+        self.details.as_ref()?.reason.as_deref()
+    }
+}
+
 /// Response metadata with pagination cursor
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ResponseMetadata {
@@ -154,6 +355,99 @@ pub struct LogsResponse {
     pub error: Option<String>,
 }
 
+/// An auditable action returned by the `actions` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditAction {
+    /// Action name, as used in the `action` filter (e.g. `user_login`)
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Group the action is listed under (e.g. `user`, `channel`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+}
+
+/// An object type returned by the `schemas` endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuditSchema {
+    #[serde(alias = "type")]
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Filter for paging through audit log entries.
+///
+/// # Example
+///
+/// ```
+/// use slack_rs::audit_logs::AuditLogFilter;
+///
+/// let filter = AuditLogFilter::new()
+///     .action("user_login")
+///     .oldest(1_700_000_000)
+///     .limit(1000);
+/// assert_eq!(filter.action.as_deref(), Some("user_login"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditLogFilter {
+    /// Entries per page (max 9999)
+    pub limit: Option<u32>,
+    /// Name of the action to filter by
+    pub action: Option<String>,
+    /// User ID who initiated the action
+    pub actor: Option<String>,
+    /// ID of the target entity
+    pub entity: Option<String>,
+    /// Unix timestamp of the least recent audit event
+    pub oldest: Option<i64>,
+    /// Unix timestamp of the most recent audit event
+    pub latest: Option<i64>,
+}
+
+impl AuditLogFilter {
+    /// Creates a filter that matches every entry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the page size.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Filters by action name.
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action = Some(action.into());
+        self
+    }
+
+    /// Filters by the user who initiated the action.
+    pub fn actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    /// Filters by target entity ID.
+    pub fn entity(mut self, entity: impl Into<String>) -> Self {
+        self.entity = Some(entity.into());
+        self
+    }
+
+    /// Only includes entries at or after this Unix timestamp.
+    pub fn oldest(mut self, oldest: i64) -> Self {
+        self.oldest = Some(oldest);
+        self
+    }
+
+    /// Only includes entries at or before this Unix timestamp.
+    pub fn latest(mut self, latest: i64) -> Self {
+        self.latest = Some(latest);
+        self
+    }
+}
+
 /// Unified audit logs API response
 #[derive(Debug, Clone)]
 pub struct AuditLogsResponse {
@@ -200,6 +494,74 @@ impl AuditLogsResponse {
     pub fn is_ok(&self) -> bool {
         self.status_code >= 200 && self.status_code < 300
     }
+
+    /// Returns the JSON body, or an `ApiError` naming `operation` if the
+    /// request failed or Slack answered `"ok": false`.
+    pub(crate) fn into_body(self, operation: &str) -> Result<serde_json::Value, SlackError> {
+        let ok = self.is_ok();
+        let body = self.body.unwrap_or_default();
+        if !ok || body["ok"] == false {
+            return Err(SlackError::ApiError {
+                message: format!(
+                    "Audit logs {} failed ({}): {}",
+                    operation,
+                    self.status_code,
+                    body["error"].as_str().unwrap_or("unknown_error")
+                ),
+                response: body,
+                status: Some(self.status_code),
+                headers: self.headers,
+            });
+        }
+        Ok(body)
+    }
+
+    /// Parses the body of an `actions` response.
+    ///
+    /// Slack lists action names grouped by category
+    /// (`{"actions": {"user": ["user_login", ...]}}`); each name becomes an
+    /// [`AuditAction`] with that category. A plain array of action objects
+    /// is accepted as well.
+    pub(crate) fn actions(self) -> Result<Vec<AuditAction>, SlackError> {
+        let mut body = self.into_body("actions")?;
+        match body["actions"].take() {
+            serde_json::Value::Array(items) => Ok(serde_json::from_value(items.into())?),
+            serde_json::Value::Object(categories) => Ok(categories
+                .into_iter()
+                .flat_map(|(category, ids)| {
+                    let ids = match ids {
+                        serde_json::Value::Array(ids) => ids,
+                        _ => Vec::new(),
+                    };
+                    ids.into_iter().filter_map(move |id| {
+                        Some(AuditAction {
+                            id: id.as_str()?.to_string(),
+                            description: None,
+                            category: Some(category.clone()),
+                        })
+                    })
+                })
+                .collect()),
+            _ => Err(SlackError::api_error_with_response(
+                "Audit logs actions response has no actions".to_string(),
+                body,
+            )),
+        }
+    }
+
+    /// Parses the body of a `schemas` response.
+    ///
+    /// Entries that only carry a `type` use it as the schema name.
+    pub(crate) fn schemas(self) -> Result<Vec<AuditSchema>, SlackError> {
+        let mut body = self.into_body("schemas")?;
+        match body["schemas"].take() {
+            schemas @ serde_json::Value::Array(_) => Ok(serde_json::from_value(schemas)?),
+            _ => Err(SlackError::api_error_with_response(
+                "Audit logs schemas response has no schemas".to_string(),
+                body,
+            )),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -309,4 +671,232 @@ mod tests {
         assert!(json.contains("192.168.1.1"));
         assert!(json.contains("workspace"));
     }
+
+    fn response(status_code: u16, body: serde_json::Value) -> AuditLogsResponse {
+        AuditLogsResponse::new(
+            "https://api.slack.com/audit/v1/".to_string(),
+            status_code,
+            HashMap::new(),
+            Some(body.to_string()),
+        )
+    }
+
+    #[test]
+    fn test_actions_grouped_by_category() {
+        let actions = response(
+            200,
+            serde_json::json!({
+                "actions": {
+                    "user": ["user_login", "user_logout"],
+                    "channel": ["user_channel_join"]
+                }
+            }),
+        )
+        .actions()
+        .unwrap();
+
+        assert_eq!(actions.len(), 3);
+        let login = actions.iter().find(|a| a.id == "user_login").unwrap();
+        assert_eq!(login.category.as_deref(), Some("user"));
+        assert_eq!(login.description, None);
+    }
+
+    #[test]
+    fn test_actions_as_objects() {
+        let actions = response(
+            200,
+            serde_json::json!({
+                "actions": [{"id": "user_login", "description": "A user logged in"}]
+            }),
+        )
+        .actions()
+        .unwrap();
+
+        assert_eq!(
+            actions,
+            vec![AuditAction {
+                id: "user_login".to_string(),
+                description: Some("A user logged in".to_string()),
+                category: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_schemas_accepts_type_as_name() {
+        let schemas = response(
+            200,
+            serde_json::json!({
+                "schemas": [
+                    {"type": "workspace", "workspace": {"id": "string"}},
+                    {"name": "user", "description": "A Slack user"}
+                ]
+            }),
+        )
+        .schemas()
+        .unwrap();
+
+        assert_eq!(schemas[0].name, "workspace");
+        assert_eq!(schemas[1].description.as_deref(), Some("A Slack user"));
+    }
+
+    #[test]
+    fn test_discovery_error_response() {
+        let err = response(
+            403,
+            serde_json::json!({"ok": false, "error": "not_allowed"}),
+        )
+        .schemas()
+        .unwrap_err();
+        assert!(err.to_string().contains("not_allowed"), "{}", err);
+
+        let err = response(200, serde_json::json!({})).actions().unwrap_err();
+        assert!(matches!(err, SlackError::ApiError { .. }));
+    }
+
+    #[test]
+    fn test_user_login_entry() {
+        let entry: LogEntry = serde_json::from_value(serde_json::json!({
+            "id": "0123a45b-6c7d-8900-e12f-3456789gh0i1",
+            "date_create": 1521214343,
+            "action": "user_login",
+            "actor": {
+                "type": "user",
+                "user": {"id": "W123AB456", "name": "Charlie Parker", "email": "bird@example.com"}
+            },
+            "entity": {
+                "type": "user",
+                "user": {"id": "W123AB456", "name": "Charlie Parker", "email": "bird@example.com"}
+            },
+            "context": {
+                "location": {"type": "enterprise", "id": "E1701NCCA", "name": "Birdland", "domain": "birdland"},
+                "ua": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_12_6)",
+                "ip_address": "1.23.45.678"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(entry.actor_user_id(), Some("W123AB456"));
+        assert_eq!(entry.entity_user_id(), Some("W123AB456"));
+        assert_eq!(entry.ip_address(), Some("1.23.45.678"));
+        assert_eq!(entry.entity_channel_id(), None);
+        assert!(entry.is_action("user_login"));
+        assert!(!entry.is_admin_action());
+    }
+
+    #[test]
+    fn test_channel_retention_changed_entry() {
+        let entry: LogEntry = serde_json::from_value(serde_json::json!({
+            "id": "1a2b3c4d-5e6f-7a8b-9c0d-1e2f3a4b5c6d",
+            "date_create": 1612345678,
+            "action": "channel_retention_changed",
+            "actor": {"type": "user", "user": {"id": "W111", "name": "admin"}},
+            "entity": {
+                "type": "channel",
+                "channel": {"id": "C0123ABC", "privacy": "public", "name": "general", "is_shared": false, "is_org_shared": false}
+            },
+            "context": {"location": {"type": "workspace", "id": "T111", "name": "Acme", "domain": "acme"}},
+            "details": {
+                "old_retention_policy": {"type": "keep_all", "duration_days": 0},
+                "new_retention_policy": {"type": "delete_after", "duration_days": 90},
+                "is_internal_integration": false
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(entry.entity_type(), Some("channel"));
+        assert_eq!(entry.entity_channel_id(), Some("C0123ABC"));
+        assert!(entry.is_admin_action());
+        let details = entry.details.unwrap();
+        assert_eq!(
+            details.new_retention_policy,
+            Some(RetentionPolicy {
+                policy_type: Some("delete_after".to_string()),
+                duration_days: Some(90),
+            })
+        );
+        assert!(details.extra.is_empty());
+    }
+
+    #[test]
+    fn test_app_installed_entry() {
+        let entry: LogEntry = serde_json::from_value(serde_json::json!({
+            "id": "2b3c4d5e-6f7a-8b9c-0d1e-2f3a4b5c6d7e",
+            "date_create": 1612345700,
+            "action": "app_installed",
+            "actor": {"type": "user", "user": {"id": "W222", "name": "installer"}},
+            "entity": {
+                "type": "app",
+                "app": {"id": "A012ABCD", "name": "Deploy Bot", "is_distributed": false, "is_directory_approved": false, "scopes": ["chat:write"]}
+            },
+            "details": {
+                "is_internal_integration": true,
+                "app_owner_id": "W222",
+                "installer_user_id": "W222",
+                "bot_scopes": ["chat:write", "commands"],
+                "granular_bot_token": true,
+                "is_token_rotation_enabled_app": false,
+                "permissions": [{"resource_type": "channel", "resource_id": "C0123ABC", "scopes": ["chat:write"]}],
+                "app_installed_to": "workspace"
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(entry.entity_app_id(), Some("A012ABCD"));
+        assert!(entry.is_admin_action());
+        let details = entry.details.unwrap();
+        assert_eq!(details.is_internal_integration, Some(true));
+        assert_eq!(details.bot_scopes.unwrap().len(), 2);
+        assert_eq!(
+            details.permissions.unwrap()[0].resource_id.as_deref(),
+            Some("C0123ABC")
+        );
+        assert_eq!(details.extra["app_installed_to"], "workspace");
+    }
+
+    #[test]
+    fn test_pref_change_and_channel_join_entries() {
+        let pref: LogEntry = serde_json::from_value(serde_json::json!({
+            "action": "pref.sso_setting_changed",
+            "actor": {"type": "user", "user": {"id": "W333"}},
+            "details": {
+                "type": "saml",
+                "previous_value": "optional",
+                "new_value": ["required", "mobile_only"],
+                "mobile_only": true,
+                "web_only": false,
+                "non_sso_only": false
+            }
+        }))
+        .unwrap();
+        assert!(pref.is_admin_action());
+        let details = pref.details.as_ref().unwrap();
+        assert_eq!(details.detail_type.as_deref(), Some("saml"));
+        assert_eq!(details.previous_value, Some(serde_json::json!("optional")));
+        assert!(details.new_value.as_ref().unwrap().is_array());
+
+        let join: LogEntry = serde_json::from_value(serde_json::json!({
+            "action": "user_channel_join",
+            "actor": {"type": "user", "user": {"id": "W444"}},
+            "entity": {"type": "channel", "channel": {"id": "C999", "privacy": "private"}},
+            "details": {
+                "inviter": {"id": "W555", "name": "Lead", "email": "lead@example.com"},
+                "reason": "invited",
+                "shared_to": {"type": "workspace", "id": "T222", "name": "Partner", "domain": "partner"}
+            }
+        }))
+        .unwrap();
+        assert_eq!(join.reason(), Some("invited"));
+        let details = join.details.unwrap();
+        assert_eq!(details.inviter.unwrap().id.as_deref(), Some("W555"));
+        assert_eq!(details.shared_to.unwrap().id.as_deref(), Some("T222"));
+    }
+
+    #[test]
+    fn test_details_round_trip_keeps_extra_fields() {
+        let json = serde_json::json!({"reason": "policy", "brand_new_field": {"nested": 1}});
+        let details: Details = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(details.reason.as_deref(), Some("policy"));
+        assert_eq!(serde_json::to_value(&details).unwrap(), json);
+    }
 }
@@ -0,0 +1,157 @@
+//! Time sources for expiry and retry logic.
+//!
+//! Components that check timestamps (signature verification, token rotation,
+//! OAuth state expiry, retry scheduling) read the current time through the
+//! [`Clock`] trait. They use [`SystemClock`] unless told otherwise, and accept
+//! a [`MockClock`] via their `with_clock` option for deterministic tests.
+//!
+//! # Example
+//!
+//! ```
+//! use slack_rs::clock::{Clock, MockClock};
+//! use slack_rs::signature::SignatureVerifier;
+//! use std::sync::Arc;
+//! use std::time::Duration;
+//!
+//! let clock = MockClock::from_unix_secs(1531420618);
+//! let verifier = SignatureVerifier::new("secret").with_clock(Arc::new(clock.clone()));
+//!
+//! let signature = verifier.generate_signature("1531420618", b"body");
+//! assert!(verifier.is_valid(b"body", "1531420618", &signature));
+//!
+//! // Ten minutes later the same request is too old
+//! clock.advance(Duration::from_secs(600));
+//! assert!(!verifier.is_valid(b"body", "1531420618", &signature));
+//! ```
+
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// A source of the current time.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current wall-clock time.
+    fn now(&self) -> SystemTime;
+
+    /// Returns the current monotonic time.
+    ///
+    /// Defaults to [`Instant::now`].
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// The real system clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A manually controlled clock for tests.
+///
+/// Time only moves when [`advance`](Self::advance) is called. Clones share
+/// the same time, so a test can keep one handle and pass another to the
+/// component under test.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    start: SystemTime,
+    start_instant: Instant,
+    elapsed: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    /// Creates a clock frozen at the given time.
+    pub fn new(start: SystemTime) -> Self {
+        Self {
+            start,
+            start_instant: Instant::now(),
+            elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+        }
+    }
+
+    /// Creates a clock frozen at the given number of seconds since the Unix epoch.
+    pub fn from_unix_secs(secs: u64) -> Self {
+        Self::new(UNIX_EPOCH + Duration::from_secs(secs))
+    }
+
+    /// Moves the clock forward.
+    pub fn advance(&self, duration: Duration) {
+        let mut elapsed = self.elapsed.lock().expect("MockClock lock poisoned");
+        *elapsed += duration;
+    }
+
+    fn elapsed(&self) -> Duration {
+        *self.elapsed.lock().expect("MockClock lock poisoned")
+    }
+}
+
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new(SystemTime::now())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> SystemTime {
+        self.start + self.elapsed()
+    }
+
+    fn instant(&self) -> Instant {
+        self.start_instant + self.elapsed()
+    }
+}
+
+/// Returns the system clock as a shared trait object.
+pub(crate) fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// Returns the clock's current time as a UTC `DateTime`.
+pub(crate) fn utc_now(clock: &dyn Clock) -> DateTime<Utc> {
+    DateTime::<Utc>::from(clock.now())
+}
+
+/// Returns the clock's current time as seconds since the Unix epoch.
+pub(crate) fn unix_timestamp(clock: &dyn Clock) -> i64 {
+    utc_now(clock).timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_system_clock_tracks_real_time() {
+        let before = SystemTime::now();
+        let now = SystemClock.now();
+        assert!(now >= before);
+    }
+
+    #[test]
+    fn test_mock_clock_is_frozen_until_advanced() {
+        let clock = MockClock::from_unix_secs(1_000);
+        let instant = clock.instant();
+
+        assert_eq!(unix_timestamp(&clock), 1_000);
+        assert_eq!(clock.instant(), instant);
+
+        clock.advance(Duration::from_secs(30));
+        assert_eq!(unix_timestamp(&clock), 1_030);
+        assert_eq!(clock.instant(), instant + Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_time() {
+        let clock = MockClock::from_unix_secs(0);
+        let shared = clock.clone();
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(shared.now(), UNIX_EPOCH + Duration::from_secs(5));
+    }
+}
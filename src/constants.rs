@@ -19,6 +19,15 @@ pub mod headers {
 
     /// Retry-After header (for rate limiting)
     pub const RETRY_AFTER: &str = "Retry-After";
+
+    /// Scopes granted to the token that made the request
+    pub const X_OAUTH_SCOPES: &str = "x-oauth-scopes";
+
+    /// Scopes accepted by the called API method
+    pub const X_ACCEPTED_OAUTH_SCOPES: &str = "x-accepted-oauth-scopes";
+
+    /// Slack's id for the request, useful when contacting Slack support
+    pub const X_SLACK_REQ_ID: &str = "x-slack-req-id";
 }
 
 /// HTTP status codes
@@ -26,6 +35,9 @@ pub mod status_codes {
     /// OK
     pub const OK: u16 = 200;
 
+    /// Forbidden
+    pub const FORBIDDEN: u16 = 403;
+
     /// Too Many Requests (rate limited)
     pub const TOO_MANY_REQUESTS: u16 = 429;
 
@@ -43,6 +55,9 @@ pub mod time {
 
     /// Default timeout for HTTP requests in seconds
     pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+    /// How far ahead `chat.scheduleMessage` accepts a `post_at` (120 days)
+    pub const MAX_SCHEDULE_AHEAD_DAYS: i64 = 120;
 }
 
 /// Block Kit validation limits
@@ -68,6 +83,15 @@ pub mod limits {
     /// Maximum number of fields in a section block
     pub const MAX_SECTION_FIELDS: usize = 10;
 
+    /// Maximum text length of each section block field (2000 characters)
+    pub const MAX_SECTION_FIELD_LENGTH: usize = 2000;
+
+    /// Maximum length for block IDs (255 characters)
+    pub const MAX_BLOCK_ID_LENGTH: usize = 255;
+
+    /// Maximum number of blocks in a message
+    pub const MAX_MESSAGE_BLOCKS: usize = 50;
+
     /// Maximum number of elements in a context block
     pub const MAX_CONTEXT_ELEMENTS: usize = 10;
 
@@ -89,6 +113,9 @@ pub mod limits {
     /// Maximum length for private metadata (3000 characters)
     pub const MAX_PRIVATE_METADATA_LENGTH: usize = 3000;
 
+    /// Maximum length for view submission error messages (255 characters)
+    pub const MAX_VIEW_ERROR_MESSAGE_LENGTH: usize = 255;
+
     /// Maximum length for button text (75 characters)
     pub const MAX_BUTTON_TEXT_LENGTH: usize = 75;
 
@@ -127,6 +154,25 @@ pub mod limits {
 
     /// Maximum number of option groups
     pub const MAX_OPTION_GROUPS: usize = 100;
+
+    /// Maximum number of options in a single option group
+    pub const MAX_OPTION_GROUP_OPTIONS: usize = 100;
+
+    /// Maximum length for option group labels (75 characters)
+    pub const MAX_OPTION_GROUP_LABEL_LENGTH: usize = 75;
+
+    /// Maximum number of users in one `conversations.invite` call
+    pub const MAX_INVITE_USERS: usize = 1000;
+
+    /// Default number of users per `conversations.invite` call in bulk invites
+    pub const DEFAULT_INVITE_CHUNK_SIZE: usize = 200;
+
+    /// Maximum number of redirects followed when downloading a file
+    pub const MAX_DOWNLOAD_REDIRECTS: usize = 5;
+
+    /// Latest initial date-time accepted by date-time pickers
+    /// (2100-12-31T23:59:59Z as a Unix timestamp)
+    pub const MAX_DATE_TIME_PICKER_TIMESTAMP: i64 = 4_133_980_799;
 }
 
 /// Signature verification constants
@@ -137,3 +183,282 @@ pub mod signature {
     /// Signature format prefix (includes version)
     pub const SIGNATURE_PREFIX: &str = "v0=";
 }
+
+/// Web API rate limit tiers
+///
+/// See: https://api.slack.com/apis/rate-limits
+pub mod rate_limits {
+    use std::time::Duration;
+
+    /// A documented rate limit for a Web API method
+    ///
+    /// Slack tolerates short bursts above the per-minute rate but does not
+    /// publish their size, so `burst_size` is a conservative estimate.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RateLimit {
+        /// Sustained requests per minute
+        pub requests_per_minute: u32,
+        /// Requests that can be sent back to back before spacing them out
+        pub burst_size: u32,
+    }
+
+    impl RateLimit {
+        /// Creates a rate limit
+        pub const fn new(requests_per_minute: u32, burst_size: u32) -> Self {
+            Self {
+                requests_per_minute,
+                burst_size,
+            }
+        }
+
+        /// Average delay between requests that stays within the limit
+        pub const fn min_interval(&self) -> Duration {
+            Duration::from_millis(60_000 / self.requests_per_minute as u64)
+        }
+    }
+
+    /// Tier 1: 1+ requests per minute
+    pub const TIER_1_RATE_LIMIT: RateLimit = RateLimit::new(1, 1);
+
+    /// Tier 2: 20+ requests per minute
+    pub const TIER_2_RATE_LIMIT: RateLimit = RateLimit::new(20, 5);
+
+    /// Tier 3: 50+ requests per minute
+    pub const TIER_3_RATE_LIMIT: RateLimit = RateLimit::new(50, 10);
+
+    /// Tier 4: 100+ requests per minute
+    pub const TIER_4_RATE_LIMIT: RateLimit = RateLimit::new(100, 20);
+
+    /// `chat.postMessage`: one message per second per channel
+    pub const CHAT_POST_MESSAGE_RATE_LIMIT: RateLimit = RateLimit::new(60, 1);
+
+    /// Returns the documented rate limit for a Web API method.
+    ///
+    /// `admin.*` methods not listed explicitly are Tier 2, and other unknown
+    /// methods fall back to Tier 3, the most common tier. This is a plain
+    /// `fn` because string patterns cannot be matched in a `const fn`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use slack_rs::constants::rate_limits::{tier_for_method, TIER_2_RATE_LIMIT};
+    ///
+    /// assert_eq!(tier_for_method("conversations.list"), TIER_2_RATE_LIMIT);
+    /// ```
+    pub fn tier_for_method(method: &str) -> RateLimit {
+        match method {
+            "chat.postMessage" => CHAT_POST_MESSAGE_RATE_LIMIT,
+
+            "apps.connections.open"
+            | "apps.manifest.create"
+            | "apps.manifest.delete"
+            | "apps.manifest.export"
+            | "apps.manifest.update"
+            | "apps.manifest.validate"
+            | "apps.uninstall"
+            | "rtm.connect"
+            | "rtm.start"
+            | "team.billableInfo" => TIER_1_RATE_LIMIT,
+
+            "auth.teams.list"
+            | "bookmarks.add"
+            | "bookmarks.edit"
+            | "bookmarks.remove"
+            | "calls.add"
+            | "calls.end"
+            | "calls.info"
+            | "calls.participants.add"
+            | "calls.participants.remove"
+            | "calls.update"
+            | "channels.archive"
+            | "channels.create"
+            | "channels.list"
+            | "channels.rename"
+            | "channels.setPurpose"
+            | "channels.setTopic"
+            | "channels.unarchive"
+            | "conversations.archive"
+            | "conversations.close"
+            | "conversations.create"
+            | "conversations.inviteShared"
+            | "conversations.list"
+            | "conversations.rename"
+            | "conversations.setPurpose"
+            | "conversations.setTopic"
+            | "conversations.unarchive"
+            | "dnd.endDnd"
+            | "dnd.endSnooze"
+            | "dnd.setSnooze"
+            | "dnd.teamInfo"
+            | "emoji.list"
+            | "files.comments.delete"
+            | "files.remote.add"
+            | "files.remote.info"
+            | "files.remote.list"
+            | "files.remote.remove"
+            | "files.remote.share"
+            | "files.remote.update"
+            | "files.upload"
+            | "groups.archive"
+            | "groups.create"
+            | "groups.createChild"
+            | "groups.list"
+            | "groups.rename"
+            | "groups.setPurpose"
+            | "groups.setTopic"
+            | "groups.unarchive"
+            | "im.close"
+            | "im.list"
+            | "migration.exchange"
+            | "mpim.close"
+            | "mpim.list"
+            | "pins.add"
+            | "pins.list"
+            | "pins.remove"
+            | "reactions.list"
+            | "reactions.remove"
+            | "reminders.add"
+            | "reminders.complete"
+            | "reminders.delete"
+            | "reminders.info"
+            | "reminders.list"
+            | "search.all"
+            | "search.files"
+            | "search.messages"
+            | "stars.add"
+            | "stars.remove"
+            | "team.accessLogs"
+            | "team.externalTeams.list"
+            | "team.integrationLogs"
+            | "usergroups.create"
+            | "usergroups.disable"
+            | "usergroups.enable"
+            | "usergroups.list"
+            | "usergroups.update"
+            | "usergroups.users.update"
+            | "users.deletePhoto"
+            | "users.list"
+            | "users.setPhoto"
+            | "users.setPresence" => TIER_2_RATE_LIMIT,
+
+            "auth.revoke"
+            | "bookmarks.list"
+            | "bots.info"
+            | "channels.history"
+            | "channels.info"
+            | "channels.invite"
+            | "channels.join"
+            | "channels.kick"
+            | "channels.leave"
+            | "channels.mark"
+            | "channels.replies"
+            | "chat.delete"
+            | "chat.deleteScheduledMessage"
+            | "chat.meMessage"
+            | "chat.scheduleMessage"
+            | "chat.scheduledMessages.list"
+            | "chat.unfurl"
+            | "chat.update"
+            | "conversations.history"
+            | "conversations.info"
+            | "conversations.invite"
+            | "conversations.join"
+            | "conversations.kick"
+            | "conversations.leave"
+            | "conversations.mark"
+            | "conversations.open"
+            | "conversations.replies"
+            | "dnd.info"
+            | "files.delete"
+            | "files.list"
+            | "files.revokePublicURL"
+            | "files.sharedPublicURL"
+            | "groups.history"
+            | "groups.info"
+            | "groups.invite"
+            | "groups.kick"
+            | "groups.leave"
+            | "groups.mark"
+            | "groups.open"
+            | "groups.replies"
+            | "im.history"
+            | "im.mark"
+            | "im.open"
+            | "im.replies"
+            | "mpim.history"
+            | "mpim.mark"
+            | "mpim.open"
+            | "mpim.replies"
+            | "reactions.add"
+            | "reactions.get"
+            | "stars.list"
+            | "team.billing.info"
+            | "team.info"
+            | "team.preferences.list"
+            | "team.profile.get"
+            | "users.conversations"
+            | "users.getPresence"
+            | "users.lookupByEmail"
+            | "users.profile.set"
+            | "workflows.featured.add"
+            | "workflows.featured.list"
+            | "workflows.featured.remove"
+            | "workflows.featured.set" => TIER_3_RATE_LIMIT,
+
+            "api.test"
+            | "auth.test"
+            | "chat.getPermalink"
+            | "chat.postEphemeral"
+            | "conversations.members"
+            | "dialog.open"
+            | "files.getUploadURLExternal"
+            | "files.info"
+            | "oauth.access"
+            | "oauth.v2.access"
+            | "oauth.v2.exchange"
+            | "usergroups.users.list"
+            | "users.identity"
+            | "users.info"
+            | "users.profile.get"
+            | "views.open"
+            | "views.publish"
+            | "views.push"
+            | "views.update"
+            | "workflows.stepCompleted"
+            | "workflows.stepFailed"
+            | "workflows.updateStep" => TIER_4_RATE_LIMIT,
+
+            method if method.starts_with("admin.") => TIER_2_RATE_LIMIT,
+            _ => TIER_3_RATE_LIMIT,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_tier_for_method() {
+            assert_eq!(tier_for_method("apps.connections.open"), TIER_1_RATE_LIMIT);
+            assert_eq!(tier_for_method("users.list"), TIER_2_RATE_LIMIT);
+            assert_eq!(tier_for_method("conversations.history"), TIER_3_RATE_LIMIT);
+            assert_eq!(tier_for_method("users.info"), TIER_4_RATE_LIMIT);
+            assert_eq!(
+                tier_for_method("chat.postMessage"),
+                CHAT_POST_MESSAGE_RATE_LIMIT
+            );
+        }
+
+        #[test]
+        fn test_tier_for_unknown_methods() {
+            assert_eq!(tier_for_method("admin.users.list"), TIER_2_RATE_LIMIT);
+            assert_eq!(tier_for_method("not.a.method"), TIER_3_RATE_LIMIT);
+        }
+
+        #[test]
+        fn test_min_interval() {
+            assert_eq!(TIER_1_RATE_LIMIT.min_interval(), Duration::from_secs(60));
+            assert_eq!(TIER_4_RATE_LIMIT.min_interval(), Duration::from_millis(600));
+        }
+    }
+}
@@ -3,6 +3,9 @@
 //! This module provides a comprehensive error type hierarchy that covers all possible
 //! failure modes when interacting with the Slack API.
 
+use crate::constants::headers::X_SLACK_REQ_ID;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
 use std::fmt;
 use thiserror::Error;
 
@@ -17,7 +20,7 @@ pub enum SlackError {
     Api(#[from] SlackApiError),
 
     /// An HTTP error occurred while making a request.
-    #[error("HTTP error: {0}")]
+    #[error("HTTP request to Slack failed: {0}")]
     Http(#[from] reqwest::Error),
 
     /// A JSON serialization/deserialization error.
@@ -33,7 +36,7 @@ pub enum SlackError {
     InvalidToken(String),
 
     /// A validation error occurred (e.g., field too long, missing required field).
-    #[error("Validation error: {0}")]
+    #[error("Validation error: {}{}", .0, validation_suggestion(.0))]
     Validation(String),
 
     /// A wrong token type was used for the operation.
@@ -57,14 +60,25 @@ pub enum SlackError {
     Io(#[from] std::io::Error),
 
     /// An HTTP error occurred (not from reqwest).
-    #[error("HTTP error: {message}")]
-    HttpError { message: String },
+    #[error("HTTP error: {message}{}", request_id_suffix(.headers))]
+    HttpError {
+        message: String,
+        /// HTTP status code, if a response was received.
+        status: Option<u16>,
+        /// Response headers keyed by lowercase name; empty without a response.
+        headers: HashMap<String, String>,
+    },
 
     /// An API error with response data.
-    #[error("API error: {message}")]
+    #[error("{}{}", api_error_text(message, response), request_id_suffix(.headers))]
     ApiError {
         message: String,
         response: serde_json::Value,
+        /// HTTP status code, if known.
+        status: Option<u16>,
+        /// Response headers keyed by lowercase name, such as
+        /// `x-slack-req-id`, `retry-after` and `x-oauth-scopes`.
+        headers: HashMap<String, String>,
     },
 
     /// Invalid input provided to the SDK.
@@ -90,6 +104,58 @@ pub enum SlackError {
     /// A resource was not found.
     #[error("Not found: {0}")]
     NotFound(String),
+
+    /// Slack asked to retry after longer than the client is willing to wait.
+    ///
+    /// Returned instead of sleeping when `Retry-After` exceeds the client's
+    /// `max_retry_wait`.
+    #[error("Rate limited: retry after {retry_after:?}")]
+    Ratelimited { retry_after: std::time::Duration },
+
+    /// State changed between reading and writing it in a read-modify-write
+    /// helper, so the write was not made.
+    #[error("Concurrent modification: {0}")]
+    ConcurrentModification(String),
+
+    /// The operation is not supported by this implementation.
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
+
+    /// An error returned by the SCIM API.
+    #[error("{0}")]
+    Scim(Box<crate::scim::models::ScimError>),
+
+    /// The response body was not JSON, e.g. an HTML error page from a proxy
+    /// or an empty body.
+    #[error(
+        "Unexpected response (HTTP {status}, {}): {}",
+        content_type.as_deref().unwrap_or("no content type"),
+        if body_snippet.trim().is_empty() { "empty body" } else { body_snippet.as_str() }
+    )]
+    UnexpectedResponse {
+        /// HTTP status code of the response.
+        status: u16,
+        /// The response's `Content-Type`, if any.
+        content_type: Option<String>,
+        /// The start of the response body.
+        body_snippet: String,
+    },
+
+    /// A field required by the caller was missing from a response, or had
+    /// the wrong type.
+    #[error("Missing field {pointer} in {method} response")]
+    MissingField {
+        /// The API method whose response lacked the field.
+        method: String,
+        /// JSON pointer to the field, e.g. `/channel/id`.
+        pointer: String,
+    },
+}
+
+impl From<crate::scim::models::ScimError> for SlackError {
+    fn from(error: crate::scim::models::ScimError) -> Self {
+        Self::Scim(Box::new(error))
+    }
 }
 
 /// An error returned by the Slack API.
@@ -147,6 +213,67 @@ impl SlackApiError {
     }
 }
 
+/// Copies response headers into a map keyed by lowercase name, skipping
+/// values that are not valid UTF-8.
+pub(crate) fn header_map(headers: &HeaderMap) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect()
+}
+
+/// Formats the request ID for error messages, e.g. ` (request id: 1a2b3c)`.
+fn request_id_suffix(headers: &HashMap<String, String>) -> String {
+    match headers.get(X_SLACK_REQ_ID) {
+        Some(req_id) => format!(" (request id: {})", req_id),
+        None => String::new(),
+    }
+}
+
+/// Formats an `ApiError` as `Slack API error '<code>': <detail>`, preferring
+/// the detail Slack sent in the response over the SDK's own message.
+fn api_error_text(message: &str, response: &serde_json::Value) -> String {
+    let Some(code) = response.get("error").and_then(|v| v.as_str()) else {
+        return format!("API error: {}", message);
+    };
+
+    let messages: Vec<&str> = response
+        .pointer("/response_metadata/messages")
+        .and_then(|v| v.as_array())
+        .map(|m| m.iter().filter_map(|v| v.as_str()).collect())
+        .unwrap_or_default();
+    let detail = match response.get("detail").and_then(|v| v.as_str()) {
+        Some(detail) => detail.to_string(),
+        None if !messages.is_empty() => messages.join("; "),
+        None => message.to_string(),
+    };
+
+    format!("Slack API error '{}': {}", code, detail)
+}
+
+/// Suggests a fix for common validation failures, e.g. ` (shorten it to at
+/// most 75 characters)` for a length limit.
+///
+/// Only the length and item-count messages of the block models are matched;
+/// other limits, such as `time_too_far`, get no suggestion.
+fn validation_suggestion(message: &str) -> String {
+    let limit = ["exceeds maximum ", "maximum is ", "cannot have more than "]
+        .iter()
+        .find_map(|marker| {
+            let rest = &message[message.find(marker)? + marker.len()..];
+            let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+            digits.parse::<usize>().ok()
+        });
+
+    match limit {
+        Some(max) if message.contains("length") => {
+            format!(" (shorten it to at most {} characters)", max)
+        }
+        Some(max) => format!(" (reduce it to at most {} items)", max),
+        None => String::new(),
+    }
+}
+
 /// A specialized Result type for Slack SDK operations.
 pub type Result<T> = std::result::Result<T, SlackError>;
 
@@ -179,9 +306,130 @@ impl SlackError {
         Self::ApiError {
             message: message.into(),
             response: serde_json::Value::Null,
+            status: None,
+            headers: HashMap::new(),
         }
     }
 
+    /// Creates an API error from a failed response body
+    pub fn api_error_with_response(
+        message: impl Into<String>,
+        response: serde_json::Value,
+    ) -> Self {
+        Self::ApiError {
+            message: message.into(),
+            response,
+            status: None,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Creates an HTTP error without response details
+    pub fn http_error(message: impl Into<String>) -> Self {
+        Self::HttpError {
+            message: message.into(),
+            status: None,
+            headers: HashMap::new(),
+        }
+    }
+
+    /// Attaches the HTTP status and headers of the failing response to an
+    /// `ApiError` or `HttpError`. Other variants are returned unchanged.
+    pub(crate) fn with_response_info(
+        mut self,
+        status_code: u16,
+        response_headers: &HeaderMap,
+    ) -> Self {
+        if let Self::ApiError {
+            status, headers, ..
+        }
+        | Self::HttpError {
+            status, headers, ..
+        } = &mut self
+        {
+            *status = Some(status_code);
+            *headers = header_map(response_headers);
+        }
+        self
+    }
+
+    /// Returns the `x-slack-req-id` of the failing response, if known.
+    ///
+    /// Slack support asks for this ID when investigating a failed call.
+    pub fn slack_request_id(&self) -> Option<&str> {
+        match self {
+            Self::ApiError { headers, .. } | Self::HttpError { headers, .. } => {
+                headers.get(X_SLACK_REQ_ID).map(String::as_str)
+            }
+            Self::Scim(error) => error.request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code of the failing response, if known.
+    pub fn http_status(&self) -> Option<u16> {
+        match self {
+            Self::ApiError { status, .. } | Self::HttpError { status, .. } => *status,
+            Self::Api(error) => Some(error.status_code),
+            Self::Http(error) => error.status().map(|status| status.as_u16()),
+            Self::Scim(error) if error.http_status != 0 => Some(error.http_status),
+            Self::UnexpectedResponse { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the headers of the failing response, if any were captured.
+    pub fn response_headers(&self) -> Option<&HashMap<String, String>> {
+        match self {
+            Self::ApiError { headers, .. } | Self::HttpError { headers, .. }
+                if !headers.is_empty() =>
+            {
+                Some(headers)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the Slack error code, such as `channel_not_found`, for errors
+    /// reported by the Web API.
+    pub fn api_error_code(&self) -> Option<&str> {
+        match self {
+            Self::ApiError { response, .. } => response.get("error").and_then(|v| v.as_str()),
+            Self::Api(error) => Some(&error.error),
+            _ => None,
+        }
+    }
+
+    /// Returns a link to the Slack documentation that explains this error,
+    /// if there is one.
+    ///
+    /// Well-known error codes link to the relevant guide; other API errors
+    /// link to Slack's general notes on evaluating Web API responses.
+    pub fn help_url(&self) -> Option<&str> {
+        if matches!(self, Self::Ratelimited { .. }) {
+            return Some("https://api.slack.com/apis/rate-limits");
+        }
+
+        let url = match self.api_error_code()? {
+            "missing_scope" | "no_permission" => "https://api.slack.com/scopes",
+            "not_authed"
+            | "invalid_auth"
+            | "account_inactive"
+            | "token_revoked"
+            | "token_expired"
+            | "not_allowed_token_type" => "https://api.slack.com/authentication/token-types",
+            "ratelimited" | "rate_limited" => "https://api.slack.com/apis/rate-limits",
+            "invalid_blocks" | "invalid_blocks_format" => {
+                "https://api.slack.com/reference/block-kit/blocks"
+            }
+            "channel_not_found" | "not_in_channel" | "is_archived" => {
+                "https://api.slack.com/types/conversation"
+            }
+            _ => "https://api.slack.com/web#evaluating_responses",
+        };
+        Some(url)
+    }
+
     /// Creates an invalid input error
     pub fn invalid_input(message: impl Into<String>) -> Self {
         Self::InvalidInput {
@@ -300,4 +548,108 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), SlackError::NotConnected));
     }
+
+    #[test]
+    fn test_api_error_request_id() {
+        let mut response_headers = HeaderMap::new();
+        response_headers.insert("x-slack-req-id", "abc123".parse().unwrap());
+        response_headers.insert("retry-after", "30".parse().unwrap());
+
+        let error =
+            SlackError::api_error("channel_not_found").with_response_info(200, &response_headers);
+
+        assert_eq!(error.slack_request_id(), Some("abc123"));
+        assert_eq!(error.http_status(), Some(200));
+        assert_eq!(
+            error.response_headers().unwrap().get("retry-after"),
+            Some(&"30".to_string())
+        );
+        assert_eq!(
+            error.to_string(),
+            "API error: channel_not_found (request id: abc123)"
+        );
+    }
+
+    #[test]
+    fn test_http_error_without_response() {
+        let error = SlackError::http_error("connection refused");
+
+        assert_eq!(error.slack_request_id(), None);
+        assert_eq!(error.http_status(), None);
+        assert!(error.response_headers().is_none());
+        assert_eq!(error.to_string(), "HTTP error: connection refused");
+    }
+
+    #[test]
+    fn test_api_error_display_uses_code_and_detail() {
+        let error = SlackError::api_error_with_response(
+            "The request to the Slack API failed: invalid_blocks",
+            serde_json::json!({
+                "ok": false,
+                "error": "invalid_blocks",
+                "response_metadata": {"messages": ["[ERROR] must be less than 76 characters [json-pointer:/blocks/0/text]"]}
+            }),
+        );
+        assert_eq!(
+            error.to_string(),
+            "Slack API error 'invalid_blocks': [ERROR] must be less than 76 characters [json-pointer:/blocks/0/text]"
+        );
+        assert_eq!(error.api_error_code(), Some("invalid_blocks"));
+        assert_eq!(
+            error.help_url(),
+            Some("https://api.slack.com/reference/block-kit/blocks")
+        );
+
+        let error = SlackError::api_error_with_response(
+            "The request to the Slack API failed: channel_not_found",
+            serde_json::json!({"ok": false, "error": "channel_not_found"}),
+        );
+        assert_eq!(
+            error.to_string(),
+            "Slack API error 'channel_not_found': The request to the Slack API failed: channel_not_found"
+        );
+    }
+
+    #[test]
+    fn test_validation_error_suggestion() {
+        let error = SlackError::Validation("Button text length 80 exceeds maximum 75".to_string());
+        assert_eq!(
+            error.to_string(),
+            "Validation error: Button text length 80 exceeds maximum 75 (shorten it to at most 75 characters)"
+        );
+
+        let error =
+            SlackError::Validation("ActionsBlock has 30 elements, maximum is 25".to_string());
+        assert!(error
+            .to_string()
+            .ends_with("(reduce it to at most 25 items)"));
+
+        let error =
+            SlackError::Validation("ContextBlock cannot have more than 10 elements".to_string());
+        assert!(error
+            .to_string()
+            .ends_with("(reduce it to at most 10 items)"));
+
+        let error = SlackError::Validation(
+            "time_too_far: post_at 1900000000 is more than 120 days ahead".to_string(),
+        );
+        assert_eq!(
+            error.to_string(),
+            "Validation error: time_too_far: post_at 1900000000 is more than 120 days ahead"
+        );
+    }
+
+    #[test]
+    fn test_help_url() {
+        assert_eq!(
+            SlackError::Api(SlackApiError::new("missing_scope", 200)).help_url(),
+            Some("https://api.slack.com/scopes")
+        );
+        assert_eq!(
+            SlackError::api_error_with_response("x", serde_json::json!({"error": "unknown"}))
+                .help_url(),
+            Some("https://api.slack.com/web#evaluating_responses")
+        );
+        assert_eq!(SlackError::Validation("bad".to_string()).help_url(), None);
+    }
 }
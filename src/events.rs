@@ -0,0 +1,352 @@
+//! Slack Events API event types.
+//!
+//! Event subscriptions are identified by strings such as `"message.channels"`
+//! or `"reaction_added"`. [`EventType`] gives each documented event a variant
+//! so that typos are caught at compile time, while [`EventType::Unknown`]
+//! keeps newer or undocumented events usable.
+//!
+//! # Example
+//!
+//! ```
+//! use slack_rs::events::EventType;
+//!
+//! let event: EventType = "message.channels".parse().unwrap();
+//! assert_eq!(event, EventType::MessageChannels);
+//! assert_eq!(event.to_string(), "message.channels");
+//!
+//! let custom: EventType = "some_future_event".parse().unwrap();
+//! assert_eq!(custom, EventType::Unknown("some_future_event".to_string()));
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+macro_rules! event_types {
+    ($($(#[$doc:meta])* $variant:ident => $name:literal,)*) => {
+        /// A Slack Events API event type.
+        ///
+        /// See: https://api.slack.com/events
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum EventType {
+            $($(#[$doc])* $variant,)*
+
+            /// An event type not known to this crate.
+            Unknown(String),
+        }
+
+        impl EventType {
+            /// Returns the event type string used by the Slack API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Unknown(name) => name,
+                }
+            }
+
+            /// Returns all known event types.
+            pub fn all() -> Vec<EventType> {
+                vec![$(Self::$variant,)*]
+            }
+        }
+
+        impl FromStr for EventType {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(match s {
+                    $($name => Self::$variant,)*
+                    other => Self::Unknown(other.to_string()),
+                })
+            }
+        }
+    };
+}
+
+event_types! {
+    /// User clicked into your App Home.
+    AppHomeOpened => "app_home_opened",
+    /// Your app was mentioned in a message.
+    AppMention => "app_mention",
+    /// Your app's event subscriptions are being rate limited.
+    AppRateLimited => "app_rate_limited",
+    /// A user requested an app.
+    AppRequested => "app_requested",
+    /// Your app was uninstalled.
+    AppUninstalled => "app_uninstalled",
+    /// A call was rejected.
+    CallRejected => "call_rejected",
+    /// A channel was archived.
+    ChannelArchive => "channel_archive",
+    /// A channel was created.
+    ChannelCreated => "channel_created",
+    /// A channel was deleted.
+    ChannelDeleted => "channel_deleted",
+    /// Bulk updates were made to a channel's history.
+    ChannelHistoryChanged => "channel_history_changed",
+    /// A channel ID changed.
+    ChannelIdChanged => "channel_id_changed",
+    /// You left a channel.
+    ChannelLeft => "channel_left",
+    /// A channel was renamed.
+    ChannelRename => "channel_rename",
+    /// A channel has been shared with an external workspace.
+    ChannelShared => "channel_shared",
+    /// A channel was unarchived.
+    ChannelUnarchive => "channel_unarchive",
+    /// A channel has been unshared with an external workspace.
+    ChannelUnshared => "channel_unshared",
+    /// Do not Disturb settings changed for the current user.
+    DndUpdated => "dnd_updated",
+    /// Do not Disturb settings changed for a member.
+    DndUpdatedUser => "dnd_updated_user",
+    /// The workspace email domain has changed.
+    EmailDomainChanged => "email_domain_changed",
+    /// A custom emoji has been added or changed.
+    EmojiChanged => "emoji_changed",
+    /// A file was changed.
+    FileChange => "file_change",
+    /// A file comment was deleted.
+    FileCommentDeleted => "file_comment_deleted",
+    /// A file was created.
+    FileCreated => "file_created",
+    /// A file was deleted.
+    FileDeleted => "file_deleted",
+    /// A file was made public.
+    FilePublic => "file_public",
+    /// A file was shared.
+    FileShared => "file_shared",
+    /// A file was unshared.
+    FileUnshared => "file_unshared",
+    /// An enterprise grid migration has finished.
+    GridMigrationFinished => "grid_migration_finished",
+    /// An enterprise grid migration has started.
+    GridMigrationStarted => "grid_migration_started",
+    /// A private channel was archived.
+    GroupArchive => "group_archive",
+    /// You closed a private channel.
+    GroupClose => "group_close",
+    /// A private channel was deleted.
+    GroupDeleted => "group_deleted",
+    /// Bulk updates were made to a private channel's history.
+    GroupHistoryChanged => "group_history_changed",
+    /// You left a private channel.
+    GroupLeft => "group_left",
+    /// You created a group DM.
+    GroupOpen => "group_open",
+    /// A private channel was renamed.
+    GroupRename => "group_rename",
+    /// A private channel was unarchived.
+    GroupUnarchive => "group_unarchive",
+    /// You closed a DM.
+    ImClose => "im_close",
+    /// A DM was created.
+    ImCreated => "im_created",
+    /// Bulk updates were made to a DM's history.
+    ImHistoryChanged => "im_history_changed",
+    /// You opened a DM.
+    ImOpen => "im_open",
+    /// A user requested an invite.
+    InviteRequested => "invite_requested",
+    /// A message was posted containing one or more links relevant to your app.
+    LinkShared => "link_shared",
+    /// A user joined a public or private channel.
+    MemberJoinedChannel => "member_joined_channel",
+    /// A user left a public or private channel.
+    MemberLeftChannel => "member_left_channel",
+    /// A message was sent to a channel.
+    Message => "message",
+    /// A user sent a message to your Slack app.
+    MessageAppHome => "message.app_home",
+    /// A message was posted to a public channel.
+    MessageChannels => "message.channels",
+    /// A message was posted to a private channel.
+    MessageGroups => "message.groups",
+    /// A message was posted in a direct message channel.
+    MessageIm => "message.im",
+    /// A message was posted in a multiparty direct message channel.
+    MessageMpim => "message.mpim",
+    /// Message metadata was deleted.
+    MessageMetadataDeleted => "message_metadata_deleted",
+    /// Message metadata was posted.
+    MessageMetadataPosted => "message_metadata_posted",
+    /// Message metadata was updated.
+    MessageMetadataUpdated => "message_metadata_updated",
+    /// A pin was added to a channel.
+    PinAdded => "pin_added",
+    /// A pin was removed from a channel.
+    PinRemoved => "pin_removed",
+    /// A member has added an emoji reaction to an item.
+    ReactionAdded => "reaction_added",
+    /// A member removed an emoji reaction.
+    ReactionRemoved => "reaction_removed",
+    /// A shared channel invite was accepted.
+    SharedChannelInviteAccepted => "shared_channel_invite_accepted",
+    /// A shared channel invite was approved.
+    SharedChannelInviteApproved => "shared_channel_invite_approved",
+    /// A shared channel invite was declined.
+    SharedChannelInviteDeclined => "shared_channel_invite_declined",
+    /// A shared channel invite was sent to a Slack user.
+    SharedChannelInviteReceived => "shared_channel_invite_received",
+    /// A member has saved an item for later or starred an item.
+    StarAdded => "star_added",
+    /// A member has removed an item saved for later or starred an item.
+    StarRemoved => "star_removed",
+    /// A User Group has been added to the workspace.
+    SubteamCreated => "subteam_created",
+    /// The membership of an existing User Group has changed.
+    SubteamMembersChanged => "subteam_members_changed",
+    /// You have been added to a User Group.
+    SubteamSelfAdded => "subteam_self_added",
+    /// You have been removed from a User Group.
+    SubteamSelfRemoved => "subteam_self_removed",
+    /// An existing User Group has been updated or its members changed.
+    SubteamUpdated => "subteam_updated",
+    /// Access to a set of teams was granted to your org app.
+    TeamAccessGranted => "team_access_granted",
+    /// Access to a set of teams was revoked from your org app.
+    TeamAccessRevoked => "team_access_revoked",
+    /// The workspace domain has changed.
+    TeamDomainChange => "team_domain_change",
+    /// A new member has joined.
+    TeamJoin => "team_join",
+    /// The workspace name has changed.
+    TeamRename => "team_rename",
+    /// API tokens for your app were revoked.
+    TokensRevoked => "tokens_revoked",
+    /// A member's data has changed.
+    UserChange => "user_change",
+    /// A user's huddle status has changed.
+    UserHuddleChanged => "user_huddle_changed",
+    /// A user's profile data has changed.
+    UserProfileChanged => "user_profile_changed",
+    /// A user's status has changed.
+    UserStatusChanged => "user_status_changed",
+    /// A workflow that contains a step supported by your app was deleted.
+    WorkflowDeleted => "workflow_deleted",
+    /// A workflow that contains a step supported by your app was published.
+    WorkflowPublished => "workflow_published",
+    /// A workflow step supported by your app was removed from a workflow.
+    WorkflowStepDeleted => "workflow_step_deleted",
+    /// A workflow step supported by your app should execute.
+    WorkflowStepExecute => "workflow_step_execute",
+    /// A workflow that contains a step supported by your app was unpublished.
+    WorkflowUnpublished => "workflow_unpublished",
+}
+
+impl EventType {
+    /// Returns whether an Events API `event` object matches this event type.
+    ///
+    /// Message subscriptions such as `message.channels` are delivered as
+    /// `message` events and are distinguished by their `channel_type`.
+    pub fn matches(&self, event: &Value) -> bool {
+        let event_type = match event.get("type").and_then(|v| v.as_str()) {
+            Some(event_type) => event_type,
+            None => return false,
+        };
+
+        let channel_type = match self {
+            Self::MessageAppHome => "app_home",
+            Self::MessageChannels => "channel",
+            Self::MessageGroups => "group",
+            Self::MessageIm => "im",
+            Self::MessageMpim => "mpim",
+            _ => return event_type == self.as_str(),
+        };
+
+        event_type == "message"
+            && event.get("channel_type").and_then(|v| v.as_str()) == Some(channel_type)
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for EventType {
+    fn from(s: &str) -> Self {
+        match s.parse() {
+            Ok(event_type) => event_type,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl Serialize for EventType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(EventType::from(s.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(EventType::MessageChannels.to_string(), "message.channels");
+        assert_eq!(EventType::AppMention.to_string(), "app_mention");
+        assert_eq!(
+            EventType::Unknown("custom_event".to_string()).to_string(),
+            "custom_event"
+        );
+    }
+
+    #[test]
+    fn test_from_str_round_trip() {
+        for event_type in EventType::all() {
+            let parsed: EventType = event_type.as_str().parse().unwrap();
+            assert_eq!(parsed, event_type);
+        }
+    }
+
+    #[test]
+    fn test_from_str_unknown() {
+        let parsed: EventType = "message.channel".parse().unwrap();
+        assert_eq!(parsed, EventType::Unknown("message.channel".to_string()));
+    }
+
+    #[test]
+    fn test_serde() {
+        let value = serde_json::to_value(vec![EventType::ReactionAdded]).unwrap();
+        assert_eq!(value, json!(["reaction_added"]));
+
+        let parsed: Vec<EventType> =
+            serde_json::from_value(json!(["app_mention", "brand_new"])).unwrap();
+        assert_eq!(
+            parsed,
+            vec![
+                EventType::AppMention,
+                EventType::Unknown("brand_new".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matches() {
+        let mention = json!({"type": "app_mention", "text": "hi"});
+        assert!(EventType::AppMention.matches(&mention));
+        assert!(!EventType::ReactionAdded.matches(&mention));
+
+        let channel_message = json!({"type": "message", "channel_type": "channel"});
+        assert!(EventType::Message.matches(&channel_message));
+        assert!(EventType::MessageChannels.matches(&channel_message));
+        assert!(!EventType::MessageIm.matches(&channel_message));
+
+        assert!(!EventType::AppMention.matches(&json!({})));
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! This module provides handlers that determine whether and how to retry failed requests.
 
-use super::interval::{BackoffIntervalCalculator, IntervalCalculator};
+use super::interval::{Backoff, IntervalCalculator};
 use super::state::RetryState;
 use crate::constants::{headers, status_codes};
+use std::fmt;
+use std::sync::Arc;
 use std::time::Duration;
 use tracing::{info, warn};
 
@@ -30,6 +32,20 @@ impl HttpResponse {
             .find(|(key, _)| key.to_lowercase() == name_lower)
             .map(|(_, value)| value.as_str())
     }
+
+    /// Parses the body as JSON, if it is JSON.
+    pub fn json_body(&self) -> Option<serde_json::Value> {
+        serde_json::from_slice(&self.body).ok()
+    }
+
+    /// Returns the Slack `error` code of an `{"ok": false, ...}` body.
+    pub fn slack_error(&self) -> Option<String> {
+        let body = self.json_body()?;
+        if body.get("ok")?.as_bool()? {
+            return None;
+        }
+        body.get("error")?.as_str().map(str::to_string)
+    }
 }
 
 /// Trait for retry handlers.
@@ -64,17 +80,26 @@ pub trait RetryHandler: Send + Sync {
 }
 
 /// Handler for rate limit errors (HTTP 429).
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RateLimitErrorHandler {
     max_attempts: u32,
-    interval_calculator: BackoffIntervalCalculator,
+    interval_calculator: Arc<dyn IntervalCalculator>,
+}
+
+// The interval calculator is user supplied and need not implement Debug
+impl fmt::Debug for RateLimitErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitErrorHandler")
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for RateLimitErrorHandler {
     fn default() -> Self {
         Self {
             max_attempts: 3,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 }
@@ -84,13 +109,19 @@ impl RateLimitErrorHandler {
     pub fn new(max_attempts: u32) -> Self {
         Self {
             max_attempts,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 
     /// Sets the interval calculator.
-    pub fn with_interval_calculator(mut self, calculator: BackoffIntervalCalculator) -> Self {
-        self.interval_calculator = calculator;
+    ///
+    /// Defaults to [`Backoff::default`]: exponential from 1 second with full
+    /// jitter, capped at 30 seconds.
+    pub fn with_interval_calculator(
+        mut self,
+        calculator: impl IntervalCalculator + 'static,
+    ) -> Self {
+        self.interval_calculator = Arc::new(calculator);
         self
     }
 
@@ -152,17 +183,25 @@ impl RetryHandler for RateLimitErrorHandler {
 }
 
 /// Handler for server errors (HTTP 5xx).
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ServerErrorHandler {
     max_attempts: u32,
-    interval_calculator: BackoffIntervalCalculator,
+    interval_calculator: Arc<dyn IntervalCalculator>,
+}
+
+impl fmt::Debug for ServerErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServerErrorHandler")
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for ServerErrorHandler {
     fn default() -> Self {
         Self {
             max_attempts: 3,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 }
@@ -172,13 +211,19 @@ impl ServerErrorHandler {
     pub fn new(max_attempts: u32) -> Self {
         Self {
             max_attempts,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 
     /// Sets the interval calculator.
-    pub fn with_interval_calculator(mut self, calculator: BackoffIntervalCalculator) -> Self {
-        self.interval_calculator = calculator;
+    ///
+    /// Defaults to [`Backoff::default`]: exponential from 1 second with full
+    /// jitter, capped at 30 seconds.
+    pub fn with_interval_calculator(
+        mut self,
+        calculator: impl IntervalCalculator + 'static,
+    ) -> Self {
+        self.interval_calculator = Arc::new(calculator);
         self
     }
 }
@@ -226,17 +271,25 @@ impl RetryHandler for ServerErrorHandler {
 }
 
 /// Handler for connection errors (network failures).
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct ConnectionErrorHandler {
     max_attempts: u32,
-    interval_calculator: BackoffIntervalCalculator,
+    interval_calculator: Arc<dyn IntervalCalculator>,
+}
+
+impl fmt::Debug for ConnectionErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionErrorHandler")
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Default for ConnectionErrorHandler {
     fn default() -> Self {
         Self {
             max_attempts: 3,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 }
@@ -246,13 +299,19 @@ impl ConnectionErrorHandler {
     pub fn new(max_attempts: u32) -> Self {
         Self {
             max_attempts,
-            interval_calculator: BackoffIntervalCalculator::default(),
+            interval_calculator: Arc::new(Backoff::default()),
         }
     }
 
     /// Sets the interval calculator.
-    pub fn with_interval_calculator(mut self, calculator: BackoffIntervalCalculator) -> Self {
-        self.interval_calculator = calculator;
+    ///
+    /// Defaults to [`Backoff::default`]: exponential from 1 second with full
+    /// jitter, capped at 30 seconds.
+    pub fn with_interval_calculator(
+        mut self,
+        calculator: impl IntervalCalculator + 'static,
+    ) -> Self {
+        self.interval_calculator = Arc::new(calculator);
         self
     }
 
@@ -308,6 +367,124 @@ impl RetryHandler for ConnectionErrorHandler {
     }
 }
 
+/// Slack error codes returned with HTTP 200 that mean the call may succeed
+/// if tried again.
+pub const TRANSIENT_API_ERRORS: &[&str] = &[
+    "internal_error",
+    "fatal_error",
+    "service_unavailable",
+    "request_timeout",
+];
+
+/// Handler for transient Slack errors reported in an `ok: false` body.
+///
+/// Slack sometimes answers HTTP 200 with `{"ok": false, "error":
+/// "internal_error"}`, which status-based handlers never retry. This handler
+/// inspects the body of the response (or the state's last response) and
+/// retries when the error code is in its set, which defaults to
+/// [`TRANSIENT_API_ERRORS`].
+#[derive(Clone)]
+pub struct TransientApiErrorHandler {
+    max_attempts: u32,
+    error_codes: Vec<String>,
+    interval_calculator: Arc<dyn IntervalCalculator>,
+}
+
+impl fmt::Debug for TransientApiErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TransientApiErrorHandler")
+            .field("max_attempts", &self.max_attempts)
+            .field("error_codes", &self.error_codes)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for TransientApiErrorHandler {
+    fn default() -> Self {
+        Self::new(2)
+    }
+}
+
+impl TransientApiErrorHandler {
+    /// Creates a new handler with the specified max attempts.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts,
+            error_codes: TRANSIENT_API_ERRORS.iter().map(|c| c.to_string()).collect(),
+            interval_calculator: Arc::new(Backoff::default()),
+        }
+    }
+
+    /// Replaces the set of error codes that are retried.
+    pub fn with_error_codes<I, S>(mut self, codes: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.error_codes = codes.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the interval calculator.
+    ///
+    /// Defaults to [`Backoff::default`]: exponential from 1 second with full
+    /// jitter, capped at 30 seconds.
+    pub fn with_interval_calculator(
+        mut self,
+        calculator: impl IntervalCalculator + 'static,
+    ) -> Self {
+        self.interval_calculator = Arc::new(calculator);
+        self
+    }
+}
+
+impl RetryHandler for TransientApiErrorHandler {
+    fn can_retry(
+        &self,
+        state: &RetryState,
+        response: Option<&HttpResponse>,
+        _error: Option<&str>,
+    ) -> bool {
+        if state.current_attempt >= self.max_attempts {
+            return false;
+        }
+
+        let Some(code) = response
+            .or(state.last_response.as_ref())
+            .and_then(HttpResponse::slack_error)
+        else {
+            return false;
+        };
+
+        if self.error_codes.contains(&code) {
+            warn!(
+                error = code.as_str(),
+                attempt = state.current_attempt,
+                "Transient Slack API error, will retry"
+            );
+            return true;
+        }
+
+        false
+    }
+
+    fn prepare_for_next_attempt(&mut self, state: &mut RetryState) {
+        state.increment_attempt();
+        let interval = self.interval_calculator.calculate(state.current_attempt);
+        state.set_next_attempt(interval);
+
+        info!(
+            attempt = state.current_attempt,
+            wait_secs = interval.as_secs(),
+            "Retrying after transient Slack API error"
+        );
+    }
+
+    fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,7 +581,9 @@ mod tests {
 
     #[test]
     fn test_rate_limit_handler_prepare_for_next_attempt() {
-        let mut handler = RateLimitErrorHandler::new(5);
+        // Without jitter, so the scheduled delay can't round down to zero
+        let mut handler = RateLimitErrorHandler::new(5)
+            .with_interval_calculator(Backoff::exponential(Duration::from_secs(1)));
         let mut state = RetryState::default();
 
         handler.prepare_for_next_attempt(&mut state);
@@ -517,7 +696,9 @@ mod tests {
 
     #[test]
     fn test_connection_error_handler_prepare_for_next_attempt() {
-        let mut handler = ConnectionErrorHandler::new(5);
+        // Without jitter, so the scheduled delay can't round down to zero
+        let mut handler = ConnectionErrorHandler::new(5)
+            .with_interval_calculator(Backoff::exponential(Duration::from_secs(1)));
         let mut state = RetryState::default();
 
         handler.prepare_for_next_attempt(&mut state);
@@ -537,8 +718,27 @@ mod tests {
         assert_eq!(connection_handler.max_attempts(), 7);
     }
 
+    #[test]
+    fn test_interval_calculator_need_not_implement_debug() {
+        struct Fixed;
+
+        impl IntervalCalculator for Fixed {
+            fn calculate(&self, _attempt: u32) -> Duration {
+                Duration::from_millis(10)
+            }
+        }
+
+        let handler = ServerErrorHandler::new(2).with_interval_calculator(Fixed);
+        assert_eq!(
+            format!("{:?}", handler),
+            "ServerErrorHandler { max_attempts: 2, .. }"
+        );
+    }
+
     #[test]
     fn test_handler_with_custom_interval_calculator() {
+        use crate::http_retry::BackoffIntervalCalculator;
+
         let calculator = BackoffIntervalCalculator::new(3.0).with_max_interval(60);
 
         let mut handler = RateLimitErrorHandler::new(5).with_interval_calculator(calculator);
@@ -558,4 +758,41 @@ mod tests {
 
         assert_eq!(state.current_attempt, 1);
     }
+
+    fn json_response(body: serde_json::Value) -> HttpResponse {
+        HttpResponse {
+            status_code: 200,
+            headers: vec![],
+            body: serde_json::to_vec(&body).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_transient_api_error_handler() {
+        let handler = TransientApiErrorHandler::default();
+        let state = RetryState::new();
+
+        let internal = json_response(serde_json::json!({"ok": false, "error": "internal_error"}));
+        assert!(handler.can_retry(&state, Some(&internal), None));
+
+        let not_found =
+            json_response(serde_json::json!({"ok": false, "error": "channel_not_found"}));
+        assert!(!handler.can_retry(&state, Some(&not_found), None));
+
+        let ok = json_response(serde_json::json!({"ok": true}));
+        assert!(!handler.can_retry(&state, Some(&ok), None));
+    }
+
+    #[test]
+    fn test_transient_api_error_handler_uses_state_snapshot_and_limit() {
+        let mut handler = TransientApiErrorHandler::new(1).with_error_codes(["team_added_to_org"]);
+        let mut state = RetryState::new();
+        state.set_last_response(json_response(
+            serde_json::json!({"ok": false, "error": "team_added_to_org"}),
+        ));
+
+        assert!(handler.can_retry(&state, None, None));
+        handler.prepare_for_next_attempt(&mut state);
+        assert!(!handler.can_retry(&state, None, None));
+    }
 }
@@ -3,6 +3,7 @@
 //! This module provides calculators for determining how long to wait between retry attempts.
 
 use rand::Rng;
+use std::sync::Mutex;
 use std::time::Duration;
 
 /// Trait for calculating retry intervals.
@@ -138,6 +139,172 @@ impl IntervalCalculator for RandomJitterCalculator {
     }
 }
 
+/// Composable backoff strategy.
+///
+/// Starts from an exponential (or constant) interval, caps it at an optional
+/// maximum, then applies jitter. Jitter only ever shortens the interval, so
+/// results never exceed the cap.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::http_retry::{Backoff, IntervalCalculator};
+/// use std::time::Duration;
+///
+/// let backoff = Backoff::exponential(Duration::from_millis(500))
+///     .with_jitter(0.2)
+///     .with_max(Duration::from_secs(30));
+///
+/// let wait = backoff.calculate(3);
+/// assert!(wait >= Duration::from_millis(1600) && wait <= Duration::from_secs(2));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    base: Duration,
+    multiplier: f64,
+    jitter_factor: f64,
+    max: Option<Duration>,
+}
+
+impl Default for Backoff {
+    /// Exponential backoff from 1 second with full jitter, capped at 30 seconds.
+    ///
+    /// This is what the default retry handlers use.
+    fn default() -> Self {
+        Self::exponential(Duration::from_secs(1))
+            .with_full_jitter()
+            .with_max(Duration::from_secs(30))
+    }
+}
+
+impl Backoff {
+    /// Creates a backoff that doubles from `base` on each attempt.
+    pub fn exponential(base: Duration) -> Self {
+        Self {
+            base,
+            multiplier: 2.0,
+            jitter_factor: 0.0,
+            max: None,
+        }
+    }
+
+    /// Creates a backoff that waits `interval` on every attempt.
+    pub fn constant(interval: Duration) -> Self {
+        Self::exponential(interval).with_multiplier(1.0)
+    }
+
+    /// Sets the growth factor between attempts.
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Randomly shortens each interval by up to `factor` of its length.
+    ///
+    /// The factor is clamped to 0.0-1.0; `0.2` gives intervals between 80%
+    /// and 100% of the computed value.
+    pub fn with_jitter(mut self, factor: f64) -> Self {
+        self.jitter_factor = factor.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Picks each interval uniformly between zero and the computed value.
+    pub fn with_full_jitter(self) -> Self {
+        self.with_jitter(1.0)
+    }
+
+    /// Caps intervals at `max`.
+    pub fn with_max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Returns the interval for `attempt` before jitter is applied.
+    fn ceiling(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let seconds = self.base.as_secs_f64() * self.multiplier.powi(exponent);
+        let interval = Duration::try_from_secs_f64(seconds).unwrap_or(Duration::MAX);
+        match self.max {
+            Some(max) => interval.min(max),
+            None => interval,
+        }
+    }
+}
+
+impl IntervalCalculator for Backoff {
+    fn calculate(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+
+        let ceiling = self.ceiling(attempt);
+        if self.jitter_factor == 0.0 {
+            return ceiling;
+        }
+
+        let scale = 1.0 - rand::thread_rng().gen::<f64>() * self.jitter_factor;
+        Duration::try_from_secs_f64(ceiling.as_secs_f64() * scale).unwrap_or(ceiling)
+    }
+}
+
+/// Decorrelated jitter interval calculator.
+///
+/// Each interval is drawn between `base` and three times the previous
+/// interval, capped at `max`, so retries spread out without every client
+/// following the same exponential curve. Attempt 1 starts a new sequence.
+///
+/// See: https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/
+#[derive(Debug)]
+pub struct DecorrelatedJitterCalculator {
+    base: Duration,
+    max: Duration,
+    previous: Mutex<Duration>,
+}
+
+impl DecorrelatedJitterCalculator {
+    /// Creates a calculator with the given minimum and maximum intervals.
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self {
+            base,
+            max,
+            previous: Mutex::new(base),
+        }
+    }
+}
+
+impl Clone for DecorrelatedJitterCalculator {
+    fn clone(&self) -> Self {
+        Self {
+            base: self.base,
+            max: self.max,
+            previous: Mutex::new(*self.previous.lock().expect("interval lock poisoned")),
+        }
+    }
+}
+
+impl IntervalCalculator for DecorrelatedJitterCalculator {
+    fn calculate(&self, attempt: u32) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO;
+        }
+
+        let mut previous = self.previous.lock().expect("interval lock poisoned");
+        if attempt == 1 {
+            *previous = self.base;
+        }
+
+        let lower = self.base.min(self.max);
+        let upper = previous.saturating_mul(3).min(self.max).max(lower);
+        let seconds = rand::thread_rng().gen_range(lower.as_secs_f64()..=upper.as_secs_f64());
+        let interval = Duration::try_from_secs_f64(seconds)
+            .unwrap_or(upper)
+            .clamp(lower, upper);
+
+        *previous = interval;
+        interval
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -318,4 +485,99 @@ mod tests {
         assert_eq!(calc1.base_seconds, calc2.base_seconds);
         assert_eq!(calc1.jitter_factor, calc2.jitter_factor);
     }
+
+    #[test]
+    fn test_backoff_without_jitter_is_exact() {
+        let backoff =
+            Backoff::exponential(Duration::from_millis(500)).with_max(Duration::from_secs(3));
+
+        assert_eq!(backoff.calculate(0), Duration::ZERO);
+        assert_eq!(backoff.calculate(1), Duration::from_millis(500));
+        assert_eq!(backoff.calculate(2), Duration::from_secs(1));
+        assert_eq!(backoff.calculate(3), Duration::from_secs(2));
+        assert_eq!(backoff.calculate(4), Duration::from_secs(3));
+        assert_eq!(backoff.calculate(u32::MAX), Duration::from_secs(3));
+
+        let constant = Backoff::constant(Duration::from_secs(2));
+        assert_eq!(constant.calculate(10), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_backoff_jitter_stays_within_bounds() {
+        let backoff = Backoff::exponential(Duration::from_secs(1))
+            .with_jitter(0.2)
+            .with_max(Duration::from_secs(30));
+
+        for attempt in 1..=20 {
+            let ceiling =
+                Duration::from_secs(1 << (attempt - 1).min(5)).min(Duration::from_secs(30));
+            for _ in 0..200 {
+                let interval = backoff.calculate(attempt);
+                assert!(interval <= ceiling, "{:?} > {:?}", interval, ceiling);
+                assert!(interval >= ceiling.mul_f64(0.8), "{:?} too short", interval);
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_backoff_is_full_jitter_capped_at_30s() {
+        let backoff = Backoff::default();
+        let mut longest = Duration::ZERO;
+
+        for attempt in 1..=50 {
+            for _ in 0..100 {
+                let interval = backoff.calculate(attempt);
+                assert!(interval <= Duration::from_secs(30));
+                longest = longest.max(interval);
+            }
+        }
+        // Full jitter still reaches well past the uncapped early attempts
+        assert!(longest > Duration::from_secs(16));
+    }
+
+    #[test]
+    fn test_backoff_jitter_factor_clamped() {
+        assert_eq!(Backoff::default().with_jitter(3.0).jitter_factor, 1.0);
+        assert_eq!(Backoff::default().with_jitter(-1.0).jitter_factor, 0.0);
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_stays_within_bounds() {
+        let base = Duration::from_millis(100);
+        let max = Duration::from_secs(5);
+        let calc = DecorrelatedJitterCalculator::new(base, max);
+
+        for _ in 0..100 {
+            let mut previous = base;
+            for attempt in 1..=30 {
+                let interval = calc.calculate(attempt);
+                let upper = (previous * 3).min(max);
+                assert!(interval >= base, "{:?} below base", interval);
+                assert!(interval <= upper, "{:?} above {:?}", interval, upper);
+                previous = interval;
+            }
+        }
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_restarts_at_first_attempt() {
+        let calc =
+            DecorrelatedJitterCalculator::new(Duration::from_secs(1), Duration::from_secs(60));
+        for attempt in 1..=20 {
+            calc.calculate(attempt);
+        }
+
+        for _ in 0..100 {
+            assert!(calc.calculate(1) <= Duration::from_secs(3));
+        }
+        assert_eq!(calc.calculate(0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_decorrelated_jitter_base_above_max() {
+        let calc =
+            DecorrelatedJitterCalculator::new(Duration::from_secs(10), Duration::from_secs(2));
+        assert_eq!(calc.calculate(1), Duration::from_secs(2));
+        assert_eq!(calc.clone().calculate(2), Duration::from_secs(2));
+    }
 }
@@ -38,8 +38,12 @@ pub mod state;
 // Re-export commonly used types
 pub use handler::{
     ConnectionErrorHandler, HttpResponse, RateLimitErrorHandler, RetryHandler, ServerErrorHandler,
+    TransientApiErrorHandler, TRANSIENT_API_ERRORS,
+};
+pub use interval::{
+    Backoff, BackoffIntervalCalculator, DecorrelatedJitterCalculator, IntervalCalculator,
+    RandomJitterCalculator,
 };
-pub use interval::{BackoffIntervalCalculator, IntervalCalculator, RandomJitterCalculator};
 pub use state::RetryState;
 
 /// Creates a default set of retry handlers.
@@ -48,11 +52,14 @@ pub use state::RetryState;
 /// - Rate limit errors (HTTP 429)
 /// - Server errors (HTTP 5xx)
 /// - Connection errors
+/// - Transient Slack errors in an `ok: false` body (`internal_error` and
+///   similar), with at most 2 attempts
 pub fn default_retry_handlers() -> Vec<Box<dyn RetryHandler + Send + Sync>> {
     vec![
         Box::new(RateLimitErrorHandler::default()),
         Box::new(ServerErrorHandler::default()),
         Box::new(ConnectionErrorHandler::default()),
+        Box::new(TransientApiErrorHandler::default()),
     ]
 }
 
@@ -63,16 +70,18 @@ mod tests {
     #[test]
     fn test_default_retry_handlers_count() {
         let handlers = default_retry_handlers();
-        assert_eq!(handlers.len(), 3);
+        assert_eq!(handlers.len(), 4);
     }
 
     #[test]
     fn test_default_retry_handlers_max_attempts() {
-        let handlers = default_retry_handlers();
+        let attempts: Vec<u32> = default_retry_handlers()
+            .iter()
+            .map(|handler| handler.max_attempts())
+            .collect();
 
-        for handler in handlers {
-            // All default handlers should have 3 max attempts
-            assert_eq!(handler.max_attempts(), 3);
-        }
+        // Status and connection handlers allow 3 attempts; the body-based
+        // transient error handler is kept to 2
+        assert_eq!(attempts, [3, 3, 3, 2]);
     }
 }
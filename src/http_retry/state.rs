@@ -2,6 +2,9 @@
 //!
 //! This module provides the state machine for tracking retry attempts and timing.
 
+use super::handler::HttpResponse;
+use crate::clock::{self, Clock};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 /// The state of a retry operation.
@@ -21,6 +24,13 @@ pub struct RetryState {
 
     /// The error from the last attempt, if any.
     pub last_error: Option<String>,
+
+    /// A snapshot of the last response, so handlers can inspect its body.
+    pub last_response: Option<HttpResponse>,
+
+    /// The clock used for timing decisions, set with
+    /// [`with_clock`](Self::with_clock).
+    pub(crate) clock: Arc<dyn Clock>,
 }
 
 impl Default for RetryState {
@@ -31,6 +41,8 @@ impl Default for RetryState {
             next_attempt_requested_at: now,
             first_attempt_at: now,
             last_error: None,
+            last_response: None,
+            clock: clock::system_clock(),
         }
     }
 }
@@ -41,6 +53,18 @@ impl RetryState {
         Self::default()
     }
 
+    /// Sets the clock used for timing decisions.
+    ///
+    /// The first and next attempt times are reset to the clock's current time.
+    /// Defaults to the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        let now = clock.instant();
+        self.first_attempt_at = now;
+        self.next_attempt_requested_at = now;
+        self.clock = clock;
+        self
+    }
+
     /// Increments the attempt counter.
     pub fn increment_attempt(&mut self) {
         self.current_attempt += 1;
@@ -48,23 +72,23 @@ impl RetryState {
 
     /// Sets when the next attempt should be made.
     pub fn set_next_attempt(&mut self, duration: Duration) {
-        self.next_attempt_requested_at = Instant::now() + duration;
+        self.next_attempt_requested_at = self.clock.instant() + duration;
     }
 
     /// Returns the duration since the first attempt.
     pub fn elapsed_since_first_attempt(&self) -> Duration {
-        Instant::now().duration_since(self.first_attempt_at)
+        self.clock.instant().duration_since(self.first_attempt_at)
     }
 
     /// Returns the duration until the next attempt should be made.
     pub fn duration_until_next_attempt(&self) -> Duration {
         self.next_attempt_requested_at
-            .saturating_duration_since(Instant::now())
+            .saturating_duration_since(self.clock.instant())
     }
 
     /// Returns whether it's time to make the next attempt.
     pub fn should_attempt_now(&self) -> bool {
-        Instant::now() >= self.next_attempt_requested_at
+        self.clock.instant() >= self.next_attempt_requested_at
     }
 
     /// Sets the last error message.
@@ -72,6 +96,11 @@ impl RetryState {
         self.last_error = Some(error.into());
     }
 
+    /// Records the last response for handlers that inspect its body.
+    pub fn set_last_response(&mut self, response: HttpResponse) {
+        self.last_response = Some(response);
+    }
+
     /// Clears the last error.
     pub fn clear_last_error(&mut self) {
         self.last_error = None;
@@ -81,6 +110,7 @@ impl RetryState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use std::thread::sleep;
 
     #[test]
@@ -135,6 +165,21 @@ mod tests {
         assert!(elapsed >= Duration::from_millis(10));
     }
 
+    #[test]
+    fn test_with_mock_clock() {
+        let clock = MockClock::default();
+        let mut state = RetryState::new().with_clock(Arc::new(clock.clone()));
+
+        state.set_next_attempt(Duration::from_secs(5));
+        assert_eq!(state.duration_until_next_attempt(), Duration::from_secs(5));
+        assert!(!state.should_attempt_now());
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(state.duration_until_next_attempt(), Duration::ZERO);
+        assert_eq!(state.elapsed_since_first_attempt(), Duration::from_secs(5));
+        assert!(state.should_attempt_now());
+    }
+
     #[test]
     fn test_should_attempt_now_initially_true() {
         let state = RetryState::new();
@@ -44,21 +44,30 @@
 //!
 //! ## Modules
 //!
+//! - [`clock`]: Injectable time sources for expiry and retry logic
+//! - [`environment`]: API hosts for commercial Slack, GovSlack and custom deployments
 //! - [`error`]: Error types for the SDK
 //! - [`http_retry`]: HTTP retry logic with handlers and state management
 //! - [`logging`]: Logging infrastructure using `tracing`
+//! - [`payloads`]: Typed slash command, interactive component and event payloads
+//! - [`text`]: Mention and formatting helpers for mrkdwn text
 //! - [`webhook`]: Webhook clients for incoming webhooks and response URLs
 
 pub mod audit_logs;
+pub mod clock;
 pub mod constants;
+pub mod environment;
 pub mod error;
+pub mod events;
 pub mod http_retry;
 pub mod logging;
 pub mod models;
 pub mod oauth;
+pub mod payloads;
 pub mod scim;
 pub mod signature;
 pub mod socket_mode;
+pub mod text;
 pub mod web;
 pub mod webhook;
 
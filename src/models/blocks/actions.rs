@@ -1,18 +1,25 @@
 //! Actions block implementation.
 
+use super::check_block_id;
 use crate::constants::limits::MAX_ACTIONS_ELEMENTS;
 use crate::error::{Result, SlackError};
+use crate::models::elements::ActionElement;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// An actions block for holding interactive elements.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ActionsBlock {
     /// The type of block (always "actions").
     #[serde(rename = "type")]
     pub block_type: String,
 
     /// An array of interactive element objects (max 25).
+    ///
+    /// Elements are kept as raw JSON so that any element type, including
+    /// ones this crate does not model, round-trips unchanged; parse them
+    /// with [`BlockElement`](crate::models::BlockElement) for typed access.
     pub elements: Vec<Value>,
 
     /// An optional unique identifier for the block.
@@ -47,11 +54,62 @@ impl ActionsBlock {
         })
     }
 
+    /// Creates a new actions block builder.
+    pub fn builder() -> ActionsBlockBuilder {
+        ActionsBlockBuilder::default()
+    }
+
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
+    }
+}
+
+/// Builder for actions blocks from typed elements.
+#[derive(Debug, Default)]
+pub struct ActionsBlockBuilder {
+    elements: Vec<ActionElement>,
+    block_id: Option<String>,
+}
+
+impl ActionsBlockBuilder {
+    /// Adds an element to the block.
+    ///
+    /// Returns a validation error if the block already holds the maximum
+    /// number of elements.
+    pub fn element(mut self, element: impl Into<ActionElement>) -> Result<Self> {
+        if self.elements.len() >= MAX_ACTIONS_ELEMENTS {
+            return Err(SlackError::Validation(format!(
+                "ActionsBlock cannot have more than {} elements",
+                MAX_ACTIONS_ELEMENTS
+            )));
+        }
+
+        self.elements.push(element.into());
+        Ok(self)
+    }
+
     /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
+    pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
         self.block_id = Some(block_id.into());
         self
     }
+
+    /// Builds the actions block.
+    pub fn build(self) -> Result<ActionsBlock> {
+        let elements = self
+            .elements
+            .iter()
+            .map(serde_json::to_value)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let block = ActionsBlock::new(elements)?;
+        match self.block_id {
+            Some(block_id) => block.with_block_id(block_id),
+            None => Ok(block),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +212,8 @@ mod tests {
 
         let block = ActionsBlock::new(elements)
             .unwrap()
-            .with_block_id("actions_1");
+            .with_block_id("actions_1")
+            .unwrap();
 
         assert_eq!(block.block_id, Some("actions_1".to_string()));
     }
@@ -178,4 +237,42 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_actions_block_builder_typed_elements() {
+        use crate::models::elements::{ButtonElement, DatePickerElement};
+
+        let block = ActionsBlock::builder()
+            .element(ButtonElement::new("Approve", "approve").unwrap())
+            .unwrap()
+            .element(DatePickerElement::new("due").unwrap())
+            .unwrap()
+            .block_id("actions_1")
+            .build()
+            .unwrap();
+
+        let output = serde_json::to_value(&block).unwrap();
+        assert_eq!(output["block_id"], "actions_1");
+        assert_eq!(output["elements"][0]["type"], "button");
+        assert_eq!(output["elements"][1]["type"], "datepicker");
+        assert_eq!(output["elements"][1]["action_id"], "due");
+    }
+
+    #[test]
+    fn test_actions_block_builder_limits() {
+        use crate::models::elements::ButtonElement;
+
+        assert!(ActionsBlock::builder().build().is_err());
+
+        let mut builder = ActionsBlock::builder();
+        for i in 0..MAX_ACTIONS_ELEMENTS {
+            builder = builder
+                .element(ButtonElement::new("Button", format!("btn_{}", i)).unwrap())
+                .unwrap();
+        }
+        let overflow = builder
+            .element(ButtonElement::new("Button", "one_too_many").unwrap())
+            .unwrap_err();
+        assert!(matches!(overflow, SlackError::Validation(_)));
+    }
 }
@@ -1,10 +1,73 @@
 //! Context block implementation.
 
+use super::check_block_id;
 use crate::constants::limits::MAX_CONTEXT_ELEMENTS;
 use crate::error::{Result, SlackError};
-use serde::{Deserialize, Serialize};
+use crate::models::elements::ImageElement;
+use crate::models::objects::TextObject;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
+/// An element that can be placed in a context block: a text object or an
+/// image.
+///
+/// Each variant serialises as the wrapped value, so its own `type` field
+/// (`plain_text`, `mrkdwn` or `image`) is the discriminant on the wire.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContextElement {
+    /// A `plain_text` or `mrkdwn` text object.
+    Text(TextObject),
+
+    /// An image element.
+    Image(ImageElement),
+}
+
+impl ContextElement {
+    /// The `type` values accepted when deserialising.
+    const TYPES: &'static [&'static str] = &["plain_text", "mrkdwn", "image"];
+}
+
+impl Serialize for ContextElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Text(text) => text.serialize(serializer),
+            Self::Image(image) => image.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ContextElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = Value::deserialize(deserializer)?;
+        let element_type = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| D::Error::missing_field("type"))?
+            .to_string();
+
+        let element = match element_type.as_str() {
+            "plain_text" | "mrkdwn" => serde_json::from_value(value).map(Self::Text),
+            "image" => serde_json::from_value(value).map(Self::Image),
+            other => return Err(D::Error::unknown_variant(other, Self::TYPES)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+impl From<TextObject> for ContextElement {
+    fn from(text: TextObject) -> Self {
+        Self::Text(text)
+    }
+}
+
+impl From<ImageElement> for ContextElement {
+    fn from(image: ImageElement) -> Self {
+        Self::Image(image)
+    }
+}
+
 /// A context block for displaying contextual information.
 ///
 /// Context blocks are used to add contextual information, typically shown in a muted color.
@@ -14,8 +77,8 @@ pub struct ContextBlock {
     #[serde(rename = "type")]
     pub block_type: String,
 
-    /// An array of image elements and text objects (max 10).
-    pub elements: Vec<Value>,
+    /// Text objects and images (max 10).
+    pub elements: Vec<ContextElement>,
 
     /// An optional unique identifier for the block.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -26,8 +89,8 @@ impl ContextBlock {
     /// Creates a new context block.
     ///
     /// # Arguments
-    /// * `elements` - Array of elements (max 10)
-    pub fn new(elements: Vec<Value>) -> Result<Self> {
+    /// * `elements` - Array of elements (1 to 10)
+    pub fn new(elements: Vec<ContextElement>) -> Result<Self> {
         if elements.is_empty() {
             return Err(SlackError::Validation(
                 "ContextBlock must have at least one element".to_string(),
@@ -49,10 +112,35 @@ impl ContextBlock {
         })
     }
 
-    /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
-        self.block_id = Some(block_id.into());
-        self
+    /// Appends a text object.
+    ///
+    /// Returns a validation error if the block already has 10 elements.
+    pub fn with_text(self, text: TextObject) -> Result<Self> {
+        self.push(ContextElement::Text(text))
+    }
+
+    /// Appends an image.
+    ///
+    /// Returns a validation error if the block already has 10 elements.
+    pub fn with_image(self, image: ImageElement) -> Result<Self> {
+        self.push(ContextElement::Image(image))
+    }
+
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
+    }
+
+    fn push(mut self, element: ContextElement) -> Result<Self> {
+        if self.elements.len() >= MAX_CONTEXT_ELEMENTS {
+            return Err(SlackError::Validation(format!(
+                "ContextBlock cannot have more than {} elements",
+                MAX_CONTEXT_ELEMENTS
+            )));
+        }
+        self.elements.push(element);
+        Ok(self)
     }
 }
 
@@ -62,12 +150,13 @@ mod tests {
     use pretty_assertions::assert_eq;
     use serde_json::json;
 
+    fn text(s: &str) -> ContextElement {
+        TextObject::markdown(s).unwrap().into()
+    }
+
     #[test]
     fn test_context_block_basic() {
-        let elements = vec![json!({
-            "type": "mrkdwn",
-            "text": "Context information"
-        })];
+        let elements = vec![text("Context information")];
 
         let block = ContextBlock::new(elements).unwrap();
 
@@ -96,9 +185,11 @@ mod tests {
     #[test]
     fn test_context_block_with_multiple_elements() {
         let elements = vec![
-            json!({"type": "mrkdwn", "text": "Text 1"}),
-            json!({"type": "image", "image_url": "https://example.com/img.png", "alt_text": "img"}),
-            json!({"type": "mrkdwn", "text": "Text 2"}),
+            text("Text 1"),
+            ImageElement::new("https://example.com/img.png", "img")
+                .unwrap()
+                .into(),
+            text("Text 2"),
         ];
 
         let block = ContextBlock::new(elements).unwrap();
@@ -114,9 +205,7 @@ mod tests {
 
     #[test]
     fn test_context_block_max_elements_validation() {
-        let elements: Vec<Value> = (0..11)
-            .map(|i| json!({"type": "mrkdwn", "text": format!("Text {}", i)}))
-            .collect();
+        let elements: Vec<ContextElement> = (0..11).map(|i| text(&format!("Text {}", i))).collect();
 
         let result = ContextBlock::new(elements);
         assert!(result.is_err());
@@ -124,9 +213,7 @@ mod tests {
 
     #[test]
     fn test_context_block_exactly_10_elements_ok() {
-        let elements: Vec<Value> = (0..10)
-            .map(|i| json!({"type": "mrkdwn", "text": format!("Text {}", i)}))
-            .collect();
+        let elements: Vec<ContextElement> = (0..10).map(|i| text(&format!("Text {}", i))).collect();
 
         let result = ContextBlock::new(elements);
         assert!(result.is_ok());
@@ -134,10 +221,11 @@ mod tests {
 
     #[test]
     fn test_context_block_with_block_id() {
-        let elements = vec![json!({"type": "mrkdwn", "text": "Text"})];
+        let elements = vec![text("Text")];
         let block = ContextBlock::new(elements)
             .unwrap()
-            .with_block_id("context_1");
+            .with_block_id("context_1")
+            .unwrap();
 
         assert_eq!(block.block_id, Some("context_1".to_string()));
     }
@@ -157,4 +245,64 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_context_block_appenders_enforce_limit() {
+        let mut block = ContextBlock::new(vec![text("Text 0")]).unwrap();
+        for i in 1..MAX_CONTEXT_ELEMENTS {
+            block = block
+                .with_text(TextObject::plain(format!("Text {}", i)).unwrap())
+                .unwrap();
+        }
+        assert_eq!(block.elements.len(), MAX_CONTEXT_ELEMENTS);
+
+        let image = ImageElement::new("https://example.com/img.png", "img").unwrap();
+        let result = block.with_image(image);
+        assert!(matches!(result, Err(SlackError::Validation(_))));
+    }
+
+    #[test]
+    fn test_context_block_documented_example_round_trip() {
+        // Example from https://api.slack.com/reference/block-kit/blocks#context
+        let input = json!({
+            "type": "context",
+            "elements": [
+                {
+                    "type": "image",
+                    "image_url": "https://image.freepik.com/free-photo/red-drawing-pin_1156-445.jpg",
+                    "alt_text": "images"
+                },
+                {
+                    "type": "mrkdwn",
+                    "text": "Location: **Dogpatch**"
+                },
+                {
+                    "type": "plain_text",
+                    "text": "Last updated today",
+                    "emoji": true
+                }
+            ]
+        });
+
+        let block: ContextBlock = serde_json::from_value(input.clone()).unwrap();
+        assert!(matches!(block.elements[0], ContextElement::Image(_)));
+        assert!(matches!(
+            block.elements[1],
+            ContextElement::Text(TextObject::Markdown { .. })
+        ));
+        assert!(matches!(
+            block.elements[2],
+            ContextElement::Text(TextObject::PlainText { .. })
+        ));
+        assert_eq!(serde_json::to_value(&block).unwrap(), input);
+    }
+
+    #[test]
+    fn test_context_block_rejects_unsupported_element() {
+        let input = json!({
+            "type": "context",
+            "elements": [{"type": "button", "text": {"type": "plain_text", "text": "Go"}}]
+        });
+        assert!(serde_json::from_value::<ContextBlock>(input).is_err());
+    }
 }
@@ -1,5 +1,7 @@
 //! Divider block implementation.
 
+use super::check_block_id;
+use crate::error::Result;
 use serde::{Deserialize, Serialize};
 
 /// A visual divider to split up blocks.
@@ -31,10 +33,10 @@ impl DividerBlock {
         }
     }
 
-    /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
-        self.block_id = Some(block_id.into());
-        self
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
     }
 }
 
@@ -54,7 +56,7 @@ mod tests {
 
     #[test]
     fn test_divider_block_with_id() {
-        let block = DividerBlock::new().with_block_id("foo");
+        let block = DividerBlock::new().with_block_id("foo").unwrap();
         let json_val = serde_json::to_value(&block).unwrap();
 
         assert_eq!(
@@ -85,7 +87,7 @@ mod tests {
 
     #[test]
     fn test_divider_block_clone() {
-        let block1 = DividerBlock::new().with_block_id("id1");
+        let block1 = DividerBlock::new().with_block_id("id1").unwrap();
         let block2 = block1.clone();
 
         assert_eq!(block1, block2);
@@ -1,5 +1,6 @@
 //! Header block implementation.
 
+use super::check_block_id;
 use crate::constants::limits::MAX_HEADER_TEXT_LENGTH;
 use crate::error::{Result, SlackError};
 use crate::models::objects::TextObject;
@@ -50,10 +51,10 @@ impl HeaderBlock {
         })
     }
 
-    /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
-        self.block_id = Some(block_id.into());
-        self
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
     }
 }
 
@@ -84,7 +85,8 @@ mod tests {
     fn test_header_block_with_id() {
         let block = HeaderBlock::new("Header")
             .unwrap()
-            .with_block_id("header_1");
+            .with_block_id("header_1")
+            .unwrap();
 
         let json_val = serde_json::to_value(&block).unwrap();
         assert_eq!(json_val["block_id"], "header_1");
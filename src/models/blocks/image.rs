@@ -1,5 +1,6 @@
 //! Image block implementation.
 
+use super::check_block_id;
 use crate::error::{Result, SlackError};
 use crate::models::objects::TextObject;
 use serde::{Deserialize, Serialize};
@@ -26,6 +27,8 @@ pub struct ImageBlock {
     pub image_url: Option<String>,
 
     /// Alternative representation using Slack file object.
+    ///
+    /// Kept as raw JSON so that file fields Slack adds survive a round trip.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slack_file: Option<Value>,
 
@@ -113,10 +116,10 @@ impl ImageBlock {
         Ok(self)
     }
 
-    /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
-        self.block_id = Some(block_id.into());
-        self
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
     }
 }
 
@@ -167,7 +170,8 @@ mod tests {
     fn test_image_block_with_block_id() {
         let block = ImageBlock::new("https://example.com/img.png", "Alt")
             .unwrap()
-            .with_block_id("img_1");
+            .with_block_id("img_1")
+            .unwrap();
 
         assert_eq!(block.block_id, Some("img_1".to_string()));
     }
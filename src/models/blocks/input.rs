@@ -1,5 +1,6 @@
 //! Input block implementation.
 
+use super::check_block_id;
 use crate::error::{Result, SlackError};
 use crate::models::objects::TextObject;
 use serde::{Deserialize, Serialize};
@@ -21,7 +22,8 @@ pub struct InputBlock {
     /// A label that appears above the input element (required, max 2000 characters).
     pub label: TextObject,
 
-    /// An interactive input element (plain_text_input, select menus, etc.).
+    /// An interactive input element (plain_text_input, select menus, etc.),
+    /// kept as raw JSON like [`ActionsBlock::elements`](super::ActionsBlock::elements).
     pub element: Value,
 
     /// Whether the input element may be empty when submitted.
@@ -95,10 +97,10 @@ impl InputBlock {
         self
     }
 
-    /// Sets the block ID.
-    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Self {
-        self.block_id = Some(block_id.into());
-        self
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
     }
 }
 
@@ -180,7 +182,8 @@ mod tests {
         let element = json!({"type": "plain_text_input", "action_id": "input_1"});
         let block = InputBlock::new("Label", element)
             .unwrap()
-            .with_block_id("input_block_1");
+            .with_block_id("input_block_1")
+            .unwrap();
 
         assert_eq!(block.block_id, Some("input_block_1".to_string()));
     }
@@ -10,10 +10,355 @@ pub mod image;
 pub mod input;
 pub mod section;
 
-pub use actions::ActionsBlock;
-pub use context::ContextBlock;
+pub use actions::{ActionsBlock, ActionsBlockBuilder};
+pub use context::{ContextBlock, ContextElement};
 pub use divider::DividerBlock;
 pub use header::HeaderBlock;
 pub use image::ImageBlock;
 pub use input::InputBlock;
 pub use section::SectionBlock;
+
+use crate::constants::limits::{MAX_BLOCK_ID_LENGTH, MAX_MESSAGE_BLOCKS};
+use crate::error::{Result, SlackError};
+use crate::models::validation::validate_block_ids;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// Checks a block ID against Slack's 255-character limit.
+pub(crate) fn check_block_id(block_id: String) -> Result<String> {
+    if block_id.len() > MAX_BLOCK_ID_LENGTH {
+        return Err(SlackError::Validation(format!(
+            "block_id length {} exceeds maximum {}",
+            block_id.len(),
+            MAX_BLOCK_ID_LENGTH
+        )));
+    }
+    Ok(block_id)
+}
+
+macro_rules! blocks {
+    ($($variant:ident($block:ident) => $name:literal,)*) => {
+        /// Any Block Kit block, identified by its `type` field.
+        ///
+        /// Block types this crate does not model are kept as
+        /// [`Block::Unknown`] so that payloads from newer Slack features
+        /// still parse.
+        #[derive(Debug, Clone, PartialEq)]
+        #[non_exhaustive]
+        pub enum Block {
+            $(
+                #[doc = concat!("A `", $name, "` block.")]
+                $variant($block),
+            )*
+            /// A block type this crate does not model, kept as raw JSON.
+            Unknown(Value),
+        }
+
+        impl Block {
+            /// Returns the block's `type` value.
+            pub fn block_type(&self) -> &str {
+                match self {
+                    $(Self::$variant(b) => &b.block_type,)*
+                    Self::Unknown(value) => value.get("type").and_then(Value::as_str).unwrap_or(""),
+                }
+            }
+
+            /// Returns the block's `block_id`, if set.
+            pub fn block_id(&self) -> Option<&str> {
+                match self {
+                    $(Self::$variant(b) => b.block_id.as_deref(),)*
+                    Self::Unknown(value) => value.get("block_id").and_then(Value::as_str),
+                }
+            }
+
+            /// Sets the block's `block_id` in place, e.g. to resolve a
+            /// conflict found by
+            /// [`validate_block_ids`](crate::models::validation::validate_block_ids).
+            ///
+            /// # Errors
+            ///
+            /// Returns `SlackError::Validation` if the ID is longer than 255
+            /// characters.
+            pub fn set_block_id(&mut self, block_id: impl Into<String>) -> Result<()> {
+                let block_id = check_block_id(block_id.into())?;
+                match self {
+                    $(Self::$variant(b) => b.block_id = Some(block_id),)*
+                    Self::Unknown(value) => {
+                        if let Some(object) = value.as_object_mut() {
+                            object.insert("block_id".to_string(), Value::String(block_id));
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        impl Serialize for Block {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$variant(b) => b.serialize(serializer),)*
+                    Self::Unknown(value) => value.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for Block {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let value = Value::deserialize(deserializer)?;
+                let block_type = value
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| D::Error::missing_field("type"))?;
+
+                match block_type {
+                    $($name => serde_json::from_value(value).map(Self::$variant).map_err(D::Error::custom),)*
+                    _ => Ok(Self::Unknown(value)),
+                }
+            }
+        }
+
+        $(
+            impl From<$block> for Block {
+                fn from(block: $block) -> Self {
+                    Self::$variant(block)
+                }
+            }
+        )*
+    };
+}
+
+blocks! {
+    Actions(ActionsBlock) => "actions",
+    Context(ContextBlock) => "context",
+    Divider(DividerBlock) => "divider",
+    Header(HeaderBlock) => "header",
+    Image(ImageBlock) => "image",
+    Input(InputBlock) => "input",
+    Section(SectionBlock) => "section",
+}
+
+/// Checks the message-level limits of a list of blocks in one pass: at most
+/// 50 blocks, each `block_id` at most 255 characters, and no `block_id`
+/// used twice.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::blocks::{validate_blocks, Block, DividerBlock};
+///
+/// let blocks: Vec<Block> = vec![
+///     DividerBlock::new().with_block_id("a")?.into(),
+///     DividerBlock::new().with_block_id("a")?.into(),
+/// ];
+/// assert!(validate_blocks(&blocks).is_err());
+/// # Ok::<(), slack_rs::error::SlackError>(())
+/// ```
+pub fn validate_blocks(blocks: &[Block]) -> Result<()> {
+    if blocks.len() > MAX_MESSAGE_BLOCKS {
+        return Err(SlackError::Validation(format!(
+            "message has {} blocks, maximum is {}",
+            blocks.len(),
+            MAX_MESSAGE_BLOCKS
+        )));
+    }
+
+    for block_id in blocks.iter().filter_map(Block::block_id) {
+        check_block_id(block_id.to_string())?;
+    }
+
+    validate_block_ids(blocks).map_err(|conflicts| {
+        let conflict = &conflicts[0];
+        SlackError::Validation(format!(
+            "duplicate block_id '{}' at block {}",
+            conflict.block_id, conflict.indices[1]
+        ))
+    })
+}
+
+/// Runs [`validate_blocks`] on a JSON `blocks` array, as held by the
+/// message builders. Values that are not arrays are left to Slack.
+///
+/// Only the block count and `block_id`s are checked; the blocks are not
+/// parsed, so fields this crate's block types would reject still reach
+/// Slack as given.
+pub(crate) fn validate_block_values(blocks: &Value) -> Result<()> {
+    let Some(blocks) = blocks.as_array() else {
+        return Ok(());
+    };
+    let blocks: Vec<Block> = blocks.iter().cloned().map(Block::Unknown).collect();
+    validate_blocks(&blocks)
+}
+
+/// Unwraps a `blocks` value sent as a JSON-encoded string, as Slack does in
+/// form-encoded payloads, into the array it contains. Other values are
+/// returned unchanged.
+pub(crate) fn blocks_value(blocks: Value) -> Value {
+    match &blocks {
+        Value::String(encoded) => match serde_json::from_str(encoded) {
+            Ok(array @ Value::Array(_)) => array,
+            _ => blocks,
+        },
+        _ => blocks,
+    }
+}
+
+/// Serializes the blocks passed to a message builder, decoding a
+/// JSON-encoded string. A serialization error is kept so the builder can
+/// report it when the message is built.
+pub(crate) fn builder_blocks<T: Serialize>(blocks: T) -> std::result::Result<Value, String> {
+    serde_json::to_value(blocks)
+        .map(blocks_value)
+        .map_err(|e| e.to_string())
+}
+
+/// Returns the blocks held by a message builder, failing with the error
+/// from setting them if they could not be serialized.
+pub(crate) fn checked_builder_blocks(
+    blocks: &Option<std::result::Result<Value, String>>,
+) -> Result<Option<&Value>> {
+    match blocks {
+        Some(Ok(blocks)) => Ok(Some(blocks)),
+        Some(Err(e)) => Err(SlackError::Validation(format!(
+            "blocks could not be serialized: {}",
+            e
+        ))),
+        None => Ok(None),
+    }
+}
+
+/// Deserializes a `blocks` field given either as an array or as a string
+/// containing a JSON array. A missing or null field yields no blocks.
+pub(crate) fn deserialize_block_values<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(Vec::new()),
+        Value::Array(blocks) => Ok(blocks),
+        Value::String(encoded) => serde_json::from_str(&encoded)
+            .map_err(|e| D::Error::custom(format!("invalid blocks string: {}", e))),
+        other => Err(D::Error::custom(format!(
+            "expected blocks array or string, got {}",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_block_dispatches_on_type() {
+        let blocks: Vec<Block> = serde_json::from_value(json!([
+            {"type": "divider", "block_id": "d1"},
+            {"type": "header", "text": {"type": "plain_text", "text": "Hi"}},
+            {"type": "rich_text", "block_id": "r1", "elements": []}
+        ]))
+        .unwrap();
+
+        assert!(matches!(blocks[0], Block::Divider(_)));
+        assert!(matches!(blocks[1], Block::Header(_)));
+        assert!(matches!(blocks[2], Block::Unknown(_)));
+        assert_eq!(blocks[2].block_type(), "rich_text");
+        assert_eq!(blocks[2].block_id(), Some("r1"));
+    }
+
+    #[test]
+    fn test_validate_blocks_limit() {
+        let blocks: Vec<Block> = (0..MAX_MESSAGE_BLOCKS)
+            .map(|_| DividerBlock::new().into())
+            .collect();
+        assert!(validate_blocks(&blocks).is_ok());
+
+        let mut too_many = blocks;
+        too_many.push(DividerBlock::new().into());
+        assert!(matches!(
+            validate_blocks(&too_many),
+            Err(SlackError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_validate_blocks_duplicate_block_id() {
+        let blocks: Vec<Block> = vec![
+            DividerBlock::new().with_block_id("a").unwrap().into(),
+            HeaderBlock::new("Title")
+                .unwrap()
+                .with_block_id("b")
+                .unwrap()
+                .into(),
+            Block::Unknown(json!({"type": "rich_text", "block_id": "a"})),
+        ];
+
+        let err = validate_blocks(&blocks).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("duplicate block_id 'a' at block 2"));
+    }
+
+    #[test]
+    fn test_block_id_length_limit() {
+        let long_id = "x".repeat(MAX_BLOCK_ID_LENGTH + 1);
+        assert!(DividerBlock::new().with_block_id(long_id.clone()).is_err());
+        assert!(HeaderBlock::new("Title")
+            .unwrap()
+            .with_block_id(long_id.clone())
+            .is_err());
+        assert!(ImageBlock::new("https://example.com/a.png", "alt")
+            .unwrap()
+            .with_block_id(long_id.clone())
+            .is_err());
+        assert!(SectionBlock::builder()
+            .text("Hi")
+            .unwrap()
+            .block_id(long_id.clone())
+            .build()
+            .is_err());
+        assert!(DividerBlock::new()
+            .with_block_id("x".repeat(MAX_BLOCK_ID_LENGTH))
+            .is_ok());
+
+        let unknown = Block::Unknown(json!({"type": "rich_text", "block_id": long_id}));
+        assert!(validate_blocks(&[unknown]).is_err());
+    }
+
+    #[test]
+    fn test_validate_block_values() {
+        assert!(validate_block_values(&json!([{"type": "divider"}])).is_ok());
+        assert!(validate_block_values(&json!([
+            {"type": "divider", "block_id": "a"},
+            {"type": "divider", "block_id": "a"}
+        ]))
+        .is_err());
+        assert!(validate_block_values(&json!("[]")).is_ok());
+
+        // Raw blocks are not parsed, only their count and IDs are checked
+        assert!(validate_block_values(&json!([
+            {"type": "header", "text": {"type": "plain_text", "text": "x".repeat(200)}},
+            {"type": "section", "text": "not an object"}
+        ]))
+        .is_ok());
+        let too_many: Vec<Value> = (0..=MAX_MESSAGE_BLOCKS)
+            .map(|_| json!({"type": "divider"}))
+            .collect();
+        assert!(validate_block_values(&Value::Array(too_many)).is_err());
+    }
+
+    #[test]
+    fn test_blocks_value_unwraps_encoded_arrays() {
+        let array = json!([{"type": "divider"}]);
+        assert_eq!(blocks_value(Value::String(array.to_string())), array);
+        assert_eq!(blocks_value(array.clone()), array);
+        assert_eq!(blocks_value(json!("not json")), json!("not json"));
+        assert_eq!(blocks_value(json!("{}")), json!("{}"));
+    }
+}
@@ -1,6 +1,7 @@
 //! Section block implementation.
 
-use crate::constants::limits::MAX_SECTION_FIELDS;
+use super::check_block_id;
+use crate::constants::limits::{MAX_SECTION_FIELDS, MAX_SECTION_FIELD_LENGTH};
 use crate::error::{Result, SlackError};
 use crate::models::objects::TextObject;
 use serde::{Deserialize, Serialize};
@@ -10,6 +11,7 @@ use serde_json::Value;
 ///
 /// One of the most commonly used blocks. Can contain text, fields, and an accessory.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct SectionBlock {
     /// The type of block (always "section").
     #[serde(rename = "type")]
@@ -23,7 +25,8 @@ pub struct SectionBlock {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fields: Option<Vec<TextObject>>,
 
-    /// An accessory element (button, select, image, datepicker, etc.).
+    /// An accessory element (button, select, image, datepicker, etc.),
+    /// kept as raw JSON like [`ActionsBlock::elements`](super::ActionsBlock::elements).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub accessory: Option<Value>,
 
@@ -52,7 +55,14 @@ impl SectionBlock {
         SectionBlockBuilder::default()
     }
 
-    /// Validates that the block has either text or fields.
+    /// Sets the block ID (max 255 characters).
+    pub fn with_block_id(mut self, block_id: impl Into<String>) -> Result<Self> {
+        self.block_id = Some(check_block_id(block_id.into())?);
+        Ok(self)
+    }
+
+    /// Validates that the block has either text or fields, the field count
+    /// and length limits, and the block ID length.
     pub fn validate(&self) -> Result<()> {
         if self.text.is_none() && self.fields.is_none() {
             return Err(SlackError::Validation(
@@ -68,6 +78,20 @@ impl SectionBlock {
                     MAX_SECTION_FIELDS
                 )));
             }
+
+            for field in fields {
+                let length = field.text().len();
+                if length > MAX_SECTION_FIELD_LENGTH {
+                    return Err(SlackError::Validation(format!(
+                        "SectionBlock field length {} exceeds maximum {}",
+                        length, MAX_SECTION_FIELD_LENGTH
+                    )));
+                }
+            }
+        }
+
+        if let Some(block_id) = &self.block_id {
+            check_block_id(block_id.clone())?;
         }
 
         Ok(())
@@ -123,7 +147,7 @@ impl SectionBlockBuilder {
         self
     }
 
-    /// Sets the block ID.
+    /// Sets the block ID (max 255 characters, checked by `build`).
     pub fn block_id(mut self, block_id: impl Into<String>) -> Self {
         self.block_id = Some(block_id.into());
         self
@@ -298,4 +322,26 @@ mod tests {
 
         assert_eq!(block1, block2);
     }
+
+    #[test]
+    fn test_section_block_field_length_validation() {
+        let result = SectionBlock::builder()
+            .fields(["x".repeat(MAX_SECTION_FIELD_LENGTH + 1)])
+            .unwrap()
+            .build();
+        assert!(matches!(result, Err(SlackError::Validation(_))));
+
+        let result = SectionBlock::builder()
+            .fields(["x".repeat(MAX_SECTION_FIELD_LENGTH)])
+            .unwrap()
+            .build();
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_section_block_with_block_id_validation() {
+        let block = SectionBlock::new("Text").unwrap();
+        assert!(block.clone().with_block_id("s1").is_ok());
+        assert!(block.with_block_id("x".repeat(256)).is_err());
+    }
 }
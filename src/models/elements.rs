@@ -5,12 +5,13 @@
 
 use crate::constants::limits::{
     MAX_ACTION_ID_LENGTH, MAX_ALT_TEXT_LENGTH, MAX_BUTTON_TEXT_LENGTH, MAX_BUTTON_VALUE_LENGTH,
-    MAX_CHOICE_OPTIONS, MAX_INITIAL_OPTIONS, MAX_OVERFLOW_OPTIONS, MAX_PLACEHOLDER_LENGTH,
-    MAX_SELECT_OPTIONS, MAX_TEXT_LENGTH, MAX_URL_LENGTH, MIN_OVERFLOW_OPTIONS,
+    MAX_CHOICE_OPTIONS, MAX_DATE_TIME_PICKER_TIMESTAMP, MAX_INITIAL_OPTIONS, MAX_OVERFLOW_OPTIONS,
+    MAX_PLACEHOLDER_LENGTH, MAX_SELECT_OPTIONS, MAX_TEXT_LENGTH, MAX_URL_LENGTH,
+    MIN_OVERFLOW_OPTIONS,
 };
 use crate::error::{Result, SlackError};
 use crate::models::objects::{ConfirmObject, OptionGroup, SlackOption, TextObject};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 
 /// Style for buttons.
@@ -157,6 +158,8 @@ pub struct ImageElement {
     pub image_url: Option<String>,
 
     /// Alternative representation using Slack file object.
+    ///
+    /// Kept as raw JSON so that file fields Slack adds survive a round trip.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slack_file: Option<Value>,
 
@@ -751,7 +754,8 @@ pub struct ConversationSelectElement {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_to_current_conversation: Option<bool>,
 
-    /// Filter for conversation types.
+    /// Filter for conversation types, kept as raw JSON so that filter
+    /// options this crate does not model are passed through.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<Value>,
 
@@ -812,7 +816,8 @@ pub struct ConversationMultiSelectElement {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_selected_items: Option<usize>,
 
-    /// Filter for conversation types.
+    /// Filter for conversation types, kept as raw JSON so that filter
+    /// options this crate does not model are passed through.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<Value>,
 
@@ -1121,18 +1126,43 @@ impl DatePickerElement {
         })
     }
 
-    /// Sets the initial date (must be in YYYY-MM-DD format).
+    /// Sets the initial date (must be a real date in YYYY-MM-DD format).
+    ///
+    /// An empty string is accepted as before and sent as is.
     pub fn with_initial_date(mut self, date: impl Into<String>) -> Result<Self> {
         let date_str = date.into();
-        // Simple validation for YYYY-MM-DD format
-        if !date_str.is_empty() && date_str.len() != 10 {
-            return Err(SlackError::Validation(
-                "Date must be in YYYY-MM-DD format".to_string(),
-            ));
+        let is_valid = date_str.len() == 10
+            && chrono::NaiveDate::parse_from_str(&date_str, "%Y-%m-%d").is_ok();
+        if !date_str.is_empty() && !is_valid {
+            return Err(SlackError::Validation(format!(
+                "Date must be in YYYY-MM-DD format, got {:?}",
+                date_str
+            )));
         }
         self.initial_date = Some(date_str);
         Ok(self)
     }
+
+    /// Sets the initial date from a [`chrono::NaiveDate`].
+    #[cfg(feature = "chrono")]
+    pub fn with_initial_date_chrono(mut self, date: chrono::NaiveDate) -> Self {
+        self.initial_date = Some(date.format("%Y-%m-%d").to_string());
+        self
+    }
+
+    /// Sets the initial date to the day `datetime` falls on in `tz`.
+    ///
+    /// Use the viewing user's time zone (the `tz` field of `users.info`):
+    /// late evening in the western hemisphere is already the next day in
+    /// UTC, so taking the UTC date would be off by one.
+    #[cfg(feature = "chrono")]
+    pub fn with_initial_date_time_tz(
+        self,
+        datetime: chrono::DateTime<chrono::Utc>,
+        tz: chrono_tz::Tz,
+    ) -> Self {
+        self.with_initial_date_chrono(datetime.with_timezone(&tz).date_naive())
+    }
 }
 
 /// A time picker element.
@@ -1153,6 +1183,10 @@ pub struct TimePickerElement {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub initial_time: Option<String>,
 
+    /// IANA time zone the time is shown in, e.g. `America/New_York`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
     /// Confirmation dialog.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub confirm: Option<ConfirmObject>,
@@ -1180,6 +1214,7 @@ impl TimePickerElement {
             action_id: action_id_str,
             placeholder: None,
             initial_time: None,
+            timezone: None,
             confirm: None,
             focus_on_load: None,
         })
@@ -1197,6 +1232,26 @@ impl TimePickerElement {
         self.initial_time = Some(time_str);
         Ok(self)
     }
+
+    /// Sets the IANA time zone, e.g. `America/New_York`, that the picker
+    /// shows times in.
+    pub fn with_timezone(mut self, tz: impl Into<String>) -> Result<Self> {
+        let tz = tz.into();
+        if tz.trim().is_empty() {
+            return Err(SlackError::Validation(
+                "Time picker timezone must not be empty".to_string(),
+            ));
+        }
+        self.timezone = Some(tz);
+        Ok(self)
+    }
+
+    /// Sets the time zone from a [`chrono_tz::Tz`].
+    #[cfg(feature = "chrono-tz")]
+    pub fn with_timezone_tz(mut self, tz: chrono_tz::Tz) -> Self {
+        self.timezone = Some(tz.name().to_string());
+        self
+    }
 }
 
 /// A date-time picker element.
@@ -1245,9 +1300,37 @@ impl DateTimePickerElement {
     }
 
     /// Sets the initial date-time as Unix timestamp.
-    pub fn with_initial_date_time(mut self, timestamp: i64) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the timestamp is before 1970 or
+    /// after the year 2100; such values are usually milliseconds or another
+    /// unit passed by mistake.
+    pub fn with_initial_date_time(mut self, timestamp: i64) -> Result<Self> {
+        if !(0..=MAX_DATE_TIME_PICKER_TIMESTAMP).contains(&timestamp) {
+            return Err(SlackError::Validation(format!(
+                "Initial date-time {} must be a Unix timestamp between 1970 and 2100",
+                timestamp
+            )));
+        }
         self.initial_date_time = Some(timestamp);
-        self
+        Ok(self)
+    }
+
+    /// Sets the initial date-time from a [`chrono::DateTime`].
+    ///
+    /// Slack only accepts whole seconds, so any fraction is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the date-time is before 1970 or
+    /// after the year 2100, as [`with_initial_date_time`](Self::with_initial_date_time) does.
+    #[cfg(feature = "chrono")]
+    pub fn with_initial_datetime_chrono(
+        self,
+        datetime: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Self> {
+        self.with_initial_date_time(datetime.timestamp())
     }
 }
 
@@ -1440,6 +1523,239 @@ impl OverflowMenuElement {
     }
 }
 
+/// An interactive element that can be placed in an actions block.
+///
+/// Each variant serialises as the wrapped element, so the element's own
+/// `type` field is the discriminant on the wire.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActionElement {
+    /// A `button` element.
+    Button(ButtonElement),
+    /// A `static_select` element.
+    StaticSelect(StaticSelectElement),
+    /// An `overflow` element.
+    OverflowMenu(OverflowMenuElement),
+    /// A `datepicker` element.
+    DatePicker(DatePickerElement),
+    /// A `timepicker` element.
+    TimePicker(TimePickerElement),
+    /// A `radio_buttons` element.
+    RadioButtons(RadioButtonsElement),
+}
+
+impl ActionElement {
+    const TYPES: &'static [&'static str] = &[
+        "button",
+        "static_select",
+        "overflow",
+        "datepicker",
+        "timepicker",
+        "radio_buttons",
+    ];
+
+    /// Returns the element's `type` value.
+    pub fn element_type(&self) -> &str {
+        match self {
+            Self::Button(e) => &e.element_type,
+            Self::StaticSelect(e) => &e.element_type,
+            Self::OverflowMenu(e) => &e.element_type,
+            Self::DatePicker(e) => &e.element_type,
+            Self::TimePicker(e) => &e.element_type,
+            Self::RadioButtons(e) => &e.element_type,
+        }
+    }
+}
+
+impl Serialize for ActionElement {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Self::Button(e) => e.serialize(serializer),
+            Self::StaticSelect(e) => e.serialize(serializer),
+            Self::OverflowMenu(e) => e.serialize(serializer),
+            Self::DatePicker(e) => e.serialize(serializer),
+            Self::TimePicker(e) => e.serialize(serializer),
+            Self::RadioButtons(e) => e.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ActionElement {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = Value::deserialize(deserializer)?;
+        let element_type = value
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| D::Error::missing_field("type"))?
+            .to_string();
+
+        let element = match element_type.as_str() {
+            "button" => serde_json::from_value(value).map(Self::Button),
+            "static_select" => serde_json::from_value(value).map(Self::StaticSelect),
+            "overflow" => serde_json::from_value(value).map(Self::OverflowMenu),
+            "datepicker" => serde_json::from_value(value).map(Self::DatePicker),
+            "timepicker" => serde_json::from_value(value).map(Self::TimePicker),
+            "radio_buttons" => serde_json::from_value(value).map(Self::RadioButtons),
+            other => return Err(D::Error::unknown_variant(other, Self::TYPES)),
+        };
+        element.map_err(D::Error::custom)
+    }
+}
+
+impl From<ButtonElement> for ActionElement {
+    fn from(element: ButtonElement) -> Self {
+        Self::Button(element)
+    }
+}
+
+impl From<StaticSelectElement> for ActionElement {
+    fn from(element: StaticSelectElement) -> Self {
+        Self::StaticSelect(element)
+    }
+}
+
+impl From<OverflowMenuElement> for ActionElement {
+    fn from(element: OverflowMenuElement) -> Self {
+        Self::OverflowMenu(element)
+    }
+}
+
+impl From<DatePickerElement> for ActionElement {
+    fn from(element: DatePickerElement) -> Self {
+        Self::DatePicker(element)
+    }
+}
+
+impl From<TimePickerElement> for ActionElement {
+    fn from(element: TimePickerElement) -> Self {
+        Self::TimePicker(element)
+    }
+}
+
+impl From<RadioButtonsElement> for ActionElement {
+    fn from(element: RadioButtonsElement) -> Self {
+        Self::RadioButtons(element)
+    }
+}
+
+macro_rules! block_elements {
+    ($($(#[$doc:meta])* $variant:ident($element:ident) => $name:literal,)*) => {
+        /// Any Block Kit element, identified by its `type` field.
+        ///
+        /// Deserializing reads `type` and builds the matching element struct.
+        /// Element types this crate does not model are kept as
+        /// [`BlockElement::Unknown`] so that payloads from newer Slack
+        /// features still parse.
+        #[derive(Debug, Clone, PartialEq)]
+        #[non_exhaustive]
+        pub enum BlockElement {
+            $($(#[$doc])* $variant($element),)*
+
+            /// An element type not known to this crate, as raw JSON.
+            Unknown(Value),
+        }
+
+        impl BlockElement {
+            /// Returns the element's `type` value.
+            pub fn element_type(&self) -> &str {
+                match self {
+                    $(Self::$variant(e) => &e.element_type,)*
+                    Self::Unknown(value) => value.get("type").and_then(Value::as_str).unwrap_or(""),
+                }
+            }
+        }
+
+        impl Serialize for BlockElement {
+            fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                match self {
+                    $(Self::$variant(e) => e.serialize(serializer),)*
+                    Self::Unknown(value) => value.serialize(serializer),
+                }
+            }
+        }
+
+        impl<'de> Deserialize<'de> for BlockElement {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                use serde::de::Error;
+
+                let value = Value::deserialize(deserializer)?;
+                let element_type = value
+                    .get("type")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| D::Error::missing_field("type"))?;
+
+                match element_type {
+                    $($name => serde_json::from_value(value).map(Self::$variant).map_err(D::Error::custom),)*
+                    _ => Ok(Self::Unknown(value)),
+                }
+            }
+        }
+
+        $(
+            impl From<$element> for BlockElement {
+                fn from(element: $element) -> Self {
+                    Self::$variant(element)
+                }
+            }
+        )*
+    };
+}
+
+block_elements! {
+    /// A `button` element.
+    Button(ButtonElement) => "button",
+    /// An `image` element.
+    Image(ImageElement) => "image",
+    /// A `plain_text_input` element.
+    PlainTextInput(PlainTextInputElement) => "plain_text_input",
+    /// A `static_select` element.
+    StaticSelect(StaticSelectElement) => "static_select",
+    /// A `multi_static_select` element.
+    StaticMultiSelect(StaticMultiSelectElement) => "multi_static_select",
+    /// A `users_select` element.
+    UserSelect(UserSelectElement) => "users_select",
+    /// A `multi_users_select` element.
+    UserMultiSelect(UserMultiSelectElement) => "multi_users_select",
+    /// A `conversations_select` element.
+    ConversationSelect(ConversationSelectElement) => "conversations_select",
+    /// A `multi_conversations_select` element.
+    ConversationMultiSelect(ConversationMultiSelectElement) => "multi_conversations_select",
+    /// A `channels_select` element.
+    ChannelSelect(ChannelSelectElement) => "channels_select",
+    /// A `multi_channels_select` element.
+    ChannelMultiSelect(ChannelMultiSelectElement) => "multi_channels_select",
+    /// An `external_select` element.
+    ExternalDataSelect(ExternalDataSelectElement) => "external_select",
+    /// A `multi_external_select` element.
+    ExternalDataMultiSelect(ExternalDataMultiSelectElement) => "multi_external_select",
+    /// A `datepicker` element.
+    DatePicker(DatePickerElement) => "datepicker",
+    /// A `timepicker` element.
+    TimePicker(TimePickerElement) => "timepicker",
+    /// A `datetimepicker` element.
+    DateTimePicker(DateTimePickerElement) => "datetimepicker",
+    /// A `checkboxes` element.
+    Checkboxes(CheckboxesElement) => "checkboxes",
+    /// A `radio_buttons` element.
+    RadioButtons(RadioButtonsElement) => "radio_buttons",
+    /// An `overflow` element.
+    OverflowMenu(OverflowMenuElement) => "overflow",
+}
+
+impl From<ActionElement> for BlockElement {
+    fn from(element: ActionElement) -> Self {
+        match element {
+            ActionElement::Button(e) => e.into(),
+            ActionElement::StaticSelect(e) => e.into(),
+            ActionElement::OverflowMenu(e) => e.into(),
+            ActionElement::DatePicker(e) => e.into(),
+            ActionElement::TimePicker(e) => e.into(),
+            ActionElement::RadioButtons(e) => e.into(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1750,6 +2066,54 @@ mod tests {
         let picker = DatePickerElement::new("date_picker").unwrap();
         let result = picker.with_initial_date("invalid");
         assert!(result.is_err());
+
+        for invalid in [
+            "2023/12/25",
+            "2023-13-01",
+            "2023-12-32",
+            "2023-02-30",
+            "23-12-25x",
+            "2023-1-05",
+        ] {
+            assert!(
+                matches!(
+                    DatePickerElement::new("date_picker")
+                        .unwrap()
+                        .with_initial_date(invalid),
+                    Err(SlackError::Validation(_))
+                ),
+                "{:?}",
+                invalid
+            );
+        }
+
+        assert!(DatePickerElement::new("date_picker")
+            .unwrap()
+            .with_initial_date("")
+            .is_ok());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_picker_chrono_dates() {
+        use chrono::{NaiveDate, TimeZone, Utc};
+
+        let picker = DatePickerElement::new("date_picker")
+            .unwrap()
+            .with_initial_date_chrono(NaiveDate::from_ymd_opt(2024, 3, 5).unwrap());
+        assert_eq!(picker.initial_date.as_deref(), Some("2024-03-05"));
+
+        // 02:30 UTC on the 6th is still the evening of the 5th in Los Angeles
+        let datetime = Utc.with_ymd_and_hms(2024, 3, 6, 2, 30, 0).unwrap();
+        let picker = DatePickerElement::new("date_picker")
+            .unwrap()
+            .with_initial_date_time_tz(datetime, chrono_tz::America::Los_Angeles);
+        assert_eq!(picker.initial_date.as_deref(), Some("2024-03-05"));
+
+        let picker = DatePickerElement::new("date_picker")
+            .unwrap()
+            .with_initial_date_time_tz(datetime, chrono_tz::Asia::Tokyo);
+        assert_eq!(picker.initial_date.as_deref(), Some("2024-03-06"));
     }
 
     #[test]
@@ -1793,6 +2157,50 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_time_picker_round_trip() {
+        let input = json!({
+            "type": "timepicker",
+            "action_id": "tp",
+            "initial_time": "09:30",
+            "timezone": "America/New_York"
+        });
+
+        let picker: TimePickerElement = serde_json::from_value(input.clone()).unwrap();
+        assert_eq!(picker.timezone.as_deref(), Some("America/New_York"));
+        let output = serde_json::to_value(&picker).unwrap();
+
+        assert_eq!(input, output);
+    }
+
+    #[test]
+    fn test_time_picker_timezone() {
+        let picker = TimePickerElement::new("tp")
+            .unwrap()
+            .with_timezone("Europe/Berlin")
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(&picker).unwrap()["timezone"],
+            "Europe/Berlin"
+        );
+
+        for empty in ["", " "] {
+            assert!(matches!(
+                TimePickerElement::new("tp").unwrap().with_timezone(empty),
+                Err(SlackError::Validation(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_time_picker_timezone_tz() {
+        let picker = TimePickerElement::new("tp")
+            .unwrap()
+            .with_timezone_tz(chrono_tz::America::Sao_Paulo);
+        assert_eq!(picker.timezone.as_deref(), Some("America/Sao_Paulo"));
+    }
+
     // DateTime picker tests
     #[test]
     fn test_datetime_picker_basic() {
@@ -1806,10 +2214,53 @@ mod tests {
     fn test_datetime_picker_with_timestamp() {
         let picker = DateTimePickerElement::new("datetime_picker")
             .unwrap()
-            .with_initial_date_time(1640444400);
+            .with_initial_date_time(1640444400)
+            .unwrap();
+
+        let json_val = serde_json::to_value(&picker).unwrap();
+        assert_eq!(json_val["initial_date_time"], 1640444400);
+    }
+
+    #[test]
+    fn test_datetime_picker_timestamp_range() {
+        let picker = DateTimePickerElement::new("datetime_picker").unwrap();
+        assert!(picker.clone().with_initial_date_time(0).is_ok());
+        assert!(picker
+            .clone()
+            .with_initial_date_time(MAX_DATE_TIME_PICKER_TIMESTAMP)
+            .is_ok());
+
+        // Negative values and millisecond timestamps are rejected
+        for timestamp in [-1, MAX_DATE_TIME_PICKER_TIMESTAMP + 1, 1640444400000] {
+            assert!(matches!(
+                picker.clone().with_initial_date_time(timestamp),
+                Err(SlackError::Validation(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_datetime_picker_chrono() {
+        use chrono::{TimeZone, Utc};
+
+        let picker = DateTimePickerElement::new("datetime_picker")
+            .unwrap()
+            .with_initial_datetime_chrono(Utc.with_ymd_and_hms(2021, 12, 25, 15, 0, 0).unwrap())
+            .unwrap();
 
         let json_val = serde_json::to_value(&picker).unwrap();
         assert_eq!(json_val["initial_date_time"], 1640444400);
+
+        for out_of_range in [
+            Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap(),
+            Utc.with_ymd_and_hms(2101, 1, 1, 0, 0, 0).unwrap(),
+        ] {
+            assert!(matches!(
+                picker.clone().with_initial_datetime_chrono(out_of_range),
+                Err(SlackError::Validation(_))
+            ));
+        }
     }
 
     // Checkboxes tests
@@ -2111,4 +2562,56 @@ mod tests {
         picker.confirm = Some(confirm);
         assert!(picker.confirm.is_some());
     }
+
+    // ActionElement tests
+    #[test]
+    fn test_action_element_serializes_as_inner_element() {
+        let element = ActionElement::from(ButtonElement::new("Go", "go").unwrap());
+        let json_val = serde_json::to_value(&element).unwrap();
+
+        assert_eq!(element.element_type(), "button");
+        assert_eq!(json_val["type"], "button");
+        assert_eq!(json_val["action_id"], "go");
+    }
+
+    #[test]
+    fn test_action_element_dispatches_on_type() {
+        let input = json!({"type": "datepicker", "action_id": "date"});
+        let element: ActionElement = serde_json::from_value(input.clone()).unwrap();
+
+        assert!(matches!(element, ActionElement::DatePicker(_)));
+        assert_eq!(serde_json::to_value(&element).unwrap(), input);
+
+        let unknown = serde_json::from_value::<ActionElement>(json!({"type": "users_select"}));
+        assert!(unknown.is_err());
+    }
+
+    // BlockElement tests
+    #[test]
+    fn test_block_element_dispatches_on_type() {
+        let input = json!({
+            "type": "multi_users_select",
+            "action_id": "reviewers",
+            "placeholder": {"type": "plain_text", "text": "Pick reviewers"}
+        });
+        let element: BlockElement = serde_json::from_value(input.clone()).unwrap();
+
+        assert!(matches!(element, BlockElement::UserMultiSelect(_)));
+        assert_eq!(element.element_type(), "multi_users_select");
+        assert_eq!(serde_json::to_value(&element).unwrap(), input);
+    }
+
+    #[test]
+    fn test_block_element_unknown_type_falls_back_to_value() {
+        let input = json!({"type": "rich_text_input", "action_id": "notes"});
+        let element: BlockElement = serde_json::from_value(input.clone()).unwrap();
+
+        assert_eq!(element, BlockElement::Unknown(input.clone()));
+        assert_eq!(element.element_type(), "rich_text_input");
+        assert_eq!(serde_json::to_value(&element).unwrap(), input);
+
+        // A known type with the wrong shape is an error, not Unknown
+        let bad = serde_json::from_value::<BlockElement>(json!({"type": "button"}));
+        assert!(bad.is_err());
+    }
 }
@@ -0,0 +1,359 @@
+//! Messages received from Slack.
+//!
+//! [`Message`] models the message objects returned by `conversations.history`
+//! and `conversations.replies` and delivered in `message` events. Fields not
+//! modeled here are kept in `extra`, so a message serializes back unchanged.
+
+use crate::error::Result;
+use crate::models::blocks::{deserialize_block_values, Block};
+use crate::models::timestamp::Timestamp;
+use crate::web::SlackResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A message as returned by the Web API or the Events API.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::Message;
+///
+/// let message: Message = serde_json::from_value(serde_json::json!({
+///     "type": "message",
+///     "user": "U123",
+///     "text": "Deploy finished",
+///     "ts": "1712345678.000200",
+///     "thread_ts": "1712345678.000200",
+///     "reply_count": 2
+/// }))?;
+/// assert!(message.is_thread_parent());
+/// assert_eq!(message.permalink_ts(), "p1712345678000200");
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    /// Object type, normally `message`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<String>,
+
+    /// Message subtype, e.g. `bot_message` or `channel_join`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subtype: Option<String>,
+
+    /// Timestamp identifying the message in its channel.
+    pub ts: Timestamp,
+
+    /// Timestamp of the thread's parent message, for parents and replies.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_ts: Option<Timestamp>,
+
+    /// ID of the user who sent the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// ID of the bot that sent the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bot_id: Option<String>,
+
+    /// Message text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// Raw Block Kit blocks; see [`blocks`](Self::blocks) for typed access.
+    ///
+    /// Blocks sent as a JSON-encoded string, as in form-encoded payloads,
+    /// are decoded; they always serialize as an array.
+    #[serde(
+        rename = "blocks",
+        default,
+        deserialize_with = "deserialize_block_values",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub raw_blocks: Vec<Value>,
+
+    /// Legacy attachments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Value>,
+
+    /// Reactions added to the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reactions: Vec<Reaction>,
+
+    /// Files shared in the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<MessageFile>,
+
+    /// Set if the message was edited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edited: Option<Edited>,
+
+    /// Number of replies, on thread parents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_count: Option<u32>,
+
+    /// Timestamp of the latest reply, on thread parents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latest_reply: Option<Timestamp>,
+
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Message {
+    /// Returns the message's blocks as typed [`Block`]s.
+    ///
+    /// Blocks this crate cannot parse, including known block types with
+    /// unmodeled fields, are returned as [`Block::Unknown`].
+    pub fn blocks(&self) -> Vec<Block> {
+        self.raw_blocks
+            .iter()
+            .map(|value| {
+                Block::deserialize(value).unwrap_or_else(|_| Block::Unknown(value.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns true if the message starts a thread.
+    pub fn is_thread_parent(&self) -> bool {
+        self.thread_ts.as_ref() == Some(&self.ts)
+    }
+
+    /// Returns true if the message is a reply in a thread.
+    pub fn is_thread_reply(&self) -> bool {
+        self.thread_ts
+            .as_ref()
+            .is_some_and(|thread_ts| *thread_ts != self.ts)
+    }
+
+    /// Returns true if the message was sent by a bot or integration.
+    pub fn is_bot_message(&self) -> bool {
+        self.bot_id.is_some() || self.subtype.as_deref() == Some("bot_message")
+    }
+
+    /// Returns the message ID used in permalinks, e.g. `p1712345678000200`.
+    pub fn permalink_ts(&self) -> String {
+        self.ts.permalink_id()
+    }
+
+    /// Parses the `messages` array of a `conversations.history` or
+    /// `conversations.replies` response.
+    pub fn list_from(response: &SlackResponse) -> Result<Vec<Self>> {
+        let messages = response
+            .data
+            .get("messages")
+            .cloned()
+            .unwrap_or(Value::Array(Vec::new()));
+        Ok(serde_json::from_value(messages)?)
+    }
+}
+
+/// A reaction on a message.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reaction {
+    /// Emoji name without colons, e.g. `thumbsup`.
+    pub name: String,
+
+    /// Number of users who reacted.
+    #[serde(default)]
+    pub count: u32,
+
+    /// IDs of users who reacted; may be truncated for popular reactions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub users: Vec<String>,
+}
+
+/// A file shared in a message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageFile {
+    /// File ID.
+    pub id: String,
+
+    /// File name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// File title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// MIME type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mimetype: Option<String>,
+
+    /// Slack file type, e.g. `png`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filetype: Option<String>,
+
+    /// Size in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Authenticated download URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_private: Option<String>,
+
+    /// Permalink to the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permalink: Option<String>,
+
+    /// Fields not modeled above.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Who last edited a message, and when.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Edited {
+    /// ID of the user who edited the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+
+    /// Time of the edit.
+    pub ts: Timestamp,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use reqwest::header::HeaderMap;
+    use serde_json::json;
+
+    fn history_payload() -> Value {
+        json!({
+            "ok": true,
+            "messages": [
+                {
+                    "type": "message",
+                    "user": "U061F7AUR",
+                    "text": "Deploy finished :tada:",
+                    "ts": "1712345678.000200",
+                    "thread_ts": "1712345678.000200",
+                    "reply_count": 2,
+                    "reply_users": ["U0G9QF9C6"],
+                    "latest_reply": "1712345690.000100",
+                    "team": "T061EG9R6",
+                    "client_msg_id": "c5f0f9a4-3b1d-4a4e-9c62-1f1b2f4a9d11",
+                    "blocks": [
+                        {
+                            "type": "rich_text",
+                            "block_id": "x1",
+                            "elements": [{"type": "rich_text_section", "elements": [{"type": "text", "text": "Deploy finished"}]}]
+                        },
+                        {"type": "divider", "block_id": "d1"}
+                    ],
+                    "reactions": [{"name": "tada", "users": ["U0G9QF9C6"], "count": 1}],
+                    "edited": {"user": "U061F7AUR", "ts": "1712345680.000000"}
+                },
+                {
+                    "type": "message",
+                    "subtype": "bot_message",
+                    "bot_id": "B0AB12CD",
+                    "username": "ci",
+                    "text": "Build #42 passed",
+                    "ts": "1712345600.000100",
+                    "attachments": [{"fallback": "Build #42", "color": "good"}]
+                },
+                {
+                    "type": "message",
+                    "user": "U0G9QF9C6",
+                    "text": "",
+                    "ts": "1712345500.000300",
+                    "files": [{
+                        "id": "F0S43PZDF",
+                        "name": "report.png",
+                        "mimetype": "image/png",
+                        "filetype": "png",
+                        "size": 1234,
+                        "url_private": "https://files.slack.com/files-pri/T061EG9R6-F0S43PZDF/report.png",
+                        "is_public": true
+                    }],
+                    "upload": true
+                }
+            ],
+            "has_more": false
+        })
+    }
+
+    #[test]
+    fn test_history_round_trip() {
+        let payload = history_payload();
+        let response = SlackResponse::new(
+            "POST".to_string(),
+            "https://slack.com/api/conversations.history".to_string(),
+            payload.clone(),
+            HeaderMap::new(),
+            200,
+        );
+
+        let messages = Message::list_from(&response).unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            serde_json::to_value(&messages).unwrap(),
+            payload["messages"]
+        );
+    }
+
+    #[test]
+    fn test_message_helpers() {
+        let messages: Vec<Message> =
+            serde_json::from_value(history_payload()["messages"].clone()).unwrap();
+
+        let parent = &messages[0];
+        assert!(parent.is_thread_parent());
+        assert!(!parent.is_thread_reply());
+        assert!(!parent.is_bot_message());
+        assert_eq!(parent.permalink_ts(), "p1712345678000200");
+        assert_eq!(parent.reactions[0].name, "tada");
+        assert_eq!(
+            parent.edited.as_ref().unwrap().ts,
+            Timestamp::from("1712345680.000000")
+        );
+        let blocks = parent.blocks();
+        assert!(matches!(blocks[0], Block::Unknown(_)));
+        assert!(matches!(blocks[1], Block::Divider(_)));
+
+        assert!(messages[1].is_bot_message());
+        assert_eq!(messages[2].files[0].id, "F0S43PZDF");
+        assert_eq!(messages[2].files[0].extra["is_public"], true);
+        assert!(messages[2].ts < messages[1].ts);
+
+        let reply: Message = serde_json::from_value(json!({
+            "ts": "1712345690.000100",
+            "thread_ts": "1712345678.000200",
+            "text": "on it"
+        }))
+        .unwrap();
+        assert!(reply.is_thread_reply());
+        assert!(!reply.is_thread_parent());
+    }
+
+    #[test]
+    fn test_blocks_as_string_round_trip() {
+        let blocks = json!([{"type": "divider", "block_id": "d1"}]);
+        let from_array: Message = serde_json::from_value(json!({
+            "ts": "1712345678.000200",
+            "blocks": blocks
+        }))
+        .unwrap();
+        let from_string: Message = serde_json::from_value(json!({
+            "ts": "1712345678.000200",
+            "blocks": blocks.to_string()
+        }))
+        .unwrap();
+
+        assert_eq!(from_string, from_array);
+        assert!(matches!(from_string.blocks()[0], Block::Divider(_)));
+        assert_eq!(
+            serde_json::to_value(&from_string).unwrap()["blocks"],
+            blocks
+        );
+
+        let invalid = serde_json::from_value::<Message>(json!({
+            "ts": "1712345678.000200",
+            "blocks": "[{"
+        }));
+        assert!(invalid.is_err());
+    }
+}
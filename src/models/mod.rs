@@ -1,30 +1,244 @@
-//! Block Kit models for building Slack UI components.
+//! Block Kit models for building Slack UI components, and models of
+//! received messages.
 //!
 //! This module provides type-safe representations of Slack's Block Kit
-//! components, including blocks, elements, composition objects, and views.
+//! components, including blocks, elements, composition objects, and views,
+//! as well as the [`Message`] objects returned by history calls.
+//!
+//! Models are meant to read real Slack payloads, so deserialization ignores
+//! fields this crate does not model rather than failing; unknown block and
+//! element types are kept as raw JSON.
 
 pub mod blocks;
 pub mod elements;
+pub mod message;
+pub mod mrkdwn;
 pub mod objects;
+pub mod timestamp;
+pub mod validation;
 pub mod views;
 
 // Re-export blocks
 pub use blocks::{
-    ActionsBlock, ContextBlock, DividerBlock, HeaderBlock, ImageBlock, InputBlock, SectionBlock,
+    ActionsBlock, ActionsBlockBuilder, ContextBlock, ContextElement, DividerBlock, HeaderBlock,
+    ImageBlock, InputBlock, SectionBlock,
 };
 
 // Re-export elements
 pub use elements::{
-    ButtonElement, ButtonStyle, ChannelMultiSelectElement, ChannelSelectElement, CheckboxesElement,
-    ConversationMultiSelectElement, ConversationSelectElement, DatePickerElement,
-    DateTimePickerElement, DispatchActionConfig, ExternalDataMultiSelectElement,
-    ExternalDataSelectElement, ImageElement, OverflowMenuElement, PlainTextInputElement,
-    RadioButtonsElement, StaticMultiSelectElement, StaticSelectElement, TimePickerElement,
-    UserMultiSelectElement, UserSelectElement,
+    ActionElement, BlockElement, ButtonElement, ButtonStyle, ChannelMultiSelectElement,
+    ChannelSelectElement, CheckboxesElement, ConversationMultiSelectElement,
+    ConversationSelectElement, DatePickerElement, DateTimePickerElement, DispatchActionConfig,
+    ExternalDataMultiSelectElement, ExternalDataSelectElement, ImageElement, OverflowMenuElement,
+    PlainTextInputElement, RadioButtonsElement, StaticMultiSelectElement, StaticSelectElement,
+    TimePickerElement, UserMultiSelectElement, UserSelectElement,
 };
 
 // Re-export objects
-pub use objects::{ConfirmObject, ConfirmStyle, OptionGroup, SlackOption, TextObject};
+pub use objects::{
+    ConfirmObject, ConfirmStyle, MrkdwnText, OptionGroup, OptionGroupBuilder, PlainText,
+    SlackOption, TextObject,
+};
+
+// Re-export messages
+pub use message::{Edited, Message, MessageFile, Reaction};
+pub use timestamp::Timestamp;
+pub use validation::{validate_block_ids, BlockIdConflict};
 
 // Re-export views
-pub use views::{View, ViewState, ViewStateValue};
+pub use views::{View, ViewResponseAction, ViewState, ViewStateValue};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde::de::DeserializeOwned;
+    use serde_json::{json, Value};
+
+    /// Keys whose values are kept as raw JSON or keyed by IDs, so a bogus
+    /// entry would be data rather than an unknown field.
+    const RAW_KEYS: &[&str] = &[
+        "accessory",
+        "element",
+        "elements",
+        "filter",
+        "slack_file",
+        "values",
+    ];
+
+    /// Adds unknown fields to every object in `value`.
+    fn inject_bogus(value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map.iter_mut() {
+                    if !RAW_KEYS.contains(&key.as_str()) {
+                        inject_bogus(child);
+                    }
+                }
+                map.insert("zz_unknown".to_string(), json!({"nested": [1, 2]}));
+                map.insert("zz_flag".to_string(), json!(true));
+            }
+            Value::Array(items) => items.iter_mut().for_each(inject_bogus),
+            _ => {}
+        }
+    }
+
+    /// Parses `payload` with and without bogus fields injected.
+    fn parse_noisy<T: DeserializeOwned>(payload: Value) -> (T, T) {
+        let clean = serde_json::from_value(payload.clone()).unwrap();
+        let mut noisy = payload;
+        inject_bogus(&mut noisy);
+        (clean, serde_json::from_value(noisy).unwrap())
+    }
+
+    /// Asserts that `payload` parses the same with bogus fields injected.
+    fn assert_tolerant<T: DeserializeOwned + PartialEq + std::fmt::Debug>(payload: Value) -> T {
+        let (clean, noisy) = parse_noisy::<T>(payload);
+        assert_eq!(noisy, clean);
+        clean
+    }
+
+    /// Asserts that raw blocks, as kept by views and messages, parse as
+    /// known block types.
+    fn assert_known_blocks(raw_blocks: &[Value]) {
+        for block in raw_blocks {
+            let block: blocks::Block = serde_json::from_value(block.clone()).unwrap();
+            assert!(!matches!(block, blocks::Block::Unknown(_)), "{:?}", block);
+        }
+    }
+
+    fn text(text: &str) -> Value {
+        json!({"type": "plain_text", "text": text, "emoji": true})
+    }
+
+    fn option(value: &str) -> Value {
+        json!({"text": text(value), "value": value, "description": text("desc")})
+    }
+
+    fn confirm() -> Value {
+        json!({
+            "title": text("Sure?"),
+            "text": {"type": "mrkdwn", "text": "*Really*", "verbatim": false},
+            "confirm": text("Yes"),
+            "deny": text("No"),
+            "style": "danger"
+        })
+    }
+
+    fn elements() -> Vec<Value> {
+        vec![
+            json!({"type": "button", "action_id": "b", "text": text("Go"), "value": "1", "style": "primary", "confirm": confirm()}),
+            json!({"type": "image", "image_url": "https://example.com/a.png", "alt_text": "a"}),
+            json!({"type": "plain_text_input", "action_id": "p", "placeholder": text("Type"), "multiline": true, "dispatch_action_config": {"trigger_actions_on": ["on_enter_pressed"]}}),
+            json!({"type": "static_select", "action_id": "s", "placeholder": text("Pick"), "option_groups": [{"label": text("G"), "options": [option("a"), option("b")]}], "initial_option": option("a"), "confirm": confirm()}),
+            json!({"type": "multi_static_select", "action_id": "ms", "options": [option("a")], "initial_options": [option("a")], "max_selected_items": 2}),
+            json!({"type": "users_select", "action_id": "u", "initial_user": "U1"}),
+            json!({"type": "multi_users_select", "action_id": "mu", "initial_users": ["U1"]}),
+            json!({"type": "conversations_select", "action_id": "c", "filter": {"include": ["public"], "new_filter_field": 1}}),
+            json!({"type": "multi_conversations_select", "action_id": "mc", "initial_conversations": ["C1"]}),
+            json!({"type": "channels_select", "action_id": "ch", "initial_channel": "C1"}),
+            json!({"type": "multi_channels_select", "action_id": "mch", "initial_channels": ["C1"]}),
+            json!({"type": "external_select", "action_id": "e", "initial_option": option("x"), "min_query_length": 3}),
+            json!({"type": "multi_external_select", "action_id": "me", "initial_options": [option("x")]}),
+            json!({"type": "datepicker", "action_id": "d", "initial_date": "2024-01-02", "confirm": confirm()}),
+            json!({"type": "timepicker", "action_id": "t", "initial_time": "09:30", "timezone": "Asia/Tokyo"}),
+            json!({"type": "datetimepicker", "action_id": "dt", "initial_date_time": 1712345678}),
+            json!({"type": "checkboxes", "action_id": "cb", "options": [option("a")], "initial_options": [option("a")]}),
+            json!({"type": "radio_buttons", "action_id": "r", "options": [option("a")], "initial_option": option("a")}),
+            json!({"type": "overflow", "action_id": "o", "options": [option("a"), option("b")]}),
+        ]
+    }
+
+    fn blocks() -> Vec<Value> {
+        vec![
+            json!({"type": "section", "block_id": "s1", "text": {"type": "mrkdwn", "text": "*Hi*"}, "fields": [text("a")], "accessory": elements()[0]}),
+            json!({"type": "actions", "block_id": "a1", "elements": elements()}),
+            json!({"type": "context", "block_id": "c1", "elements": [text("ctx"), {"type": "image", "image_url": "https://example.com/a.png", "alt_text": "a"}]}),
+            json!({"type": "divider", "block_id": "d1"}),
+            json!({"type": "header", "block_id": "h1", "text": text("Header")}),
+            json!({"type": "image", "block_id": "i1", "slack_file": {"id": "F1", "new_field": true}, "alt_text": "a", "title": text("T")}),
+            json!({"type": "input", "block_id": "in1", "label": text("Name"), "element": elements()[2], "hint": text("hint"), "optional": true}),
+        ]
+    }
+
+    #[test]
+    fn test_elements_tolerate_unknown_fields() {
+        for element in elements() {
+            let parsed: BlockElement = assert_tolerant(element.clone());
+            assert!(
+                !matches!(parsed, BlockElement::Unknown(_)),
+                "{} parsed as unknown",
+                element["type"]
+            );
+        }
+    }
+
+    #[test]
+    fn test_blocks_tolerate_unknown_fields() {
+        for block in blocks() {
+            let parsed: blocks::Block = assert_tolerant(block.clone());
+            assert!(
+                !matches!(parsed, blocks::Block::Unknown(_)),
+                "{} parsed as unknown",
+                block["type"]
+            );
+        }
+    }
+
+    #[test]
+    fn test_view_submission_tolerates_unknown_fields() {
+        let (clean, view): (View, View) = parse_noisy(json!({
+            "id": "V1",
+            "team_id": "T1",
+            "type": "modal",
+            "title": text("Title"),
+            "submit": text("Submit"),
+            "close": null,
+            "blocks": blocks(),
+            "private_metadata": "",
+            "callback_id": "cb",
+            "state": {"values": {
+                "in1": {"p": {"type": "plain_text_input", "value": "Ada"}},
+                "a1": {"s": {"type": "static_select", "selected_option": option("a")}}
+            }},
+            "hash": "1712345678.abc",
+            "clear_on_close": false,
+            "notify_on_close": false,
+            "root_view_id": "V1",
+            "app_id": "A1",
+            "external_id": "",
+            "app_installed_team_id": "T1",
+            "bot_id": "B1"
+        }));
+        assert_eq!(view.title, clean.title);
+        assert_eq!(view.state, clean.state);
+        assert_eq!(
+            view.state
+                .unwrap()
+                .get("in1", "p")
+                .unwrap()
+                .value
+                .as_deref(),
+            Some("Ada")
+        );
+        assert_known_blocks(&view.blocks);
+    }
+
+    #[test]
+    fn test_message_tolerates_unknown_fields() {
+        let (clean, message): (Message, Message) = parse_noisy(json!({
+            "type": "message",
+            "ts": "1712345678.000200",
+            "text": "hi",
+            "blocks": blocks(),
+            "reactions": [{"name": "tada", "count": 1, "users": ["U1"]}],
+            "edited": {"user": "U1", "ts": "1712345680.000000"},
+            "files": [{"id": "F1", "name": "a.png"}]
+        }));
+        assert_eq!(message.reactions, clean.reactions);
+        assert_eq!(message.edited, clean.edited);
+        assert_eq!(message.files[0].extra["zz_flag"], true);
+        assert_eq!(message.extra["zz_flag"], true);
+        assert_known_blocks(&message.raw_blocks);
+    }
+}
@@ -0,0 +1,299 @@
+//! Helpers for Slack's mrkdwn control sequences.
+//!
+//! Slack renders special `<...>` sequences in mrkdwn text as dates in the
+//! reader's timezone, channel links, and group mentions. The functions in this
+//! module build those sequences so they don't have to be assembled by hand.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_rs::models::mrkdwn::{self, DateToken, SlackDate};
+//!
+//! let date = SlackDate::new(1392734382)
+//!     .token(DateToken::DateShort)
+//!     .token_text(" at ")
+//!     .token(DateToken::Time)
+//!     .fallback("Feb 18th at 6:39 AM")
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(date, "<!date^1392734382^{date_short} at {time}|Feb 18th at 6:39 AM>");
+//!
+//! let text = format!("{} deploy finished in {}", mrkdwn::HERE, mrkdwn::format_channel("C123"));
+//! assert_eq!(text, "<!here> deploy finished in <#C123>");
+//! ```
+//!
+//! See: https://api.slack.com/reference/surfaces/formatting
+
+use crate::error::{Result, SlackError};
+use std::fmt;
+
+/// Notifies active members of a channel.
+pub const HERE: &str = "<!here>";
+
+/// Notifies all members of a channel.
+pub const CHANNEL: &str = "<!channel>";
+
+/// Notifies every member of the workspace (only in `#general`).
+pub const EVERYONE: &str = "<!everyone>";
+
+/// Escapes `&`, `<`, and `>` so text is not parsed as a control sequence.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::mrkdwn::escape;
+///
+/// assert_eq!(escape("a < b & c"), "a &lt; b &amp; c");
+/// ```
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Formats a channel link, e.g. `<#C123456>`.
+pub fn format_channel(channel_id: &str) -> String {
+    format!("<#{}>", channel_id)
+}
+
+/// Formats a user group mention, e.g. `<!subteam^S123456>`.
+pub fn format_user_group(usergroup_id: &str) -> String {
+    format!("<!subteam^{}>", usergroup_id)
+}
+
+/// A token that Slack replaces with a localized date or time.
+///
+/// See: https://api.slack.com/reference/surfaces/formatting#date-formatting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateToken {
+    /// Numeric date, e.g. `2014-02-18`.
+    DateNum,
+    /// Date, e.g. `February 18th, 2014`.
+    Date,
+    /// Short date, e.g. `Feb 18, 2014`.
+    DateShort,
+    /// Long date, e.g. `Tuesday, February 18th, 2014`.
+    DateLong,
+    /// Like `Date`, but "yesterday", "today", or "tomorrow" where applicable.
+    DatePretty,
+    /// Like `DateShort`, but "yesterday", "today", or "tomorrow" where applicable.
+    DateShortPretty,
+    /// Like `DateLong`, but "yesterday", "today", or "tomorrow" where applicable.
+    DateLongPretty,
+    /// Time, e.g. `6:39 AM` or `06:39` depending on the reader's settings.
+    Time,
+    /// Time with seconds, e.g. `6:39:42 AM`.
+    TimeSecs,
+    /// Relative time, e.g. `3 minutes ago` or `in 2 hours`.
+    Ago,
+}
+
+impl DateToken {
+    /// Returns the token as it appears in the format string, e.g. `{date_short}`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DateNum => "{date_num}",
+            Self::Date => "{date}",
+            Self::DateShort => "{date_short}",
+            Self::DateLong => "{date_long}",
+            Self::DatePretty => "{date_pretty}",
+            Self::DateShortPretty => "{date_short_pretty}",
+            Self::DateLongPretty => "{date_long_pretty}",
+            Self::Time => "{time}",
+            Self::TimeSecs => "{time_secs}",
+            Self::Ago => "{ago}",
+        }
+    }
+}
+
+impl fmt::Display for DateToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum DatePart {
+    Token(DateToken),
+    Text(String),
+}
+
+/// Builder for `<!date>` sequences rendered in the reader's timezone.
+///
+/// A fallback is required; it is shown by clients that cannot render the
+/// date, such as notifications.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlackDate {
+    timestamp: i64,
+    parts: Vec<DatePart>,
+    link: Option<String>,
+    fallback: Option<String>,
+}
+
+impl SlackDate {
+    /// Creates a builder for the given Unix timestamp (seconds).
+    pub fn new(timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            parts: Vec::new(),
+            link: None,
+            fallback: None,
+        }
+    }
+
+    /// Appends a date or time token.
+    pub fn token(mut self, token: DateToken) -> Self {
+        self.parts.push(DatePart::Token(token));
+        self
+    }
+
+    /// Appends literal text between tokens.
+    pub fn token_text(mut self, text: impl Into<String>) -> Self {
+        self.parts.push(DatePart::Text(text.into()));
+        self
+    }
+
+    /// Sets the text shown when the date cannot be rendered.
+    pub fn fallback(mut self, fallback: impl Into<String>) -> Self {
+        self.fallback = Some(fallback.into());
+        self
+    }
+
+    /// Makes the rendered date a link to `url`.
+    pub fn link(mut self, url: impl Into<String>) -> Self {
+        self.link = Some(url.into());
+        self
+    }
+
+    /// Renders the `<!date>` sequence.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if no token was added, the fallback
+    /// is missing or empty, or literal text or the link contains `^`, `|`,
+    /// `{`, or `}`, which would break the sequence.
+    pub fn build(&self) -> Result<String> {
+        if !self.parts.iter().any(|p| matches!(p, DatePart::Token(_))) {
+            return Err(SlackError::Validation(
+                "SlackDate must contain at least one date token".to_string(),
+            ));
+        }
+
+        let fallback = match self.fallback.as_deref() {
+            Some(fallback) if !fallback.trim().is_empty() => fallback,
+            _ => {
+                return Err(SlackError::Validation(
+                    "SlackDate requires a non-empty fallback".to_string(),
+                ))
+            }
+        };
+
+        let mut format = String::new();
+        for part in &self.parts {
+            match part {
+                DatePart::Token(token) => format.push_str(token.as_str()),
+                DatePart::Text(text) => {
+                    check_date_text("token_text", text)?;
+                    format.push_str(&escape(text));
+                }
+            }
+        }
+
+        let mut rendered = format!("<!date^{}^{}", self.timestamp, format);
+        if let Some(link) = &self.link {
+            check_date_text("link", link)?;
+            rendered.push('^');
+            rendered.push_str(link);
+        }
+        rendered.push('|');
+        rendered.push_str(&escape(fallback));
+        rendered.push('>');
+
+        Ok(rendered)
+    }
+}
+
+/// Rejects characters that delimit parts of a `<!date>` sequence.
+fn check_date_text(field: &str, text: &str) -> Result<()> {
+    if let Some(c) = text.chars().find(|c| matches!(c, '^' | '|' | '{' | '}')) {
+        return Err(SlackError::Validation(format!(
+            "SlackDate {} must not contain '{}'",
+            field, c
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_date_with_link() {
+        let date = SlackDate::new(1392734382)
+            .token(DateToken::DateNum)
+            .token_text(" ")
+            .token(DateToken::TimeSecs)
+            .link("https://example.com/")
+            .fallback("2014-02-18 6:39:42 AM PST")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            date,
+            "<!date^1392734382^{date_num} {time_secs}^https://example.com/|2014-02-18 6:39:42 AM PST>"
+        );
+    }
+
+    #[test]
+    fn test_date_requires_fallback() {
+        let missing = SlackDate::new(0).token(DateToken::Ago).build();
+        assert!(matches!(missing, Err(SlackError::Validation(_))));
+
+        let blank = SlackDate::new(0)
+            .token(DateToken::Ago)
+            .fallback(" ")
+            .build();
+        assert!(blank.is_err());
+    }
+
+    #[test]
+    fn test_date_requires_token() {
+        let result = SlackDate::new(0)
+            .token_text("today")
+            .fallback("today")
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_date_rejects_delimiters() {
+        let result = SlackDate::new(0)
+            .token(DateToken::Date)
+            .token_text(" | ")
+            .fallback("x")
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_date_escapes_text() {
+        let date = SlackDate::new(0)
+            .token(DateToken::DatePretty)
+            .token_text(" <soon>")
+            .fallback("Q&A")
+            .build()
+            .unwrap();
+        assert_eq!(date, "<!date^0^{date_pretty} &lt;soon&gt;|Q&amp;A>");
+    }
+
+    #[test]
+    fn test_mentions() {
+        assert_eq!(format_channel("C123"), "<#C123>");
+        assert_eq!(format_user_group("S123"), "<!subteam^S123>");
+        assert_eq!(HERE, "<!here>");
+        assert_eq!(CHANNEL, "<!channel>");
+        assert_eq!(EVERYONE, "<!everyone>");
+    }
+}
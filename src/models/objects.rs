@@ -4,8 +4,8 @@
 //! the Block Kit API, including text objects, options, and confirmation dialogs.
 
 use crate::constants::limits::{
-    MAX_CONFIRM_TEXT_LENGTH, MAX_CONFIRM_TITLE_LENGTH, MAX_OPTION_GROUPS, MAX_OPTION_LABEL_LENGTH,
-    MAX_OPTION_VALUE_LENGTH, MAX_TEXT_LENGTH,
+    MAX_CONFIRM_TEXT_LENGTH, MAX_CONFIRM_TITLE_LENGTH, MAX_OPTION_GROUP_LABEL_LENGTH,
+    MAX_OPTION_GROUP_OPTIONS, MAX_OPTION_LABEL_LENGTH, MAX_OPTION_VALUE_LENGTH, MAX_TEXT_LENGTH,
 };
 use crate::error::{Result, SlackError};
 use serde::{Deserialize, Serialize};
@@ -49,13 +49,7 @@ impl TextObject {
     /// Returns an error if the text exceeds maximum length.
     pub fn plain(text: impl Into<String>) -> Result<Self> {
         let text = text.into();
-        if text.len() > MAX_TEXT_LENGTH {
-            return Err(SlackError::Validation(format!(
-                "Text length {} exceeds maximum {}",
-                text.len(),
-                MAX_TEXT_LENGTH
-            )));
-        }
+        check_text_length(&text)?;
 
         Ok(Self::PlainText { text, emoji: None })
     }
@@ -63,13 +57,7 @@ impl TextObject {
     /// Creates a new plain text object with emoji setting.
     pub fn plain_with_emoji(text: impl Into<String>, emoji: bool) -> Result<Self> {
         let text = text.into();
-        if text.len() > MAX_TEXT_LENGTH {
-            return Err(SlackError::Validation(format!(
-                "Text length {} exceeds maximum {}",
-                text.len(),
-                MAX_TEXT_LENGTH
-            )));
-        }
+        check_text_length(&text)?;
 
         Ok(Self::PlainText {
             text,
@@ -83,13 +71,7 @@ impl TextObject {
     /// * `text` - The markdown text content (max 3000 characters)
     pub fn markdown(text: impl Into<String>) -> Result<Self> {
         let text = text.into();
-        if text.len() > MAX_TEXT_LENGTH {
-            return Err(SlackError::Validation(format!(
-                "Text length {} exceeds maximum {}",
-                text.len(),
-                MAX_TEXT_LENGTH
-            )));
-        }
+        check_text_length(&text)?;
 
         Ok(Self::Markdown {
             text,
@@ -100,13 +82,7 @@ impl TextObject {
     /// Creates a new markdown text object with verbatim setting.
     pub fn markdown_with_verbatim(text: impl Into<String>, verbatim: bool) -> Result<Self> {
         let text = text.into();
-        if text.len() > MAX_TEXT_LENGTH {
-            return Err(SlackError::Validation(format!(
-                "Text length {} exceeds maximum {}",
-                text.len(),
-                MAX_TEXT_LENGTH
-            )));
-        }
+        check_text_length(&text)?;
 
         Ok(Self::Markdown {
             text,
@@ -123,6 +99,110 @@ impl TextObject {
     }
 }
 
+/// Parses text into a `plain_text` object.
+///
+/// # Errors
+/// Returns an error if the text exceeds the maximum length.
+impl TryFrom<String> for TextObject {
+    type Error = SlackError;
+
+    fn try_from(text: String) -> Result<Self> {
+        Self::plain(text)
+    }
+}
+
+/// Parses text into a `plain_text` object.
+///
+/// # Errors
+/// Returns an error if the text exceeds the maximum length.
+impl TryFrom<&str> for TextObject {
+    type Error = SlackError;
+
+    fn try_from(text: &str) -> Result<Self> {
+        Self::plain(text)
+    }
+}
+
+/// Plain text whose length was checked at construction.
+///
+/// Converts into a `plain_text` [`TextObject`] without further checks.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::{PlainText, TextObject};
+///
+/// let label = PlainText::new("Approve").unwrap();
+/// let text: TextObject = label.into();
+/// assert_eq!(text.text(), "Approve");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlainText(String);
+
+impl PlainText {
+    /// Creates plain text (max 3000 characters).
+    pub fn new(text: impl Into<String>) -> Result<Self> {
+        let text = text.into();
+        check_text_length(&text)?;
+        Ok(Self(text))
+    }
+
+    /// Returns the text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<PlainText> for TextObject {
+    fn from(text: PlainText) -> Self {
+        Self::PlainText {
+            text: text.0,
+            emoji: None,
+        }
+    }
+}
+
+/// Markdown text whose length was checked at construction.
+///
+/// Converts into a `mrkdwn` [`TextObject`] without further checks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MrkdwnText(String);
+
+impl MrkdwnText {
+    /// Creates markdown text (max 3000 characters).
+    pub fn new(text: impl Into<String>) -> Result<Self> {
+        let text = text.into();
+        check_text_length(&text)?;
+        Ok(Self(text))
+    }
+
+    /// Returns the text.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<MrkdwnText> for TextObject {
+    fn from(text: MrkdwnText) -> Self {
+        Self::Markdown {
+            text: text.0,
+            verbatim: None,
+        }
+    }
+}
+
+/// Rejects text longer than a text object allows.
+fn check_text_length(text: &str) -> Result<()> {
+    if text.len() > MAX_TEXT_LENGTH {
+        return Err(SlackError::Validation(format!(
+            "Text length {} exceeds maximum {}",
+            text.len(),
+            MAX_TEXT_LENGTH
+        )));
+    }
+    Ok(())
+}
+
 /// An option for select menus and other choice-based elements.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SlackOption {
@@ -194,10 +274,29 @@ impl SlackOption {
         self.url = Some(url.into());
         self
     }
+
+    /// Sets whether emoji in the label are rendered.
+    ///
+    /// # Errors
+    /// Returns an error if `emoji` is true and the label is `mrkdwn`, which
+    /// does not support the `emoji` flag.
+    pub fn with_emoji(mut self, emoji: bool) -> Result<Self> {
+        match &mut self.text {
+            TextObject::PlainText { emoji: flag, .. } => *flag = Some(emoji),
+            TextObject::Markdown { .. } if emoji => {
+                return Err(SlackError::Validation(
+                    "Option emoji can only be set on a plain_text label".to_string(),
+                ))
+            }
+            TextObject::Markdown { .. } => {}
+        }
+        Ok(self)
+    }
 }
 
 /// A group of options with a label.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct OptionGroup {
     /// A plain text label for the group.
     pub label: TextObject,
@@ -210,21 +309,119 @@ impl OptionGroup {
     /// Creates a new option group.
     ///
     /// # Arguments
-    /// * `label` - The group label
-    /// * `options` - The options in this group (max 100)
+    /// * `label` - The group label (max 75 characters)
+    /// * `options` - The options in this group (1 to 100)
+    ///
+    /// # Errors
+    /// Returns an error if the label is too long or the option count is out
+    /// of range.
     pub fn new(label: impl Into<String>, options: Vec<SlackOption>) -> Result<Self> {
-        if options.len() > MAX_OPTION_GROUPS {
+        let group = Self {
+            label: TextObject::plain(label)?,
+            options,
+        };
+
+        group.validate()?;
+        Ok(group)
+    }
+
+    /// Creates a new option group builder.
+    pub fn builder() -> OptionGroupBuilder {
+        OptionGroupBuilder::default()
+    }
+
+    /// Validates the label type and length and the number of options.
+    pub fn validate(&self) -> Result<()> {
+        let TextObject::PlainText { text, .. } = &self.label else {
+            return Err(SlackError::Validation(
+                "Option group label must be plain_text".to_string(),
+            ));
+        };
+
+        if text.len() > MAX_OPTION_GROUP_LABEL_LENGTH {
+            return Err(SlackError::Validation(format!(
+                "Option group label length {} exceeds maximum {}",
+                text.len(),
+                MAX_OPTION_GROUP_LABEL_LENGTH
+            )));
+        }
+
+        if self.options.is_empty() {
+            return Err(SlackError::Validation(
+                "Option group must have at least one option".to_string(),
+            ));
+        }
+
+        if self.options.len() > MAX_OPTION_GROUP_OPTIONS {
             return Err(SlackError::Validation(format!(
                 "Option group has {} options, maximum is {}",
-                options.len(),
-                MAX_OPTION_GROUPS
+                self.options.len(),
+                MAX_OPTION_GROUP_OPTIONS
             )));
         }
 
-        Ok(Self {
-            label: TextObject::plain(label)?,
-            options,
-        })
+        Ok(())
+    }
+}
+
+/// Builder for option groups.
+#[derive(Debug, Default)]
+pub struct OptionGroupBuilder {
+    label: Option<TextObject>,
+    options: Vec<SlackOption>,
+}
+
+impl OptionGroupBuilder {
+    /// Sets the label (plain text).
+    pub fn label_text(mut self, label: impl Into<String>) -> Result<Self> {
+        self.label = Some(TextObject::plain(label)?);
+        Ok(self)
+    }
+
+    /// Sets the label with a custom text object.
+    ///
+    /// # Errors
+    /// Returns an error if the label is not `plain_text`.
+    pub fn label(mut self, label: TextObject) -> Result<Self> {
+        if !matches!(label, TextObject::PlainText { .. }) {
+            return Err(SlackError::Validation(
+                "Option group label must be plain_text".to_string(),
+            ));
+        }
+        self.label = Some(label);
+        Ok(self)
+    }
+
+    /// Adds an option to the group.
+    ///
+    /// # Errors
+    /// Returns an error if the group already has 100 options.
+    pub fn add_option(mut self, option: SlackOption) -> Result<Self> {
+        if self.options.len() >= MAX_OPTION_GROUP_OPTIONS {
+            return Err(SlackError::Validation(format!(
+                "Option group cannot have more than {} options",
+                MAX_OPTION_GROUP_OPTIONS
+            )));
+        }
+        self.options.push(option);
+        Ok(self)
+    }
+
+    /// Builds the option group.
+    ///
+    /// # Errors
+    /// Returns an error if no label was set or no options were added.
+    pub fn build(self) -> Result<OptionGroup> {
+        let label = self
+            .label
+            .ok_or_else(|| SlackError::Validation("Option group requires a label".to_string()))?;
+        let group = OptionGroup {
+            label,
+            options: self.options,
+        };
+
+        group.validate()?;
+        Ok(group)
     }
 }
 
@@ -468,6 +665,33 @@ mod tests {
 
     // Option Tests
 
+    #[test]
+    fn test_text_object_try_from() {
+        let text = TextObject::try_from("Hello".to_string()).unwrap();
+        assert_eq!(text, TextObject::plain("Hello").unwrap());
+
+        let text: TextObject = "Hi".try_into().unwrap();
+        assert_eq!(text, TextObject::plain("Hi").unwrap());
+
+        assert!(TextObject::try_from("a".repeat(3001)).is_err());
+    }
+
+    #[test]
+    fn test_text_newtypes() {
+        let plain: TextObject = PlainText::new("Plain").unwrap().into();
+        assert_eq!(plain, TextObject::plain("Plain").unwrap());
+
+        let markdown = MrkdwnText::new("*Bold*").unwrap();
+        assert_eq!(markdown.as_str(), "*Bold*");
+        assert_eq!(
+            TextObject::from(markdown),
+            TextObject::markdown("*Bold*").unwrap()
+        );
+
+        assert!(PlainText::new("a".repeat(3001)).is_err());
+        assert!(MrkdwnText::new("a".repeat(3001)).is_err());
+    }
+
     #[test]
     fn test_option_basic() {
         let option = SlackOption::new("an option", "option_1").unwrap();
@@ -543,6 +767,35 @@ mod tests {
         assert!(json_val["url"] == "https://example.com");
     }
 
+    #[test]
+    fn test_option_with_emoji_round_trip() {
+        let option = SlackOption::new(":tada: Launch", "launch")
+            .unwrap()
+            .with_emoji(true)
+            .unwrap();
+
+        let json = serde_json::to_value(&option).unwrap();
+        assert_eq!(
+            json,
+            json!({
+                "text": {"type": "plain_text", "text": ":tada: Launch", "emoji": true},
+                "value": "launch"
+            })
+        );
+
+        let deserialized: SlackOption = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized, option);
+    }
+
+    #[test]
+    fn test_option_with_emoji_rejects_markdown() {
+        let mut option = SlackOption::new("*Launch*", "launch").unwrap();
+        option.text = TextObject::markdown("*Launch*").unwrap();
+
+        assert!(option.clone().with_emoji(true).is_err());
+        assert_eq!(option.clone().with_emoji(false).unwrap(), option);
+    }
+
     #[test]
     fn test_option_label_length_validation() {
         let long_label = "a".repeat(76);
@@ -638,6 +891,70 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_option_group_rejects_empty_options() {
+        assert!(OptionGroup::new("empty", vec![]).is_err());
+        assert!(OptionGroup::builder()
+            .label_text("empty")
+            .unwrap()
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_option_group_builder() {
+        let group = OptionGroup::builder()
+            .label_text("Fruit")
+            .unwrap()
+            .add_option(SlackOption::from_single_value("apple").unwrap())
+            .unwrap()
+            .add_option(SlackOption::from_single_value("pear").unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            group,
+            OptionGroup::new(
+                "Fruit",
+                vec![
+                    SlackOption::from_single_value("apple").unwrap(),
+                    SlackOption::from_single_value("pear").unwrap(),
+                ]
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_option_group_builder_validation() {
+        assert!(OptionGroup::builder()
+            .label(TextObject::markdown("*Fruit*").unwrap())
+            .is_err());
+        assert!(OptionGroup::builder()
+            .add_option(SlackOption::from_single_value("apple").unwrap())
+            .unwrap()
+            .build()
+            .is_err());
+        assert!(OptionGroup::builder()
+            .label_text("x".repeat(76))
+            .unwrap()
+            .add_option(SlackOption::from_single_value("apple").unwrap())
+            .unwrap()
+            .build()
+            .is_err());
+
+        let mut builder = OptionGroup::builder().label_text("Many").unwrap();
+        for i in 0..100 {
+            builder = builder
+                .add_option(SlackOption::from_single_value(format!("opt{}", i)).unwrap())
+                .unwrap();
+        }
+        assert!(builder
+            .add_option(SlackOption::from_single_value("opt100").unwrap())
+            .is_err());
+    }
+
     // ConfirmObject Tests
 
     #[test]
@@ -0,0 +1,247 @@
+//! Slack message timestamps.
+
+use crate::error::SlackError;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A message timestamp such as `"1712345678.000200"`.
+///
+/// Slack identifies messages by these strings. The original string is kept
+/// and serialized unchanged, while comparisons are numeric, so
+/// `"999999999.5"` sorts before `"1000000000.1"` and `"1712345678.5"`
+/// equals `"1712345678.500000"`. Unlike converting to `f64`, no precision
+/// is lost.
+///
+/// `From<&str>` accepts any string, as Slack sends it; parse with
+/// [`FromStr`] to reject malformed values.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::Timestamp;
+///
+/// let parent: Timestamp = "1712345678.000200".parse()?;
+/// let reply = Timestamp::from("1712345690.000100");
+/// assert!(parent < reply);
+/// assert_eq!(parent.permalink_id(), "p1712345678000200");
+/// # Ok::<(), slack_rs::error::SlackError>(())
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Timestamp(String);
+
+impl Timestamp {
+    /// Returns the timestamp as sent by Slack.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Creates the timestamp of `datetime`, with six decimal places.
+    pub fn from_datetime(datetime: DateTime<Utc>) -> Self {
+        Self(format!(
+            "{}.{:06}",
+            datetime.timestamp(),
+            datetime.timestamp_subsec_micros()
+        ))
+    }
+
+    /// Returns the time the timestamp refers to, or None if it is not a
+    /// valid `seconds.micros` value.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        let (seconds, micros) = parse(&self.0)?;
+        DateTime::from_timestamp(seconds, micros * 1_000)
+    }
+
+    /// Returns the time the timestamp refers to.
+    #[deprecated(note = "use to_datetime instead")]
+    pub fn as_datetime(&self) -> Option<DateTime<Utc>> {
+        self.to_datetime()
+    }
+
+    /// Returns the message ID used in permalinks: `p` followed by the
+    /// timestamp without its dot.
+    pub fn permalink_id(&self) -> String {
+        format!("p{}", self.0.replace('.', ""))
+    }
+
+    /// Key for comparisons: numeric for valid timestamps, which sort before
+    /// invalid ones compared as strings.
+    fn key(&self) -> (bool, i64, u32, &str) {
+        match parse(&self.0) {
+            Some((seconds, micros)) => (false, seconds, micros, ""),
+            None => (true, 0, 0, &self.0),
+        }
+    }
+}
+
+/// Splits `seconds.fraction` into seconds and microseconds.
+fn parse(ts: &str) -> Option<(i64, u32)> {
+    let (seconds, fraction) = match ts.split_once('.') {
+        Some((_, "")) => return None,
+        Some(parts) => parts,
+        None => (ts, ""),
+    };
+    if seconds.is_empty()
+        || !seconds.bytes().all(|b| b.is_ascii_digit())
+        || fraction.len() > 6
+        || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let micros = format!("{:0<6}", fraction).parse().ok()?;
+    Some((seconds.parse().ok()?, micros))
+}
+
+impl PartialEq for Timestamp {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Timestamp {}
+
+impl Hash for Timestamp {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = SlackError;
+
+    /// Parses a `seconds.micros` timestamp, keeping its exact formatting.
+    fn from_str(ts: &str) -> Result<Self, Self::Err> {
+        match parse(ts) {
+            Some(_) => Ok(Self(ts.to_string())),
+            None => Err(SlackError::Validation(format!(
+                "invalid message timestamp: {:?}",
+                ts
+            ))),
+        }
+    }
+}
+
+impl From<DateTime<Utc>> for Timestamp {
+    fn from(datetime: DateTime<Utc>) -> Self {
+        Self::from_datetime(datetime)
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(ts: &str) -> Self {
+        Self(ts.to_string())
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(ts: String) -> Self {
+        Self(ts)
+    }
+}
+
+impl From<Timestamp> for crate::web::TimestampParam {
+    fn from(ts: Timestamp) -> Self {
+        ts.0.into()
+    }
+}
+
+impl From<&Timestamp> for crate::web::TimestampParam {
+    fn from(ts: &Timestamp) -> Self {
+        ts.as_str().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_ordering_is_numeric() {
+        let mut timestamps: Vec<Timestamp> = ["1712345678.10", "1712345678.9", "999999999.999999"]
+            .into_iter()
+            .map(Timestamp::from)
+            .collect();
+        timestamps.sort();
+        assert_eq!(
+            timestamps.iter().map(Timestamp::as_str).collect::<Vec<_>>(),
+            ["999999999.999999", "1712345678.10", "1712345678.9"]
+        );
+        assert_eq!(
+            Timestamp::from("1712345678.5"),
+            Timestamp::from("1712345678.500000")
+        );
+    }
+
+    #[test]
+    fn test_datetime_conversion() {
+        let ts = Timestamp::from("1712345678.000200");
+        let datetime = ts.to_datetime().unwrap();
+        assert_eq!(datetime.timestamp(), 1712345678);
+        assert_eq!(datetime.timestamp_subsec_micros(), 200);
+        assert!(Timestamp::from("not-a-ts").to_datetime().is_none());
+
+        for raw in [
+            "1712345678.000200",
+            "1712345678.100000",
+            "1712345678.000000",
+        ] {
+            let ts: Timestamp = raw.parse().unwrap();
+            let round_trip = Timestamp::from_datetime(ts.to_datetime().unwrap());
+            assert_eq!(round_trip.as_str(), raw);
+            assert_eq!(round_trip, ts);
+        }
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_as_datetime_forwards_to_to_datetime() {
+        let ts = Timestamp::from("1712345678.000200");
+        assert_eq!(ts.as_datetime(), ts.to_datetime());
+        assert!(Timestamp::from("not-a-ts").as_datetime().is_none());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let ts: Timestamp = "1712345678.120000".parse().unwrap();
+        assert_eq!(ts.to_string(), "1712345678.120000");
+
+        for invalid in ["", "abc", "1712345678.", ".5", "1712345678.1234567", "-1.0"] {
+            assert!(
+                matches!(invalid.parse::<Timestamp>(), Err(SlackError::Validation(_))),
+                "{:?}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_serde_keeps_original_string() {
+        for raw in [r#""1712345678.000200""#, r#""1712345678.500000""#] {
+            let ts: Timestamp = serde_json::from_str(raw).unwrap();
+            assert_eq!(serde_json::to_string(&ts).unwrap(), raw);
+        }
+    }
+}
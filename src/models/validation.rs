@@ -0,0 +1,123 @@
+//! Checks spanning several blocks of a message or view.
+
+use crate::models::blocks::Block;
+use std::collections::HashMap;
+use std::fmt;
+
+/// A `block_id` used by more than one block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockIdConflict {
+    /// The duplicated ID.
+    pub block_id: String,
+
+    /// Positions of every block using it, in order.
+    pub indices: Vec<usize>,
+}
+
+impl fmt::Display for BlockIdConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let indices: Vec<String> = self.indices.iter().map(usize::to_string).collect();
+        write!(
+            f,
+            "duplicate block_id '{}' at blocks {}",
+            self.block_id,
+            indices.join(", ")
+        )
+    }
+}
+
+/// Checks that no two blocks share a `block_id`, as Slack requires within a
+/// message or view.
+///
+/// Returns every conflict, ordered by where the first duplicate appears.
+/// Blocks without an ID are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::models::blocks::{Block, DividerBlock};
+/// use slack_rs::models::validation::validate_block_ids;
+///
+/// let blocks: Vec<Block> = vec![
+///     DividerBlock::new().with_block_id("a")?.into(),
+///     DividerBlock::new().into(),
+///     DividerBlock::new().with_block_id("a")?.into(),
+/// ];
+/// let conflicts = validate_block_ids(&blocks).unwrap_err();
+/// assert_eq!(conflicts[0].block_id, "a");
+/// assert_eq!(conflicts[0].indices, [0, 2]);
+/// # Ok::<(), slack_rs::error::SlackError>(())
+/// ```
+pub fn validate_block_ids(blocks: &[Block]) -> Result<(), Vec<BlockIdConflict>> {
+    let mut indices: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, block) in blocks.iter().enumerate() {
+        if let Some(block_id) = block.block_id() {
+            indices.entry(block_id).or_default().push(index);
+        }
+    }
+
+    let mut conflicts: Vec<BlockIdConflict> = indices
+        .into_iter()
+        .filter(|(_, indices)| indices.len() > 1)
+        .map(|(block_id, indices)| BlockIdConflict {
+            block_id: block_id.to_string(),
+            indices,
+        })
+        .collect();
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    conflicts.sort_by_key(|conflict| conflict.indices[1]);
+    Err(conflicts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::blocks::{DividerBlock, HeaderBlock};
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_validate_block_ids_reports_every_conflict() {
+        let mut blocks: Vec<Block> = vec![
+            HeaderBlock::new("Report").unwrap().into(),
+            DividerBlock::new().with_block_id("b").unwrap().into(),
+            DividerBlock::new().with_block_id("a").unwrap().into(),
+            Block::Unknown(json!({"type": "rich_text", "block_id": "a"})),
+            DividerBlock::new().with_block_id("b").unwrap().into(),
+            DividerBlock::new().with_block_id("a").unwrap().into(),
+        ];
+
+        assert_eq!(
+            validate_block_ids(&blocks).unwrap_err(),
+            [
+                BlockIdConflict {
+                    block_id: "a".to_string(),
+                    indices: vec![2, 3, 5],
+                },
+                BlockIdConflict {
+                    block_id: "b".to_string(),
+                    indices: vec![1, 4],
+                },
+            ]
+        );
+
+        for (index, block_id) in [(3, "c"), (4, "d"), (5, "e")] {
+            blocks[index].set_block_id(block_id).unwrap();
+        }
+        assert!(validate_block_ids(&blocks).is_ok());
+    }
+
+    #[test]
+    fn test_conflict_display() {
+        let conflict = BlockIdConflict {
+            block_id: "a".to_string(),
+            indices: vec![0, 2],
+        };
+        assert_eq!(
+            conflict.to_string(),
+            "duplicate block_id 'a' at blocks 0, 2"
+        );
+    }
+}
@@ -2,11 +2,14 @@
 
 use crate::constants::limits::{
     MAX_CALLBACK_ID_LENGTH, MAX_PRIVATE_METADATA_LENGTH, MAX_VIEW_BLOCKS, MAX_VIEW_BUTTON_LENGTH,
-    MAX_VIEW_TITLE_LENGTH,
+    MAX_VIEW_ERROR_MESSAGE_LENGTH, MAX_VIEW_TITLE_LENGTH,
 };
 use crate::error::{Result, SlackError};
-use crate::models::objects::TextObject;
-use serde::{Deserialize, Serialize};
+use crate::models::blocks::Block;
+use crate::models::objects::{SlackOption, TextObject};
+use crate::models::validation::validate_block_ids;
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -42,6 +45,10 @@ pub struct View {
     pub close: Option<TextObject>,
 
     /// An array of blocks that defines the content of the view (max 100).
+    ///
+    /// Kept as raw JSON so that blocks this crate does not model round-trip
+    /// unchanged; parse them with [`Block`](crate::models::blocks::Block)
+    /// for typed access.
     pub blocks: Vec<Value>,
 
     /// Private metadata for the view (max 3000 characters).
@@ -238,7 +245,22 @@ impl View {
         self
     }
 
+    /// Returns the view's blocks as typed [`Block`]s.
+    ///
+    /// Blocks this crate cannot parse are returned as [`Block::Unknown`].
+    pub fn typed_blocks(&self) -> Vec<Block> {
+        self.blocks
+            .iter()
+            .map(|value| {
+                Block::deserialize(value).unwrap_or_else(|_| Block::Unknown(value.clone()))
+            })
+            .collect()
+    }
+
     /// Validates the view.
+    ///
+    /// Besides the view-level rules, checks that no two blocks share a
+    /// `block_id` (see [`validate_block_ids`]).
     pub fn validate(&self) -> Result<()> {
         // Check view type
         if self.view_type != "modal" && self.view_type != "home" {
@@ -261,7 +283,11 @@ impl View {
             ));
         }
 
-        Ok(())
+        // Block IDs must be unique within the view
+        validate_block_ids(&self.typed_blocks()).map_err(|conflicts| {
+            let conflicts: Vec<String> = conflicts.iter().map(ToString::to_string).collect();
+            SlackError::Validation(format!("View has {}", conflicts.join("; ")))
+        })
     }
 }
 
@@ -305,13 +331,13 @@ pub struct ViewStateValue {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
 
-    /// Selected option (for single selects).
+    /// Selected option (for single selects and radio buttons).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selected_option: Option<Value>,
+    pub selected_option: Option<SlackOption>,
 
-    /// Selected options (for multi selects).
+    /// Selected options (for multi selects and checkboxes).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub selected_options: Option<Vec<Value>>,
+    pub selected_options: Option<Vec<SlackOption>>,
 
     /// Selected date (for date pickers).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -350,6 +376,145 @@ pub struct ViewStateValue {
     pub selected_channels: Option<Vec<String>>,
 }
 
+/// A `response_action` returned when acknowledging a `view_submission`.
+///
+/// Serializes to the JSON shape Slack expects in the acknowledgment body,
+/// for example `{"response_action": "errors", "errors": {"block_id": "message"}}`.
+///
+/// See: https://api.slack.com/surfaces/modals#updating_response
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewResponseAction {
+    /// Replaces the submitted view with a new one.
+    Update(View),
+    /// Pushes a new view onto the modal stack.
+    Push(View),
+    /// Closes all views in the modal stack.
+    Clear,
+    /// Shows validation errors, keyed by the `block_id` of each input block.
+    Errors(HashMap<String, String>),
+}
+
+impl ViewResponseAction {
+    /// Creates an `Errors` action from `(block_id, message)` pairs.
+    pub fn errors<I, K, V>(errors: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self::Errors(
+            errors
+                .into_iter()
+                .map(|(k, v)| (k.into(), v.into()))
+                .collect(),
+        )
+    }
+
+    /// Returns the `response_action` value.
+    pub fn action(&self) -> &'static str {
+        match self {
+            Self::Update(_) => "update",
+            Self::Push(_) => "push",
+            Self::Clear => "clear",
+            Self::Errors(_) => "errors",
+        }
+    }
+
+    /// Validates the response action.
+    ///
+    /// `Update` and `Push` views must be valid modals. `Errors` must contain
+    /// at least one entry, every key must be a non-empty `block_id`, and
+    /// every message must be at most 255 characters.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::Update(view) | Self::Push(view) => {
+                if view.view_type != "modal" {
+                    return Err(SlackError::Validation(format!(
+                        "response_action '{}' requires a modal view",
+                        self.action()
+                    )));
+                }
+                view.validate()
+            }
+            Self::Clear => Ok(()),
+            Self::Errors(errors) => {
+                if errors.is_empty() {
+                    return Err(SlackError::Validation(
+                        "response_action 'errors' must contain at least one error".to_string(),
+                    ));
+                }
+                for (block_id, message) in errors {
+                    if block_id.is_empty() {
+                        return Err(SlackError::Validation(
+                            "response_action 'errors' keys must be non-empty block_ids".to_string(),
+                        ));
+                    }
+                    if message.chars().count() > MAX_VIEW_ERROR_MESSAGE_LENGTH {
+                        return Err(SlackError::Validation(format!(
+                            "Error message for block '{}' exceeds {} characters",
+                            block_id, MAX_VIEW_ERROR_MESSAGE_LENGTH
+                        )));
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Validates the action and converts it into an acknowledgment payload.
+    pub fn to_payload(&self) -> Result<Value> {
+        self.validate()?;
+        Ok(serde_json::to_value(self)?)
+    }
+}
+
+impl Serialize for ViewResponseAction {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("response_action", self.action())?;
+        match self {
+            Self::Update(view) | Self::Push(view) => map.serialize_entry("view", view)?,
+            Self::Clear => {}
+            Self::Errors(errors) => map.serialize_entry("errors", errors)?,
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ViewResponseAction {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(Deserialize)]
+        struct Raw {
+            response_action: String,
+            view: Option<View>,
+            errors: Option<HashMap<String, String>>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        match raw.response_action.as_str() {
+            "update" => raw
+                .view
+                .map(Self::Update)
+                .ok_or_else(|| D::Error::missing_field("view")),
+            "push" => raw
+                .view
+                .map(Self::Push)
+                .ok_or_else(|| D::Error::missing_field("view")),
+            "clear" => Ok(Self::Clear),
+            "errors" => raw
+                .errors
+                .map(Self::Errors)
+                .ok_or_else(|| D::Error::missing_field("errors")),
+            other => Err(D::Error::unknown_variant(
+                other,
+                &["update", "push", "clear", "errors"],
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -619,6 +784,28 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_view_validate_duplicate_block_ids() {
+        let view = View::modal(
+            "Title",
+            vec![
+                json!({"type": "divider", "block_id": "a"}),
+                json!({"type": "divider", "block_id": "b"}),
+                json!({"type": "divider", "block_id": "a"}),
+                json!({"type": "divider", "block_id": "b"}),
+            ],
+        )
+        .unwrap();
+        match view.validate() {
+            Err(SlackError::Validation(message)) => assert_eq!(
+                message,
+                "View has duplicate block_id 'a' at blocks 0, 2; \
+                 duplicate block_id 'b' at blocks 1, 3"
+            ),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_view_state_get() {
         let mut state = ViewState::new();
@@ -669,4 +856,130 @@ mod tests {
 
         assert_eq!(input, output);
     }
+
+    // ViewResponseAction tests
+    fn modal_json() -> Value {
+        json!({
+            "type": "modal",
+            "title": {"type": "plain_text", "text": "Updated view"},
+            "blocks": [{"type": "section", "text": {"type": "plain_text", "text": "Done"}}]
+        })
+    }
+
+    #[test]
+    fn test_response_action_update() {
+        let view: View = serde_json::from_value(modal_json()).unwrap();
+        let action = ViewResponseAction::Update(view);
+
+        let expected = json!({"response_action": "update", "view": modal_json()});
+        assert_eq!(action.to_payload().unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<ViewResponseAction>(expected).unwrap(),
+            action
+        );
+    }
+
+    #[test]
+    fn test_response_action_push() {
+        let view: View = serde_json::from_value(modal_json()).unwrap();
+        let action = ViewResponseAction::Push(view);
+
+        let expected = json!({"response_action": "push", "view": modal_json()});
+        assert_eq!(serde_json::to_value(&action).unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<ViewResponseAction>(expected).unwrap(),
+            action
+        );
+    }
+
+    #[test]
+    fn test_response_action_clear() {
+        let expected = json!({"response_action": "clear"});
+        assert_eq!(
+            serde_json::to_value(ViewResponseAction::Clear).unwrap(),
+            expected
+        );
+        assert_eq!(
+            serde_json::from_value::<ViewResponseAction>(expected).unwrap(),
+            ViewResponseAction::Clear
+        );
+    }
+
+    #[test]
+    fn test_response_action_errors() {
+        let action = ViewResponseAction::errors([(
+            "ticket-due-date",
+            "You may not select a due date in the past",
+        )]);
+
+        let expected = json!({
+            "response_action": "errors",
+            "errors": {"ticket-due-date": "You may not select a due date in the past"}
+        });
+        assert_eq!(action.to_payload().unwrap(), expected);
+        assert_eq!(
+            serde_json::from_value::<ViewResponseAction>(expected).unwrap(),
+            action
+        );
+    }
+
+    #[test]
+    fn test_response_action_errors_validation() {
+        assert!(ViewResponseAction::errors(Vec::<(String, String)>::new())
+            .validate()
+            .is_err());
+        assert!(ViewResponseAction::errors([("", "Required")])
+            .validate()
+            .is_err());
+        assert!(ViewResponseAction::errors([("block", "a".repeat(256))])
+            .validate()
+            .is_err());
+        assert!(ViewResponseAction::errors([("block", "a".repeat(255))])
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_response_action_requires_modal() {
+        let home = View::home(vec![json!({"type": "divider"})]).unwrap();
+        assert!(ViewResponseAction::Push(home).validate().is_err());
+    }
+
+    #[test]
+    fn test_response_action_missing_view() {
+        let result =
+            serde_json::from_value::<ViewResponseAction>(json!({"response_action": "update"}));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_view_state_value_typed_options() {
+        let input = json!({
+            "type": "static_select",
+            "selected_option": {
+                "text": {"type": "plain_text", "text": "High", "emoji": true},
+                "value": "high"
+            }
+        });
+
+        let value: ViewStateValue = serde_json::from_value(input.clone()).unwrap();
+        assert_eq!(value.selected_option.as_ref().unwrap().value, "high");
+        assert_eq!(serde_json::to_value(&value).unwrap(), input);
+
+        let checkboxes: ViewStateValue = serde_json::from_value(json!({
+            "type": "checkboxes",
+            "selected_options": [
+                {"text": {"type": "mrkdwn", "text": "*Email*"}, "value": "email"},
+                {"text": {"type": "mrkdwn", "text": "*SMS*"}, "value": "sms"}
+            ]
+        }))
+        .unwrap();
+        let values: Vec<&str> = checkboxes
+            .selected_options
+            .iter()
+            .flatten()
+            .map(|option| option.value.as_str())
+            .collect();
+        assert_eq!(values, ["email", "sms"]);
+    }
 }
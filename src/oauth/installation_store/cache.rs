@@ -3,7 +3,7 @@
 //! Provides fast, thread-safe in-memory storage for installations.
 
 use crate::error::Result;
-use crate::oauth::installation_store::InstallationStore;
+use crate::oauth::installation_store::{InstallationStore, Page};
 use crate::oauth::models::{Bot, Installation};
 use async_trait::async_trait;
 use std::collections::HashMap;
@@ -12,7 +12,7 @@ use tokio::sync::RwLock;
 use tracing::debug;
 
 /// Key for looking up installations
-#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+#[derive(Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 struct InstallationKey {
     enterprise_id: Option<String>,
     team_id: Option<String>,
@@ -266,11 +266,65 @@ impl InstallationStore for CacheInstallationStore {
 
         Ok(())
     }
+
+    async fn find_all_installations(
+        &self,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<Page<Installation>> {
+        // Each installation is also stored under a workspace-level key with
+        // no user_id; only the user-specific entries are listed.
+        let mut entries: Vec<_> = self
+            .installations
+            .read()
+            .await
+            .iter()
+            .filter(|(key, _)| key.user_id.is_some())
+            .map(|(key, installation)| (key.clone(), installation.clone()))
+            .collect();
+        entries.sort_by(|(a_key, a), (b_key, b)| {
+            a.installed_at
+                .total_cmp(&b.installed_at)
+                .then_with(|| a_key.cmp(b_key))
+        });
+
+        Page::from_sorted(
+            entries
+                .into_iter()
+                .map(|(_, installation)| installation)
+                .collect(),
+            limit,
+            cursor,
+        )
+    }
+
+    async fn find_all_bots(&self, limit: usize, cursor: Option<&str>) -> Result<Page<Bot>> {
+        let mut entries: Vec<_> = self
+            .bots
+            .read()
+            .await
+            .iter()
+            .map(|(key, bot)| (key.clone(), bot.clone()))
+            .collect();
+        entries.sort_by(|(a_key, a), (b_key, b)| {
+            a.installed_at
+                .total_cmp(&b.installed_at)
+                .then_with(|| a_key.cmp(b_key))
+        });
+
+        Page::from_sorted(
+            entries.into_iter().map(|(_, bot)| bot).collect(),
+            limit,
+            cursor,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
+    use crate::oauth::installation_store::encode_cursor;
 
     #[tokio::test]
     async fn test_save_and_find_installation() {
@@ -465,4 +519,99 @@ mod tests {
             .unwrap();
         assert!(found2.is_some());
     }
+
+    #[tokio::test]
+    async fn test_find_all_installations_paginates() {
+        let store = CacheInstallationStore::new();
+
+        // Save newest first so the listing has to sort by installed_at.
+        for i in (0..25).rev() {
+            let mut installation = Installation::new(format!("U{:02}", i))
+                .team_id(format!("T{:02}", i))
+                .bot_token("xoxb-token")
+                .bot_id("B12345")
+                .bot_user_id("U67890");
+            installation.installed_at = 1_700_000_000.0 + i as f64;
+            store.save(installation).await.unwrap();
+        }
+
+        let mut user_ids = Vec::new();
+        let mut page_sizes = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = store
+                .find_all_installations(10, cursor.as_deref())
+                .await
+                .unwrap();
+            page_sizes.push(page.items.len());
+            user_ids.extend(page.items.into_iter().map(|i| i.user_id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(page_sizes, vec![10, 10, 5]);
+        let expected: Vec<String> = (0..25).map(|i| format!("U{:02}", i)).collect();
+        assert_eq!(user_ids, expected);
+
+        let bots = store
+            .find_all_bots(10, Some(&encode_cursor(20)))
+            .await
+            .unwrap();
+        assert_eq!(bots.items.len(), 5);
+        assert_eq!(bots.items[0].team_id.as_deref(), Some("T20"));
+        assert!(!bots.has_more());
+
+        // Cursors are opaque; a raw offset is rejected
+        assert!(matches!(
+            store.find_all_bots(10, Some("20")).await,
+            Err(Error::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_all_installations_rejects_bad_input() {
+        let store = CacheInstallationStore::new();
+
+        assert!(store.find_all_installations(0, None).await.is_err());
+        assert!(store
+            .find_all_installations(10, Some("not-a-cursor"))
+            .await
+            .is_err());
+
+        let page = store.find_all_installations(10, None).await.unwrap();
+        assert!(page.items.is_empty());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_custom_values_round_trip() {
+        let store = CacheInstallationStore::new();
+
+        let mut installation = Installation::new("U12345")
+            .team_id("T12345")
+            .bot_token("xoxb-token")
+            .bot_id("B12345")
+            .bot_user_id("U67890");
+        installation.set_custom_value(
+            "settings",
+            serde_json::json!({"locale": "de-DE", "channels": ["C1", "C2"], "limits": {"daily": 50}}),
+        );
+        store.save(installation.clone()).await.unwrap();
+
+        let found = store
+            .find_installation(None, Some("T12345"), Some("U12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.custom_values, installation.custom_values);
+
+        let bot = store
+            .find_bot(None, Some("T12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bot.custom_values, installation.custom_values);
+    }
 }
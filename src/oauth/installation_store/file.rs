@@ -4,13 +4,71 @@
 //! Stores installations as JSON files in a directory structure.
 
 use crate::error::{Error, Result};
-use crate::oauth::installation_store::InstallationStore;
+use crate::oauth::installation_store::{InstallationStore, Page};
 use crate::oauth::models::{Bot, Installation};
 use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tokio::fs;
+use tokio::io::AsyncWriteExt;
 use tracing::{debug, warn};
 
+/// Key under which a file holds the record's custom values
+const CUSTOM_VALUES_KEY: &str = "custom_values";
+
+/// A stored record with application-specific custom values
+///
+/// The models flatten custom values into the record itself, where a value
+/// named like a field would clash with it. Files keep them under
+/// [`CUSTOM_VALUES_KEY`] instead; values flattened by older versions are
+/// still read back.
+trait StoredRecord: Serialize + DeserializeOwned + Clone {
+    fn custom_values_mut(&mut self) -> &mut HashMap<String, Value>;
+
+    /// Serializes the record with its custom values under their own key
+    fn encode(&self) -> serde_json::Result<Vec<u8>> {
+        let mut record = self.clone();
+        let custom_values = std::mem::take(record.custom_values_mut());
+        let mut value = serde_json::to_value(&record)?;
+        if !custom_values.is_empty() {
+            value[CUSTOM_VALUES_KEY] = serde_json::to_value(custom_values)?;
+        }
+        serde_json::to_vec(&value)
+    }
+
+    /// Parses a record written by [`encode`](Self::encode) or by an older
+    /// version
+    fn decode(data: &[u8]) -> serde_json::Result<Self> {
+        let mut value: Value = serde_json::from_slice(data)?;
+        let custom_values: HashMap<String, Value> = match value
+            .as_object_mut()
+            .and_then(|object| object.remove(CUSTOM_VALUES_KEY))
+        {
+            Some(custom_values) => serde_json::from_value(custom_values)?,
+            None => HashMap::new(),
+        };
+        let mut record: Self = serde_json::from_value(value)?;
+        record.custom_values_mut().extend(custom_values);
+        Ok(record)
+    }
+}
+
+impl StoredRecord for Installation {
+    fn custom_values_mut(&mut self) -> &mut HashMap<String, Value> {
+        &mut self.custom_values
+    }
+}
+
+impl StoredRecord for Bot {
+    fn custom_values_mut(&mut self) -> &mut HashMap<String, Value> {
+        &mut self.custom_values
+    }
+}
+
 /// File-based installation store
 ///
 /// Stores installations in a directory structure:
@@ -23,6 +81,10 @@ use tracing::{debug, warn};
 /// - `installer-{user_id}-latest` - Latest installation for specific user
 /// - `installer-{user_id}-{timestamp}` - Historical installations (if enabled)
 ///
+/// Each file is written to a `{name}.tmp.{pid}.{n}` sibling, synced to disk,
+/// then renamed over the target, so a crash mid-write leaves the previous
+/// contents in place.
+///
 /// # Example
 ///
 /// ```
@@ -81,6 +143,62 @@ impl FileInstallationStore {
         self
     }
 
+    /// Copies all installations and bots from another store into this one
+    ///
+    /// Useful for moving from `CacheInstallationStore` in development to a
+    /// file store in production. Installations are read with
+    /// [`InstallationStore::list_all`] and saved oldest first, so the latest
+    /// installation per user stays the latest. Bots are copied afterwards
+    /// when the source can list them, keeping any bot data saved separately
+    /// from an installation, such as rotated tokens.
+    ///
+    /// Returns the number of installations copied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Unsupported` if the source can't list its
+    /// installations, or any error from writing the files.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use slack_rs::oauth::installation_store::cache::CacheInstallationStore;
+    /// use slack_rs::oauth::installation_store::file::FileInstallationStore;
+    ///
+    /// # async fn example(dev_store: CacheInstallationStore) -> slack_rs::error::Result<()> {
+    /// let store = FileInstallationStore::new("./data/installations");
+    /// let copied = store.migrate_from(&dev_store).await?;
+    /// println!("Migrated {} installations", copied);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn migrate_from(&self, source: &dyn InstallationStore) -> Result<usize> {
+        let installations = source.list_all().await?;
+        let count = installations.len();
+        for installation in installations {
+            self.save(installation).await?;
+        }
+
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = match source.find_all_bots(100, cursor.as_deref()).await {
+                Ok(page) => page,
+                Err(Error::Unsupported(_)) => break,
+                Err(e) => return Err(e),
+            };
+            for bot in page.items {
+                self.save_bot(bot).await?;
+            }
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => break,
+            }
+        }
+
+        debug!("Migrated {} installations", count);
+        Ok(count)
+    }
+
     /// Gets the base directory for this store
     fn get_base_dir(&self) -> PathBuf {
         if let Some(client_id) = &self.client_id {
@@ -104,11 +222,45 @@ impl FileInstallationStore {
         })
     }
 
-    /// Writes data to a file
+    /// Atomically replaces a file's contents
+    ///
+    /// Writes to a temporary file in the same directory, syncs it, then
+    /// renames it over `path`. On Windows the rename uses `MoveFileExW` with
+    /// `MOVEFILE_REPLACE_EXISTING`.
     async fn write_file(&self, path: &Path, data: &[u8]) -> Result<()> {
-        fs::write(path, data)
-            .await
-            .map_err(|e| Error::storage_error(format!("Failed to write file {:?}: {}", path, e)))
+        static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| Error::storage_error(format!("Invalid file path {:?}", path)))?;
+        let temp_path = path.with_file_name(format!(
+            "{}.tmp.{}.{}",
+            file_name,
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+
+        let result = async {
+            let mut file = fs::File::create(&temp_path).await?;
+            file.write_all(data).await?;
+            file.sync_all().await?;
+            drop(file);
+            fs::rename(&temp_path, path).await
+        }
+        .await;
+
+        result.map_err(|e| {
+            if let Err(cleanup) = std::fs::remove_file(&temp_path) {
+                if cleanup.kind() != std::io::ErrorKind::NotFound {
+                    warn!(
+                        "Failed to remove temporary file {:?}: {}",
+                        temp_path, cleanup
+                    );
+                }
+            }
+            Error::storage_error(format!("Failed to write file {:?}: {}", path, e))
+        })
     }
 
     /// Reads data from a file
@@ -122,6 +274,66 @@ impl FileInstallationStore {
         })
     }
 
+    /// Reads every file accepted by `matches` in each team directory
+    ///
+    /// Returns the parsed items with their paths, ordered by `installed_at`
+    /// and then path.
+    async fn read_all<T, F>(&self, matches: F, installed_at: fn(&T) -> f64) -> Result<Vec<T>>
+    where
+        T: StoredRecord,
+        F: Fn(&str) -> bool,
+    {
+        let base_dir = self.get_base_dir();
+        if !base_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        let mut team_dirs = fs::read_dir(&base_dir).await.map_err(|e| {
+            Error::storage_error(format!("Failed to read directory {:?}: {}", base_dir, e))
+        })?;
+        while let Some(team_dir) = team_dirs
+            .next_entry()
+            .await
+            .map_err(|e| Error::storage_error(format!("Failed to read directory entry: {}", e)))?
+        {
+            let team_dir = team_dir.path();
+            if !team_dir.is_dir() {
+                continue;
+            }
+
+            let mut entries = fs::read_dir(&team_dir).await.map_err(|e| {
+                Error::storage_error(format!("Failed to read directory {:?}: {}", team_dir, e))
+            })?;
+            while let Some(entry) = entries.next_entry().await.map_err(|e| {
+                Error::storage_error(format!("Failed to read directory entry: {}", e))
+            })? {
+                let path = entry.path();
+                if path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(&matches)
+                {
+                    let data = self.read_file(&path).await?;
+                    let item = T::decode(&data).map_err(|e| {
+                        Error::deserialization_error(format!(
+                            "Failed to deserialize {:?}: {}",
+                            path, e
+                        ))
+                    })?;
+                    files.push((path, item));
+                }
+            }
+        }
+
+        files.sort_by(|(a_path, a), (b_path, b)| {
+            installed_at(a)
+                .total_cmp(&installed_at(b))
+                .then_with(|| a_path.cmp(b_path))
+        });
+        Ok(files.into_iter().map(|(_, item)| item).collect())
+    }
+
     /// Deletes files matching a pattern
     async fn delete_files_by_pattern(&self, dir: &Path, pattern: &str) -> Result<()> {
         if !dir.exists() {
@@ -169,7 +381,7 @@ impl InstallationStore for FileInstallationStore {
             self.save_bot(bot).await?;
         }
 
-        let data = serde_json::to_vec(&installation).map_err(|e| {
+        let data = installation.encode().map_err(|e| {
             Error::serialization_error(format!("Failed to serialize installation: {}", e))
         })?;
 
@@ -216,7 +428,8 @@ impl InstallationStore for FileInstallationStore {
         let team_dir = self.get_team_dir(bot.enterprise_id.as_deref(), actual_team_id);
         self.ensure_dir(&team_dir).await?;
 
-        let data = serde_json::to_vec(&bot)
+        let data = bot
+            .encode()
             .map_err(|e| Error::serialization_error(format!("Failed to serialize bot: {}", e)))?;
 
         if self.historical_data_enabled {
@@ -260,7 +473,7 @@ impl InstallationStore for FileInstallationStore {
         }
 
         let data = self.read_file(&bot_path).await?;
-        let bot: Bot = serde_json::from_slice(&data).map_err(|e| {
+        let bot = Bot::decode(&data).map_err(|e| {
             Error::deserialization_error(format!("Failed to deserialize bot: {}", e))
         })?;
 
@@ -294,7 +507,7 @@ impl InstallationStore for FileInstallationStore {
         }
 
         let data = self.read_file(&installation_path).await?;
-        let mut installation: Installation = serde_json::from_slice(&data).map_err(|e| {
+        let mut installation = Installation::decode(&data).map_err(|e| {
             Error::deserialization_error(format!("Failed to deserialize installation: {}", e))
         })?;
 
@@ -358,6 +571,33 @@ impl InstallationStore for FileInstallationStore {
 
         Ok(())
     }
+
+    async fn find_all_installations(
+        &self,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<Page<Installation>> {
+        // `installer-{user_id}-latest` holds the latest installation per user;
+        // `installer-latest` repeats one of them and is skipped.
+        let installations = self
+            .read_all(
+                |name| {
+                    name != "installer-latest"
+                        && name.starts_with("installer-")
+                        && name.ends_with("-latest")
+                },
+                |installation: &Installation| installation.installed_at,
+            )
+            .await?;
+        Page::from_sorted(installations, limit, cursor)
+    }
+
+    async fn find_all_bots(&self, limit: usize, cursor: Option<&str>) -> Result<Page<Bot>> {
+        let bots = self
+            .read_all(|name| name == "bot-latest", |bot: &Bot| bot.installed_at)
+            .await?;
+        Page::from_sorted(bots, limit, cursor)
+    }
 }
 
 #[cfg(test)]
@@ -396,6 +636,42 @@ mod tests {
         assert_eq!(found.bot_token, Some("xoxb-token".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_interrupted_write_keeps_previous_value() {
+        let (store, temp_dir) = create_test_store().await;
+
+        let installation = Installation::new("U12345")
+            .team_id("T12345")
+            .bot_token("xoxb-old");
+        store.save(installation).await.unwrap();
+
+        let team_dir = temp_dir.path().join("none-T12345");
+        let mut names = Vec::new();
+        let mut entries = fs::read_dir(&team_dir).await.unwrap();
+        while let Some(entry) = entries.next_entry().await.unwrap() {
+            names.push(entry.file_name().into_string().unwrap());
+        }
+        assert!(names.iter().all(|name| !name.contains(".tmp.")));
+
+        // A crash mid-write leaves a truncated temporary file behind
+        fs::write(
+            team_dir.join("installer-U12345-latest.tmp.999.0"),
+            br#"{"user_id": "U12345", "bot_tok"#,
+        )
+        .await
+        .unwrap();
+
+        let found = store
+            .find_installation(None, Some("T12345"), Some("U12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.bot_token.as_deref(), Some("xoxb-old"));
+
+        let page = store.find_all_installations(10, None).await.unwrap();
+        assert_eq!(page.items.len(), 1);
+    }
+
     #[tokio::test]
     async fn test_save_and_find_bot() {
         let (store, _temp_dir) = create_test_store().await;
@@ -554,4 +830,215 @@ mod tests {
 
         assert_eq!(file_count, 2); // bot-latest and installer-U12345-latest
     }
+
+    #[tokio::test]
+    async fn test_find_all_installations_paginates() {
+        let (store, _temp_dir) = create_test_store().await;
+
+        // Save newest first so the listing has to sort by installed_at.
+        for i in (0..25).rev() {
+            let mut installation = Installation::new(format!("U{:02}", i))
+                .team_id(format!("T{:02}", i))
+                .bot_token("xoxb-token")
+                .bot_id("B12345")
+                .bot_user_id("U67890");
+            installation.installed_at = 1_700_000_000.0 + i as f64;
+            store.save(installation).await.unwrap();
+        }
+
+        let mut user_ids = Vec::new();
+        let mut page_sizes = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = store
+                .find_all_installations(10, cursor.as_deref())
+                .await
+                .unwrap();
+            page_sizes.push(page.items.len());
+            user_ids.extend(page.items.into_iter().map(|i| i.user_id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(page_sizes, vec![10, 10, 5]);
+        let expected: Vec<String> = (0..25).map(|i| format!("U{:02}", i)).collect();
+        assert_eq!(user_ids, expected);
+
+        let bots = store.find_all_bots(10, None).await.unwrap();
+        assert_eq!(bots.items.len(), 10);
+        assert_eq!(bots.items[0].team_id.as_deref(), Some("T00"));
+        assert!(bots.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_find_all_installations_empty_store() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = FileInstallationStore::new(temp_dir.path().join("missing"));
+
+        let page = store.find_all_installations(10, None).await.unwrap();
+        assert!(page.items.is_empty());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_migrate_from_cache_store() {
+        use crate::oauth::installation_store::cache::CacheInstallationStore;
+
+        let source = CacheInstallationStore::new();
+        for i in 0..3 {
+            let mut installation = Installation::new(format!("U{}", i))
+                .team_id("T1")
+                .bot_token(format!("xoxb-{}", i))
+                .bot_id("B1")
+                .bot_user_id("U99");
+            installation.installed_at = 1_700_000_000.0 + i as f64;
+            source.save(installation).await.unwrap();
+        }
+        let mut rotated = Bot::new("xoxb-rotated", "B2", "U98");
+        rotated.team_id = Some("T2".to_string());
+        source.save_bot(rotated).await.unwrap();
+
+        let (store, _temp_dir) = create_test_store().await;
+        assert_eq!(store.migrate_from(&source).await.unwrap(), 3);
+
+        assert_eq!(store.list_all().await.unwrap().len(), 3);
+        let latest = store
+            .find_installation(None, Some("T1"), None, false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(latest.user_id, "U2");
+        let bot = store.find_bot(None, Some("T2"), false).await.unwrap();
+        assert_eq!(bot.unwrap().bot_token, "xoxb-rotated");
+    }
+
+    #[tokio::test]
+    async fn test_migrate_from_unlistable_store() {
+        struct Opaque;
+
+        #[async_trait]
+        impl InstallationStore for Opaque {
+            async fn save(&self, _: Installation) -> Result<()> {
+                Ok(())
+            }
+            async fn save_bot(&self, _: Bot) -> Result<()> {
+                Ok(())
+            }
+            async fn find_bot(
+                &self,
+                _: Option<&str>,
+                _: Option<&str>,
+                _: bool,
+            ) -> Result<Option<Bot>> {
+                Ok(None)
+            }
+            async fn find_installation(
+                &self,
+                _: Option<&str>,
+                _: Option<&str>,
+                _: Option<&str>,
+                _: bool,
+            ) -> Result<Option<Installation>> {
+                Ok(None)
+            }
+            async fn delete_bot(&self, _: Option<&str>, _: Option<&str>) -> Result<()> {
+                Ok(())
+            }
+            async fn delete_installation(
+                &self,
+                _: Option<&str>,
+                _: Option<&str>,
+                _: Option<&str>,
+            ) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let (store, _temp_dir) = create_test_store().await;
+        assert!(matches!(
+            store.migrate_from(&Opaque).await,
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_custom_values_round_trip() {
+        let (store, temp_dir) = create_test_store().await;
+
+        let mut installation = Installation::new("U12345")
+            .app_id("A12345")
+            .team_id("T12345")
+            .bot_token("xoxb-token")
+            .bot_id("B12345")
+            .bot_user_id("U67890");
+        installation.set_custom_value(
+            "settings",
+            serde_json::json!({"locale": "de-DE", "channels": ["C1", "C2"], "limits": {"daily": 50}}),
+        );
+        // Named like a field, so it only survives under its own key
+        installation.set_custom_value("team_id", serde_json::json!("custom"));
+        store.save(installation.clone()).await.unwrap();
+
+        let data = fs::read(temp_dir.path().join("none-T12345/installer-U12345-latest"))
+            .await
+            .unwrap();
+        let stored: Value = serde_json::from_slice(&data).unwrap();
+        assert_eq!(stored["team_id"], "T12345");
+        assert_eq!(stored["custom_values"]["settings"]["limits"]["daily"], 50);
+
+        let found = store
+            .find_installation(None, Some("T12345"), Some("U12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.team_id.as_deref(), Some("T12345"));
+        assert_eq!(found.custom_values, installation.custom_values);
+
+        let bot = store
+            .find_bot(None, Some("T12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bot.custom_values, installation.custom_values);
+
+        let bots = store.find_all_bots(10, None).await.unwrap();
+        assert_eq!(bots.items[0].custom_values, installation.custom_values);
+    }
+
+    #[tokio::test]
+    async fn test_reads_flattened_custom_values() {
+        let (store, temp_dir) = create_test_store().await;
+
+        // Written by versions that flattened custom values into the record
+        let team_dir = temp_dir.path().join("none-T1");
+        fs::create_dir_all(&team_dir).await.unwrap();
+        fs::write(
+            team_dir.join("bot-latest"),
+            serde_json::to_vec(&serde_json::json!({
+                "team_id": "T1",
+                "bot_token": "xoxb-old",
+                "bot_id": "B1",
+                "bot_user_id": "UB1",
+                "bot_scopes": [],
+                "is_enterprise_install": false,
+                "installed_at": 1700000000.0,
+                "plan": {"tier": "pro"}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+        let bot = store
+            .find_bot(None, Some("T1"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            bot.get_custom_value("plan"),
+            Some(&serde_json::json!({"tier": "pro"}))
+        );
+    }
 }
@@ -3,7 +3,7 @@
 //! This module provides traits and implementations for storing and retrieving
 //! Slack app installation data.
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::oauth::models::{Bot, Installation};
 use async_trait::async_trait;
 
@@ -13,6 +13,79 @@ pub mod file;
 #[cfg(feature = "sqlite")]
 pub mod sqlite;
 
+/// Page size used when [`InstallationStore::list_all`] walks a store.
+const LIST_ALL_PAGE_SIZE: usize = 100;
+
+/// A page of results from a listing method such as
+/// [`InstallationStore::find_all_installations`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// Items on this page.
+    pub items: Vec<T>,
+
+    /// Cursor for the next page, or None on the last page.
+    ///
+    /// The cursor is opaque; pass it back unchanged.
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Returns true if there are more pages after this one.
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+
+    /// Slices a page out of `items`, which must already be in a stable order.
+    ///
+    /// The cursor encodes the offset of the page's first item.
+    pub(crate) fn from_sorted(
+        mut items: Vec<T>,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<Self> {
+        let offset = parse_cursor(limit, cursor)?;
+        let total = items.len();
+        let end = offset.saturating_add(limit).min(total);
+        let items = if offset < total {
+            items.truncate(end);
+            items.split_off(offset)
+        } else {
+            Vec::new()
+        };
+        Ok(Self {
+            items,
+            next_cursor: (end < total).then(|| encode_cursor(end)),
+        })
+    }
+}
+
+/// Prefix of the offset encoded in a page cursor.
+const CURSOR_PREFIX: &str = "offset:";
+
+/// Encodes the offset of a page's first item as an opaque cursor.
+pub(crate) fn encode_cursor(offset: usize) -> String {
+    hex::encode(format!("{}{}", CURSOR_PREFIX, offset))
+}
+
+/// Validates `limit` and decodes `cursor` into an offset.
+///
+/// Only cursors made by [`encode_cursor`] are accepted.
+pub(crate) fn parse_cursor(limit: usize, cursor: Option<&str>) -> Result<usize> {
+    if limit == 0 {
+        return Err(Error::Validation(
+            "limit must be greater than 0".to_string(),
+        ));
+    }
+    let Some(cursor) = cursor else {
+        return Ok(0);
+    };
+    hex::decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .and_then(|decoded| decoded.strip_prefix(CURSOR_PREFIX)?.parse().ok())
+        .ok_or_else(|| Error::Validation(format!("invalid cursor: {}", cursor)))
+}
+
 /// Trait for storing and retrieving Slack app installations
 ///
 /// Implementations of this trait handle persistence of OAuth installation data,
@@ -30,6 +103,9 @@ pub mod sqlite;
 /// - `save_bot` - Store bot data
 /// - `find_bot` - Retrieve bot data
 /// - `delete_bot` - Remove bot data
+///
+/// Stores that can enumerate their contents may also implement
+/// `find_all_installations` and `find_all_bots`.
 #[async_trait]
 pub trait InstallationStore: Send + Sync {
     /// Saves an installation
@@ -107,4 +183,64 @@ pub trait InstallationStore: Send + Sync {
             .await?;
         Ok(())
     }
+
+    /// Lists the latest installation per user, oldest first
+    ///
+    /// Results are ordered by `installed_at`. Pass the returned
+    /// `next_cursor` to fetch the following page.
+    ///
+    /// The default implementation returns `SlackError::Unsupported`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of installations per page (must be > 0)
+    /// * `cursor` - Cursor from a previous page (None for the first page)
+    async fn find_all_installations(
+        &self,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<Page<Installation>> {
+        let _ = (limit, cursor);
+        Err(Error::Unsupported(
+            "find_all_installations is not supported by this store".to_string(),
+        ))
+    }
+
+    /// Lists the latest bot per workspace/org, oldest first
+    ///
+    /// Results are ordered by `installed_at`. Pass the returned
+    /// `next_cursor` to fetch the following page.
+    ///
+    /// The default implementation returns `SlackError::Unsupported`.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of bots per page (must be > 0)
+    /// * `cursor` - Cursor from a previous page (None for the first page)
+    async fn find_all_bots(&self, limit: usize, cursor: Option<&str>) -> Result<Page<Bot>> {
+        let _ = (limit, cursor);
+        Err(Error::Unsupported(
+            "find_all_bots is not supported by this store".to_string(),
+        ))
+    }
+
+    /// Lists the latest installation per user, oldest first, in one call
+    ///
+    /// The default implementation pages through `find_all_installations`,
+    /// so it returns `SlackError::Unsupported` for stores that can't list
+    /// their contents.
+    async fn list_all(&self) -> Result<Vec<Installation>> {
+        let mut installations = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = self
+                .find_all_installations(LIST_ALL_PAGE_SIZE, cursor.as_deref())
+                .await?;
+            installations.extend(page.items);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => return Ok(installations),
+            }
+        }
+    }
 }
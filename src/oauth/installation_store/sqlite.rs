@@ -4,10 +4,12 @@
 //! Stores installations in a SQLite database with proper indexing.
 
 use crate::error::{Error, Result};
-use crate::oauth::installation_store::InstallationStore;
+use crate::oauth::installation_store::{encode_cursor, parse_cursor, InstallationStore, Page};
 use crate::oauth::models::{Bot, Installation};
 use async_trait::async_trait;
 use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 use tracing::debug;
@@ -91,6 +93,10 @@ impl SqliteInstallationStore {
                 .unwrap_or(false);
 
             if table_exists {
+                // Databases created before custom values were stored lack the column
+                for table in ["slack_installations", "slack_bots"] {
+                    add_custom_values_column(&conn, table)?;
+                }
                 return Ok::<_, Error>(());
             }
 
@@ -122,7 +128,8 @@ impl SqliteInstallationStore {
                     incoming_webhook_configuration_url TEXT,
                     is_enterprise_install INTEGER NOT NULL DEFAULT 0,
                     token_type TEXT,
-                    installed_at REAL NOT NULL
+                    installed_at REAL NOT NULL,
+                    custom_values TEXT
                 )
                 "#,
                 [],
@@ -160,7 +167,8 @@ impl SqliteInstallationStore {
                     bot_refresh_token TEXT,
                     bot_token_expires_at INTEGER,
                     is_enterprise_install INTEGER NOT NULL DEFAULT 0,
-                    installed_at REAL NOT NULL
+                    installed_at REAL NOT NULL,
+                    custom_values TEXT
                 )
                 "#,
                 [],
@@ -215,6 +223,8 @@ impl InstallationStore for SqliteInstallationStore {
                 .map(|s| s.join(","))
                 .unwrap_or_default();
 
+            let custom_values = encode_custom_values(&installation.custom_values)?;
+
             let team_id_str = installation.team_id.as_deref().unwrap_or("");
             let enterprise_id_str = installation.enterprise_id.as_deref().unwrap_or("");
 
@@ -227,9 +237,9 @@ impl InstallationStore for SqliteInstallationStore {
                     user_refresh_token, user_token_expires_at, incoming_webhook_url,
                     incoming_webhook_channel, incoming_webhook_channel_id,
                     incoming_webhook_configuration_url, is_enterprise_install, token_type,
-                    installed_at
+                    installed_at, custom_values
                 )
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26)
                 "#,
                 params![
                     client_id,
@@ -257,6 +267,7 @@ impl InstallationStore for SqliteInstallationStore {
                     if installation.is_enterprise_install { 1 } else { 0 },
                     installation.token_type,
                     installation.installed_at,
+                    custom_values,
                 ],
             )
             .map_err(|e| Error::storage_error(format!("Failed to insert installation: {}", e)))?;
@@ -283,6 +294,7 @@ impl InstallationStore for SqliteInstallationStore {
             let conn = conn.lock().unwrap();
 
             let bot_scopes = bot.bot_scopes.join(",");
+            let custom_values = encode_custom_values(&bot.custom_values)?;
 
             let team_id_str = bot.team_id.as_deref().unwrap_or("");
             let enterprise_id_str = bot.enterprise_id.as_deref().unwrap_or("");
@@ -292,9 +304,9 @@ impl InstallationStore for SqliteInstallationStore {
                 INSERT INTO slack_bots (
                     client_id, app_id, enterprise_id, enterprise_name, team_id, team_name,
                     bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token,
-                    bot_token_expires_at, is_enterprise_install, installed_at
+                    bot_token_expires_at, is_enterprise_install, installed_at, custom_values
                 )
-                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
                 "#,
                 params![
                     client_id,
@@ -311,6 +323,7 @@ impl InstallationStore for SqliteInstallationStore {
                     bot.bot_token_expires_at,
                     if bot.is_enterprise_install { 1 } else { 0 },
                     bot.installed_at,
+                    custom_values,
                 ],
             )
             .map_err(|e| Error::storage_error(format!("Failed to insert bot: {}", e)))?;
@@ -348,49 +361,14 @@ impl InstallationStore for SqliteInstallationStore {
                     r#"
                     SELECT app_id, enterprise_id, enterprise_name, team_id, team_name,
                            bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token,
-                           bot_token_expires_at, is_enterprise_install, installed_at
+                           bot_token_expires_at, is_enterprise_install, installed_at, custom_values
                     FROM slack_bots
                     WHERE client_id = ?1 AND enterprise_id = ?2 AND team_id = ?3
                     ORDER BY installed_at DESC
                     LIMIT 1
                     "#,
                     params![client_id, enterprise_id, team_id],
-                    |row| {
-                        let bot_scopes: String = row.get(8)?;
-                        let bot_scopes = if bot_scopes.is_empty() {
-                            Vec::new()
-                        } else {
-                            bot_scopes.split(',').map(|s| s.to_string()).collect()
-                        };
-
-                        let enterprise_id: String = row.get(1)?;
-                        let team_id: String = row.get(3)?;
-
-                        Ok(Bot {
-                            app_id: row.get(0)?,
-                            enterprise_id: if enterprise_id.is_empty() {
-                                None
-                            } else {
-                                Some(enterprise_id)
-                            },
-                            enterprise_name: row.get(2)?,
-                            team_id: if team_id.is_empty() {
-                                None
-                            } else {
-                                Some(team_id)
-                            },
-                            team_name: row.get(4)?,
-                            bot_token: row.get(5)?,
-                            bot_id: row.get(6)?,
-                            bot_user_id: row.get(7)?,
-                            bot_scopes,
-                            bot_refresh_token: row.get(9)?,
-                            bot_token_expires_at: row.get(10)?,
-                            is_enterprise_install: row.get::<_, i32>(11)? != 0,
-                            installed_at: row.get(12)?,
-                            custom_values: Default::default(),
-                        })
-                    },
+                    parse_bot_row,
                 )
                 .optional()
                 .map_err(|e| Error::storage_error(format!("Failed to query bot: {}", e)))?;
@@ -435,7 +413,8 @@ impl InstallationStore for SqliteInstallationStore {
                            bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token, bot_token_expires_at,
                            user_id, user_token, user_scopes, user_refresh_token, user_token_expires_at,
                            incoming_webhook_url, incoming_webhook_channel, incoming_webhook_channel_id,
-                           incoming_webhook_configuration_url, is_enterprise_install, token_type, installed_at
+                           incoming_webhook_configuration_url, is_enterprise_install, token_type, installed_at,
+                           custom_values
                     FROM slack_installations
                     WHERE client_id = ?1 AND enterprise_id = ?2 AND team_id = ?3 AND user_id = ?4
                     ORDER BY installed_at DESC
@@ -453,7 +432,8 @@ impl InstallationStore for SqliteInstallationStore {
                            bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token, bot_token_expires_at,
                            user_id, user_token, user_scopes, user_refresh_token, user_token_expires_at,
                            incoming_webhook_url, incoming_webhook_channel, incoming_webhook_channel_id,
-                           incoming_webhook_configuration_url, is_enterprise_install, token_type, installed_at
+                           incoming_webhook_configuration_url, is_enterprise_install, token_type, installed_at,
+                           custom_values
                     FROM slack_installations
                     WHERE client_id = ?1 AND enterprise_id = ?2 AND team_id = ?3
                     ORDER BY installed_at DESC
@@ -597,6 +577,196 @@ impl InstallationStore for SqliteInstallationStore {
 
         Ok(())
     }
+
+    async fn find_all_installations(
+        &self,
+        limit: usize,
+        cursor: Option<&str>,
+    ) -> Result<Page<Installation>> {
+        let offset = parse_cursor(limit, cursor)?;
+        let conn = self.conn.clone();
+        let client_id = self.client_id.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            // Rows are appended on every save; list only the latest per user.
+            // One extra row is fetched to tell whether another page follows.
+            let mut stmt = conn
+                .prepare(
+                    r#"
+                    SELECT app_id, enterprise_id, enterprise_name, enterprise_url, team_id, team_name,
+                           bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token, bot_token_expires_at,
+                           user_id, user_token, user_scopes, user_refresh_token, user_token_expires_at,
+                           incoming_webhook_url, incoming_webhook_channel, incoming_webhook_channel_id,
+                           incoming_webhook_configuration_url, is_enterprise_install, token_type, installed_at,
+                           custom_values
+                    FROM slack_installations
+                    WHERE id IN (
+                        SELECT MAX(id) FROM slack_installations
+                        WHERE client_id = ?1
+                        GROUP BY enterprise_id, team_id, user_id
+                    )
+                    ORDER BY installed_at ASC, id ASC
+                    LIMIT ?2 OFFSET ?3
+                    "#,
+                )
+                .map_err(|e| Error::storage_error(format!("Failed to prepare query: {}", e)))?;
+
+            let mut items = stmt
+                .query_map(
+                    params![client_id, (limit + 1) as i64, offset as i64],
+                    parse_installation_row,
+                )
+                .and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+                .map_err(|e| Error::storage_error(format!("Failed to query installations: {}", e)))?;
+
+            let next_cursor = if items.len() > limit {
+                items.truncate(limit);
+                Some(encode_cursor(offset + limit))
+            } else {
+                None
+            };
+
+            Ok::<_, Error>(Page { items, next_cursor })
+        })
+        .await
+        .map_err(|e| Error::storage_error(format!("Task join error: {}", e)))?
+    }
+
+    async fn find_all_bots(&self, limit: usize, cursor: Option<&str>) -> Result<Page<Bot>> {
+        let offset = parse_cursor(limit, cursor)?;
+        let conn = self.conn.clone();
+        let client_id = self.client_id.clone();
+
+        tokio::task::spawn_blocking(move || {
+            let conn = conn.lock().unwrap();
+
+            let mut stmt = conn
+                .prepare(
+                    r#"
+                    SELECT app_id, enterprise_id, enterprise_name, team_id, team_name,
+                           bot_token, bot_id, bot_user_id, bot_scopes, bot_refresh_token,
+                           bot_token_expires_at, is_enterprise_install, installed_at, custom_values
+                    FROM slack_bots
+                    WHERE id IN (
+                        SELECT MAX(id) FROM slack_bots
+                        WHERE client_id = ?1
+                        GROUP BY enterprise_id, team_id
+                    )
+                    ORDER BY installed_at ASC, id ASC
+                    LIMIT ?2 OFFSET ?3
+                    "#,
+                )
+                .map_err(|e| Error::storage_error(format!("Failed to prepare query: {}", e)))?;
+
+            let mut items = stmt
+                .query_map(
+                    params![client_id, (limit + 1) as i64, offset as i64],
+                    parse_bot_row,
+                )
+                .and_then(|rows| rows.collect::<rusqlite::Result<Vec<_>>>())
+                .map_err(|e| Error::storage_error(format!("Failed to query bots: {}", e)))?;
+
+            let next_cursor = if items.len() > limit {
+                items.truncate(limit);
+                Some(encode_cursor(offset + limit))
+            } else {
+                None
+            };
+
+            Ok::<_, Error>(Page { items, next_cursor })
+        })
+        .await
+        .map_err(|e| Error::storage_error(format!("Task join error: {}", e)))?
+    }
+}
+
+/// Adds the `custom_values` column to `table` if it is missing.
+fn add_custom_values_column(conn: &Connection, table: &str) -> Result<()> {
+    let has_column: bool = conn
+        .query_row(
+            &format!(
+                "SELECT COUNT(*) FROM pragma_table_info('{}') WHERE name = 'custom_values'",
+                table
+            ),
+            [],
+            |row| row.get(0),
+        )
+        .map(|count: i64| count > 0)
+        .map_err(|e| Error::storage_error(format!("Failed to inspect {}: {}", table, e)))?;
+
+    if !has_column {
+        conn.execute(
+            &format!("ALTER TABLE {} ADD COLUMN custom_values TEXT", table),
+            [],
+        )
+        .map_err(|e| {
+            Error::storage_error(format!("Failed to add custom_values to {}: {}", table, e))
+        })?;
+        debug!("Added custom_values column to {}", table);
+    }
+    Ok(())
+}
+
+/// Serializes custom values to JSON text, or NULL when there are none.
+fn encode_custom_values(custom_values: &HashMap<String, Value>) -> Result<Option<String>> {
+    if custom_values.is_empty() {
+        return Ok(None);
+    }
+    serde_json::to_string(custom_values).map(Some).map_err(|e| {
+        Error::serialization_error(format!("Failed to serialize custom values: {}", e))
+    })
+}
+
+/// Reads the JSON text of a `custom_values` column.
+fn parse_custom_values(
+    row: &rusqlite::Row,
+    idx: usize,
+) -> rusqlite::Result<HashMap<String, Value>> {
+    match row.get::<_, Option<String>>(idx)? {
+        Some(json) => serde_json::from_str(&json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(idx, rusqlite::types::Type::Text, Box::new(e))
+        }),
+        None => Ok(HashMap::new()),
+    }
+}
+
+fn parse_bot_row(row: &rusqlite::Row) -> rusqlite::Result<Bot> {
+    let bot_scopes: String = row.get(8)?;
+    let bot_scopes = if bot_scopes.is_empty() {
+        Vec::new()
+    } else {
+        bot_scopes.split(',').map(|s| s.to_string()).collect()
+    };
+
+    let enterprise_id: String = row.get(1)?;
+    let team_id: String = row.get(3)?;
+
+    Ok(Bot {
+        app_id: row.get(0)?,
+        enterprise_id: if enterprise_id.is_empty() {
+            None
+        } else {
+            Some(enterprise_id)
+        },
+        enterprise_name: row.get(2)?,
+        team_id: if team_id.is_empty() {
+            None
+        } else {
+            Some(team_id)
+        },
+        team_name: row.get(4)?,
+        bot_token: row.get(5)?,
+        bot_id: row.get(6)?,
+        bot_user_id: row.get(7)?,
+        bot_scopes,
+        bot_refresh_token: row.get(9)?,
+        bot_token_expires_at: row.get(10)?,
+        is_enterprise_install: row.get::<_, i32>(11)? != 0,
+        installed_at: row.get(12)?,
+        custom_values: parse_custom_values(row, 13)?,
+    })
 }
 
 fn parse_installation_row(row: &rusqlite::Row) -> rusqlite::Result<Installation> {
@@ -654,7 +824,7 @@ fn parse_installation_row(row: &rusqlite::Row) -> rusqlite::Result<Installation>
         is_enterprise_install: row.get::<_, i32>(21)? != 0,
         token_type: row.get(22)?,
         installed_at: row.get(23)?,
-        custom_values: Default::default(),
+        custom_values: parse_custom_values(row, 24)?,
     })
 }
 
@@ -757,4 +927,190 @@ mod tests {
         let found = store.find_bot(None, Some("T12345"), false).await.unwrap();
         assert!(found.is_none());
     }
+
+    #[tokio::test]
+    async fn test_find_all_installations_paginates() {
+        let store = create_test_store().await;
+
+        // Save newest first so the listing has to sort by installed_at.
+        for i in (0..25).rev() {
+            let mut installation = Installation::new(format!("U{:02}", i))
+                .app_id("A12345")
+                .team_id(format!("T{:02}", i))
+                .bot_token("xoxb-token")
+                .bot_id("B12345")
+                .bot_user_id("U67890");
+            installation.installed_at = 1_700_000_000.0 + i as f64;
+            store.save(installation).await.unwrap();
+        }
+
+        // A re-install replaces the user's entry rather than adding one.
+        let mut reinstall = Installation::new("U00")
+            .app_id("A12345")
+            .team_id("T00")
+            .bot_token("xoxb-token-2")
+            .bot_id("B12345")
+            .bot_user_id("U67890");
+        reinstall.installed_at = 1_700_000_100.0;
+        store.save(reinstall).await.unwrap();
+
+        let mut user_ids = Vec::new();
+        let mut page_sizes = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let page = store
+                .find_all_installations(10, cursor.as_deref())
+                .await
+                .unwrap();
+            page_sizes.push(page.items.len());
+            user_ids.extend(page.items.into_iter().map(|i| i.user_id));
+            cursor = page.next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(page_sizes, vec![10, 10, 5]);
+        let mut expected: Vec<String> = (1..25).map(|i| format!("U{:02}", i)).collect();
+        expected.push("U00".to_string());
+        assert_eq!(user_ids, expected);
+
+        let bots = store
+            .find_all_bots(10, Some(&encode_cursor(20)))
+            .await
+            .unwrap();
+        assert_eq!(bots.items.len(), 5);
+        assert_eq!(bots.items[4].bot_token, "xoxb-token-2");
+        assert!(!bots.has_more());
+    }
+
+    fn nested_custom_values() -> serde_json::Value {
+        serde_json::json!({
+            "settings": {"locale": "de-DE", "channels": ["C1", "C2"], "limits": {"daily": 50}},
+            "plan": "pro"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_custom_values_round_trip() {
+        let store = create_test_store().await;
+
+        let mut installation = Installation::new("U12345")
+            .app_id("A12345")
+            .team_id("T12345")
+            .bot_token("xoxb-token")
+            .bot_id("B12345")
+            .bot_user_id("U67890");
+        for (name, value) in nested_custom_values().as_object().unwrap() {
+            installation.set_custom_value(name.clone(), value.clone());
+        }
+        store.save(installation.clone()).await.unwrap();
+
+        let found = store
+            .find_installation(None, Some("T12345"), Some("U12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.custom_values, installation.custom_values);
+
+        let bot = store
+            .find_bot(None, Some("T12345"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bot.custom_values, installation.custom_values);
+
+        let listed = store.find_all_installations(10, None).await.unwrap();
+        assert_eq!(listed.items[0].custom_values, installation.custom_values);
+    }
+
+    #[tokio::test]
+    async fn test_adds_custom_values_column_to_existing_database() {
+        let temp_dir = TempDir::new().unwrap();
+        let db_path = temp_dir.path().join("legacy.db");
+        {
+            let conn = Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                r#"
+                CREATE TABLE slack_installations (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    client_id TEXT NOT NULL,
+                    app_id TEXT NOT NULL,
+                    enterprise_id TEXT NOT NULL DEFAULT '',
+                    enterprise_name TEXT,
+                    enterprise_url TEXT,
+                    team_id TEXT NOT NULL DEFAULT '',
+                    team_name TEXT,
+                    bot_token TEXT,
+                    bot_id TEXT,
+                    bot_user_id TEXT,
+                    bot_scopes TEXT,
+                    bot_refresh_token TEXT,
+                    bot_token_expires_at INTEGER,
+                    user_id TEXT NOT NULL,
+                    user_token TEXT,
+                    user_scopes TEXT,
+                    user_refresh_token TEXT,
+                    user_token_expires_at INTEGER,
+                    incoming_webhook_url TEXT,
+                    incoming_webhook_channel TEXT,
+                    incoming_webhook_channel_id TEXT,
+                    incoming_webhook_configuration_url TEXT,
+                    is_enterprise_install INTEGER NOT NULL DEFAULT 0,
+                    token_type TEXT,
+                    installed_at REAL NOT NULL
+                );
+                CREATE TABLE slack_bots (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    client_id TEXT NOT NULL,
+                    app_id TEXT NOT NULL,
+                    enterprise_id TEXT NOT NULL DEFAULT '',
+                    enterprise_name TEXT,
+                    team_id TEXT NOT NULL DEFAULT '',
+                    team_name TEXT,
+                    bot_token TEXT NOT NULL,
+                    bot_id TEXT NOT NULL,
+                    bot_user_id TEXT NOT NULL,
+                    bot_scopes TEXT,
+                    bot_refresh_token TEXT,
+                    bot_token_expires_at INTEGER,
+                    is_enterprise_install INTEGER NOT NULL DEFAULT 0,
+                    installed_at REAL NOT NULL
+                );
+                INSERT INTO slack_bots (client_id, app_id, team_id, bot_token, bot_id, bot_user_id, bot_scopes, installed_at)
+                VALUES ('client_123', 'A1', 'T1', 'xoxb-old', 'B1', 'UB1', 'chat:write', 1700000000.0);
+                "#,
+            )
+            .unwrap();
+        }
+
+        let store = SqliteInstallationStore::new(&db_path, "client_123")
+            .await
+            .unwrap();
+
+        let old = store
+            .find_bot(None, Some("T1"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(old.bot_token, "xoxb-old");
+        assert!(old.custom_values.is_empty());
+
+        let mut bot = Bot::new("xoxb-new", "B1", "UB1");
+        bot.app_id = Some("A1".to_string());
+        bot.team_id = Some("T1".to_string());
+        bot.set_custom_value("settings", nested_custom_values());
+        store.save_bot(bot.clone()).await.unwrap();
+
+        // Reopening an already migrated database leaves it alone
+        let store = SqliteInstallationStore::new(&db_path, "client_123")
+            .await
+            .unwrap();
+        let found = store
+            .find_bot(None, Some("T1"), false)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.custom_values, bot.custom_values);
+    }
 }
@@ -96,4 +96,4 @@ pub use authorize_url_generator::{AuthorizeUrlGenerator, OpenIDConnectAuthorizeU
 pub use installation_store::InstallationStore;
 pub use models::{Bot, Installation, OAuthV2Response};
 pub use state_store::OAuthStateStore;
-pub use token_rotation::TokenRotator;
+pub use token_rotation::{TokenRotationHook, TokenRotator};
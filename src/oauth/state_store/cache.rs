@@ -1,9 +1,11 @@
 //! In-memory cache-based OAuth state storage
 
+use crate::clock::{self, Clock};
 use crate::error::Result;
-use crate::oauth::state_store::OAuthStateStore;
+use crate::oauth::state_store::{check_state_data, OAuthStateStore};
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -14,6 +16,7 @@ use uuid::Uuid;
 #[derive(Debug, Clone)]
 struct StateEntry {
     expires_at: DateTime<Utc>,
+    data: Value,
 }
 
 /// In-memory OAuth state store
@@ -48,6 +51,7 @@ struct StateEntry {
 pub struct CacheOAuthStateStore {
     states: Arc<RwLock<HashMap<String, StateEntry>>>,
     expiration_seconds: i64,
+    clock: Arc<dyn Clock>,
 }
 
 impl CacheOAuthStateStore {
@@ -56,6 +60,7 @@ impl CacheOAuthStateStore {
         Self {
             states: Arc::new(RwLock::new(HashMap::new())),
             expiration_seconds: 600, // 10 minutes
+            clock: clock::system_clock(),
         }
     }
 
@@ -65,6 +70,14 @@ impl CacheOAuthStateStore {
         self
     }
 
+    /// Sets the clock used for expiration checks
+    ///
+    /// Defaults to the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Returns the number of stored states
     pub async fn state_count(&self) -> usize {
         self.states.read().await.len()
@@ -75,9 +88,14 @@ impl CacheOAuthStateStore {
         self.states.write().await.clear();
     }
 
+    /// Returns the current time according to the configured clock
+    fn now(&self) -> DateTime<Utc> {
+        clock::utc_now(self.clock.as_ref())
+    }
+
     /// Removes expired states
     pub async fn cleanup_expired(&self) {
-        let now = Utc::now();
+        let now = self.now();
         let mut states = self.states.write().await;
 
         let before = states.len();
@@ -99,40 +117,50 @@ impl Default for CacheOAuthStateStore {
 #[async_trait]
 impl OAuthStateStore for CacheOAuthStateStore {
     async fn issue(&self) -> Result<String> {
+        self.issue_with_data(Value::Null).await
+    }
+
+    async fn consume(&self, state: &str) -> Result<bool> {
+        Ok(self.consume_with_data(state).await?.is_some())
+    }
+
+    async fn issue_with_data(&self, data: Value) -> Result<String> {
+        check_state_data(&data)?;
+
         // Cleanup expired states before issuing new one
         self.cleanup_expired().await;
 
         let state = Uuid::new_v4().to_string();
-        let expires_at = Utc::now() + Duration::seconds(self.expiration_seconds);
+        let expires_at = self.now() + Duration::seconds(self.expiration_seconds);
 
         self.states
             .write()
             .await
-            .insert(state.clone(), StateEntry { expires_at });
+            .insert(state.clone(), StateEntry { expires_at, data });
 
         debug!("Issued state {} (expires at {})", state, expires_at);
 
         Ok(state)
     }
 
-    async fn consume(&self, state: &str) -> Result<bool> {
+    async fn consume_with_data(&self, state: &str) -> Result<Option<Value>> {
         // Cleanup expired states
         self.cleanup_expired().await;
 
         let mut states = self.states.write().await;
 
         if let Some(entry) = states.remove(state) {
-            let now = Utc::now();
+            let now = self.now();
             if entry.expires_at > now {
                 debug!("Consumed valid state {}", state);
-                Ok(true)
+                Ok(Some(entry.data))
             } else {
                 debug!("State {} has expired", state);
-                Ok(false)
+                Ok(None)
             }
         } else {
             debug!("State {} not found or already consumed", state);
-            Ok(false)
+            Ok(None)
         }
     }
 }
@@ -140,7 +168,7 @@ impl OAuthStateStore for CacheOAuthStateStore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tokio::time::{sleep, Duration as TokioDuration};
+    use crate::clock::MockClock;
 
     #[tokio::test]
     async fn test_issue_and_consume() {
@@ -183,12 +211,15 @@ mod tests {
 
     #[tokio::test]
     async fn test_expiration() {
-        let store = CacheOAuthStateStore::new().with_expiration_seconds(1);
+        let clock = MockClock::default();
+        let store = CacheOAuthStateStore::new()
+            .with_expiration_seconds(1)
+            .with_clock(Arc::new(clock.clone()));
 
         let state = store.issue().await.unwrap();
 
-        // Wait for expiration
-        sleep(TokioDuration::from_secs(2)).await;
+        // Move past expiration
+        clock.advance(std::time::Duration::from_secs(2));
 
         let is_valid = store.consume(&state).await.unwrap();
         assert!(!is_valid);
@@ -196,15 +227,18 @@ mod tests {
 
     #[tokio::test]
     async fn test_cleanup() {
-        let store = CacheOAuthStateStore::new().with_expiration_seconds(1);
+        let clock = MockClock::default();
+        let store = CacheOAuthStateStore::new()
+            .with_expiration_seconds(1)
+            .with_clock(Arc::new(clock.clone()));
 
         let _state1 = store.issue().await.unwrap();
         let _state2 = store.issue().await.unwrap();
 
         assert_eq!(store.state_count().await, 2);
 
-        // Wait for expiration
-        sleep(TokioDuration::from_secs(2)).await;
+        // Move past expiration
+        clock.advance(std::time::Duration::from_secs(2));
 
         // Cleanup should remove expired states
         store.cleanup_expired().await;
@@ -223,4 +257,43 @@ mod tests {
         store.clear().await;
         assert_eq!(store.state_count().await, 0);
     }
+
+    #[tokio::test]
+    async fn test_state_data_round_trip() {
+        let clock = MockClock::default();
+        let store = CacheOAuthStateStore::new()
+            .with_expiration_seconds(60)
+            .with_clock(Arc::new(clock.clone()));
+        let data =
+            serde_json::json!({"user_id": "U123", "return_to": "/settings", "reinstall": true});
+
+        let state = store.issue_with_data(data.clone()).await.unwrap();
+        assert_eq!(store.consume_with_data(&state).await.unwrap(), Some(data));
+        assert_eq!(store.consume_with_data(&state).await.unwrap(), None);
+
+        let plain = store.issue().await.unwrap();
+        assert_eq!(
+            store.consume_with_data(&plain).await.unwrap(),
+            Some(Value::Null)
+        );
+
+        let expiring = store
+            .issue_with_data(serde_json::json!({"user_id": "U123"}))
+            .await
+            .unwrap();
+        clock.advance(std::time::Duration::from_secs(61));
+        assert_eq!(store.consume_with_data(&expiring).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_state_data_size_limit() {
+        let store = CacheOAuthStateStore::new();
+        let data = Value::String("x".repeat(crate::oauth::state_store::MAX_STATE_DATA_BYTES));
+
+        assert!(matches!(
+            store.issue_with_data(data).await,
+            Err(crate::error::SlackError::Validation(_))
+        ));
+        assert_eq!(store.state_count().await, 0);
+    }
 }
@@ -1,12 +1,15 @@
 #![allow(missing_debug_implementations)]
 //! File-based OAuth state storage
 
+use crate::clock::{self, Clock};
 use crate::error::{Error, Result};
-use crate::oauth::state_store::OAuthStateStore;
+use crate::oauth::state_store::{check_state_data, OAuthStateStore};
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::path::PathBuf;
+use std::sync::Arc;
 use tokio::fs;
 use tracing::{debug, warn};
 use uuid::Uuid;
@@ -15,6 +18,8 @@ use uuid::Uuid;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct StateEntry {
     expires_at: DateTime<Utc>,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    data: Value,
 }
 
 /// File-based OAuth state store
@@ -41,6 +46,7 @@ struct StateEntry {
 pub struct FileOAuthStateStore {
     base_dir: PathBuf,
     expiration_seconds: i64,
+    clock: Arc<dyn Clock>,
 }
 
 impl FileOAuthStateStore {
@@ -53,6 +59,7 @@ impl FileOAuthStateStore {
         Self {
             base_dir: base_dir.into(),
             expiration_seconds: 600, // 10 minutes
+            clock: clock::system_clock(),
         }
     }
 
@@ -62,6 +69,19 @@ impl FileOAuthStateStore {
         self
     }
 
+    /// Sets the clock used for expiration checks
+    ///
+    /// Defaults to the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the current time according to the configured clock
+    fn now(&self) -> DateTime<Utc> {
+        clock::utc_now(self.clock.as_ref())
+    }
+
     /// Ensures the base directory exists
     async fn ensure_dir(&self) -> Result<()> {
         fs::create_dir_all(&self.base_dir).await.map_err(|e| {
@@ -126,7 +146,7 @@ impl FileOAuthStateStore {
             return Ok(());
         }
 
-        let now = Utc::now();
+        let now = self.now();
         let mut entries = fs::read_dir(&self.base_dir).await.map_err(|e| {
             Error::storage_error(format!(
                 "Failed to read directory {:?}: {}",
@@ -171,6 +191,15 @@ impl FileOAuthStateStore {
 #[async_trait]
 impl OAuthStateStore for FileOAuthStateStore {
     async fn issue(&self) -> Result<String> {
+        self.issue_with_data(Value::Null).await
+    }
+
+    async fn consume(&self, state: &str) -> Result<bool> {
+        Ok(self.consume_with_data(state).await?.is_some())
+    }
+
+    async fn issue_with_data(&self, data: Value) -> Result<String> {
+        check_state_data(&data)?;
         self.ensure_dir().await?;
 
         // Cleanup expired states
@@ -179,9 +208,9 @@ impl OAuthStateStore for FileOAuthStateStore {
         }
 
         let state = Uuid::new_v4().to_string();
-        let expires_at = Utc::now() + Duration::seconds(self.expiration_seconds);
+        let expires_at = self.now() + Duration::seconds(self.expiration_seconds);
 
-        let entry = StateEntry { expires_at };
+        let entry = StateEntry { expires_at, data };
         self.write_state(&state, &entry).await?;
 
         debug!("Issued state {} (expires at {})", state, expires_at);
@@ -189,7 +218,7 @@ impl OAuthStateStore for FileOAuthStateStore {
         Ok(state)
     }
 
-    async fn consume(&self, state: &str) -> Result<bool> {
+    async fn consume_with_data(&self, state: &str) -> Result<Option<Value>> {
         // Cleanup expired states
         if let Err(e) = self.cleanup_expired().await {
             warn!("Failed to cleanup expired states: {}", e);
@@ -199,17 +228,17 @@ impl OAuthStateStore for FileOAuthStateStore {
             // Delete the state file first
             self.delete_state(state).await?;
 
-            let now = Utc::now();
+            let now = self.now();
             if entry.expires_at > now {
                 debug!("Consumed valid state {}", state);
-                Ok(true)
+                Ok(Some(entry.data))
             } else {
                 debug!("State {} has expired", state);
-                Ok(false)
+                Ok(None)
             }
         } else {
             debug!("State {} not found or already consumed", state);
-            Ok(false)
+            Ok(None)
         }
     }
 }
@@ -217,8 +246,8 @@ impl OAuthStateStore for FileOAuthStateStore {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use tempfile::TempDir;
-    use tokio::time::{sleep, Duration as TokioDuration};
 
     async fn create_test_store() -> (FileOAuthStateStore, TempDir) {
         let temp_dir = TempDir::new().unwrap();
@@ -268,12 +297,15 @@ mod tests {
     #[tokio::test]
     async fn test_expiration() {
         let temp_dir = TempDir::new().unwrap();
-        let store = FileOAuthStateStore::new(temp_dir.path()).with_expiration_seconds(1);
+        let clock = MockClock::default();
+        let store = FileOAuthStateStore::new(temp_dir.path())
+            .with_expiration_seconds(1)
+            .with_clock(Arc::new(clock.clone()));
 
         let state = store.issue().await.unwrap();
 
-        // Wait for expiration
-        sleep(TokioDuration::from_secs(2)).await;
+        // Move past expiration
+        clock.advance(std::time::Duration::from_secs(2));
 
         let is_valid = store.consume(&state).await.unwrap();
         assert!(!is_valid);
@@ -282,13 +314,16 @@ mod tests {
     #[tokio::test]
     async fn test_cleanup() {
         let temp_dir = TempDir::new().unwrap();
-        let store = FileOAuthStateStore::new(temp_dir.path()).with_expiration_seconds(1);
+        let clock = MockClock::default();
+        let store = FileOAuthStateStore::new(temp_dir.path())
+            .with_expiration_seconds(1)
+            .with_clock(Arc::new(clock.clone()));
 
         let _state1 = store.issue().await.unwrap();
         let _state2 = store.issue().await.unwrap();
 
-        // Wait for expiration
-        sleep(TokioDuration::from_secs(2)).await;
+        // Move past expiration
+        clock.advance(std::time::Duration::from_secs(2));
 
         // Cleanup should remove expired states
         store.cleanup_expired().await.unwrap();
@@ -296,9 +331,59 @@ mod tests {
         // Check that files are gone
         let mut entries = fs::read_dir(temp_dir.path()).await.unwrap();
         let mut count = 0;
-        while let Some(_) = entries.next_entry().await.unwrap() {
+        while entries.next_entry().await.unwrap().is_some() {
             count += 1;
         }
         assert_eq!(count, 0);
     }
+
+    #[tokio::test]
+    async fn test_state_data_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let clock = MockClock::default();
+        let store = FileOAuthStateStore::new(temp_dir.path())
+            .with_expiration_seconds(60)
+            .with_clock(Arc::new(clock.clone()));
+        let data =
+            serde_json::json!({"user_id": "U123", "return_to": "/settings", "reinstall": true});
+
+        let state = store.issue_with_data(data.clone()).await.unwrap();
+        assert_eq!(store.consume_with_data(&state).await.unwrap(), Some(data));
+        assert_eq!(store.consume_with_data(&state).await.unwrap(), None);
+
+        let expiring = store
+            .issue_with_data(serde_json::json!({"user_id": "U123"}))
+            .await
+            .unwrap();
+        clock.advance(std::time::Duration::from_secs(61));
+        assert_eq!(store.consume_with_data(&expiring).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_state_without_data_reads_as_null() {
+        let (store, temp_dir) = create_test_store().await;
+
+        // Files written before state data existed have no `data` field
+        let state = store.issue().await.unwrap();
+        let contents = fs::read_to_string(temp_dir.path().join(format!("{}.json", state)))
+            .await
+            .unwrap();
+        assert!(!contents.contains("data"));
+
+        assert_eq!(
+            store.consume_with_data(&state).await.unwrap(),
+            Some(Value::Null)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_state_data_size_limit() {
+        let (store, _temp_dir) = create_test_store().await;
+        let data = Value::String("x".repeat(crate::oauth::state_store::MAX_STATE_DATA_BYTES));
+
+        assert!(matches!(
+            store.issue_with_data(data).await,
+            Err(Error::Validation(_))
+        ));
+    }
 }
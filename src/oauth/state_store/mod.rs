@@ -2,12 +2,16 @@
 //!
 //! This module provides traits and implementations for managing OAuth state parameters.
 
-use crate::error::Result;
+use crate::error::{Result, SlackError};
 use async_trait::async_trait;
+use serde_json::Value;
 
 pub mod cache;
 pub mod file;
 
+/// Largest serialized data payload a state may carry, in bytes
+pub const MAX_STATE_DATA_BYTES: usize = 4096;
+
 /// Trait for storing and validating OAuth state parameters
 ///
 /// OAuth state parameters are used to prevent CSRF attacks during the OAuth flow.
@@ -65,4 +69,104 @@ pub trait OAuthStateStore: Send + Sync {
     /// `Ok(true)` if the state is valid and consumed
     /// `Ok(false)` if the state is invalid or already consumed
     async fn consume(&self, state: &str) -> Result<bool>;
+
+    /// Issues a new OAuth state value carrying `data` through the OAuth
+    /// round-trip, e.g. the initiating user or a return-to URL
+    ///
+    /// The default implementation cannot store data: it issues a plain
+    /// state when `data` is null and returns `SlackError::Unsupported`
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if `data` serializes to more than
+    /// [`MAX_STATE_DATA_BYTES`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use slack_rs::oauth::state_store::cache::CacheOAuthStateStore;
+    /// use slack_rs::oauth::state_store::OAuthStateStore;
+    /// use serde_json::json;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> slack_rs::error::Result<()> {
+    /// let store = CacheOAuthStateStore::new();
+    /// let state = store
+    ///     .issue_with_data(json!({"user_id": "U123", "return_to": "/settings"}))
+    ///     .await?;
+    ///
+    /// let data = store.consume_with_data(&state).await?;
+    /// assert_eq!(data.unwrap()["return_to"], "/settings");
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn issue_with_data(&self, data: Value) -> Result<String> {
+        check_state_data(&data)?;
+        if !data.is_null() {
+            return Err(SlackError::Unsupported(
+                "this OAuth state store cannot store state data".to_string(),
+            ));
+        }
+        self.issue().await
+    }
+
+    /// Consumes an OAuth state value and returns the data it was issued
+    /// with
+    ///
+    /// Returns `Ok(None)` if the state is invalid, expired or already
+    /// consumed, and `Value::Null` for states issued without data. The
+    /// default implementation delegates to [`consume`](Self::consume).
+    async fn consume_with_data(&self, state: &str) -> Result<Option<Value>> {
+        Ok(self.consume(state).await?.then_some(Value::Null))
+    }
+}
+
+/// Checks that state data fits within [`MAX_STATE_DATA_BYTES`]
+pub(crate) fn check_state_data(data: &Value) -> Result<()> {
+    let size = serde_json::to_vec(data)?.len();
+    if size > MAX_STATE_DATA_BYTES {
+        return Err(SlackError::Validation(format!(
+            "OAuth state data is {} bytes; the maximum length is {} bytes",
+            size, MAX_STATE_DATA_BYTES
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A store relying on the default data methods
+    struct PlainStore;
+
+    #[async_trait]
+    impl OAuthStateStore for PlainStore {
+        async fn issue(&self) -> Result<String> {
+            Ok("state".to_string())
+        }
+
+        async fn consume(&self, state: &str) -> Result<bool> {
+            Ok(state == "state")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_data_methods() {
+        let store = PlainStore;
+
+        assert_eq!(store.issue_with_data(Value::Null).await.unwrap(), "state");
+        assert!(matches!(
+            store
+                .issue_with_data(serde_json::json!({"user_id": "U1"}))
+                .await,
+            Err(SlackError::Unsupported(_))
+        ));
+        assert_eq!(
+            store.consume_with_data("state").await.unwrap(),
+            Some(Value::Null)
+        );
+        assert_eq!(store.consume_with_data("other").await.unwrap(), None);
+    }
 }
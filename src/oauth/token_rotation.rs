@@ -3,14 +3,14 @@
 //!
 //! Handles automatic token refresh when token rotation is enabled.
 
+use crate::clock::{self, Clock};
 use crate::error::{Error, Result};
 use crate::oauth::installation_store::InstallationStore;
 use crate::oauth::models::Installation;
-use chrono::Utc;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tracing::debug;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, Instrument};
 
 /// Token refresh response from Slack API
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,6 +51,8 @@ pub struct TokenRotator {
     store: Arc<dyn InstallationStore>,
     client_id: String,
     client_secret: String,
+    clock: Arc<dyn Clock>,
+    base_url: String,
 }
 
 impl TokenRotator {
@@ -71,15 +73,33 @@ impl TokenRotator {
             store,
             client_id: client_id.into(),
             client_secret: client_secret.into(),
+            clock: clock::system_clock(),
+            base_url: crate::web::AsyncWebClient::BASE_URL.to_string(),
         }
     }
 
+    /// Sets the Slack API base URL used for token refreshes
+    ///
+    /// Defaults to `https://slack.com/api/`.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the clock used for expiry checks
+    ///
+    /// Defaults to the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Checks if a token needs rotation
     ///
     /// Returns true if the token expires within the threshold (default 2 hours).
     pub fn needs_rotation(&self, token_expires_at: Option<i64>, threshold_seconds: i64) -> bool {
         if let Some(expires_at) = token_expires_at {
-            let now = Utc::now().timestamp();
+            let now = clock::unix_timestamp(self.clock.as_ref());
             let time_until_expiry = expires_at - now;
             time_until_expiry < threshold_seconds
         } else {
@@ -130,7 +150,8 @@ impl TokenRotator {
         }
 
         if let Some(expires_in) = response.expires_in {
-            installation.bot_token_expires_at = Some(Utc::now().timestamp() + expires_in);
+            installation.bot_token_expires_at =
+                Some(clock::unix_timestamp(self.clock.as_ref()) + expires_in);
         }
 
         // Save updated installation
@@ -181,7 +202,8 @@ impl TokenRotator {
         }
 
         if let Some(expires_in) = response.expires_in {
-            installation.user_token_expires_at = Some(Utc::now().timestamp() + expires_in);
+            installation.user_token_expires_at =
+                Some(clock::unix_timestamp(self.clock.as_ref()) + expires_in);
         }
 
         // Save updated installation
@@ -202,12 +224,8 @@ impl TokenRotator {
         });
 
         // Make direct HTTP call since oauth.v2.access doesn't require auth
-        let response = self
-            .http_client
-            .post("https://slack.com/api/oauth.v2.access")
-            .form(&params)
-            .send()
-            .await?;
+        let url = format!("{}/oauth.v2.access", self.base_url.trim_end_matches('/'));
+        let response = self.http_client.post(url).form(&params).send().await?;
 
         let refresh_response: TokenRefreshResponse = response.json().await.map_err(|e| {
             Error::deserialization_error(format!("Failed to parse refresh response: {}", e))
@@ -265,10 +283,125 @@ impl TokenRotator {
     }
 }
 
+/// Rotates an installation's token before API calls that would use it
+/// close to expiry
+///
+/// Set on a client with
+/// [`AsyncWebClientBuilder::with_auto_token_rotation`](crate::web::AsyncWebClientBuilder::with_auto_token_rotation).
+/// Before each request the hook checks the token's expiry and, if it is
+/// within the threshold, refreshes it through the [`TokenRotator`], which
+/// also saves the new token to the installation store. The current token is
+/// shared with the client through an `Arc<Mutex<String>>`.
+///
+/// The bot token is rotated when the installation has one, otherwise the
+/// user token.
+pub struct TokenRotationHook {
+    rotator: Arc<TokenRotator>,
+    installation: tokio::sync::Mutex<Installation>,
+    token: Arc<Mutex<String>>,
+    bot: bool,
+    threshold_seconds: i64,
+}
+
+impl TokenRotationHook {
+    /// Creates a hook rotating the token of `installation`
+    pub fn new(rotator: Arc<TokenRotator>, installation: Installation) -> Self {
+        let bot = installation.bot_token.is_some();
+        let token = if bot {
+            installation.bot_token.clone()
+        } else {
+            installation.user_token.clone()
+        };
+        Self {
+            rotator,
+            installation: tokio::sync::Mutex::new(installation),
+            token: Arc::new(Mutex::new(token.unwrap_or_default())),
+            bot,
+            threshold_seconds: 7200,
+        }
+    }
+
+    /// Sets how many seconds before expiry the token is rotated
+    ///
+    /// Defaults to 7200 (2 hours).
+    pub fn with_threshold(mut self, threshold_seconds: i64) -> Self {
+        self.threshold_seconds = threshold_seconds;
+        self
+    }
+
+    /// Returns the shared handle to the current token
+    pub fn token(&self) -> Arc<Mutex<String>> {
+        Arc::clone(&self.token)
+    }
+
+    /// Rotates the token if it needs rotation and returns the token to use
+    ///
+    /// Concurrent callers wait for an in-progress rotation instead of
+    /// starting their own.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::InvalidInput` if the installation has no token,
+    /// or the rotator's error if the refresh fails.
+    pub async fn before_request(&self) -> Result<String> {
+        let mut installation = self.installation.lock().await;
+        let expires_at = if self.bot {
+            installation.bot_token_expires_at
+        } else {
+            installation.user_token_expires_at
+        };
+
+        if self
+            .rotator
+            .needs_rotation(expires_at, self.threshold_seconds)
+        {
+            let kind = if self.bot { "bot" } else { "user" };
+            let span = tracing::info_span!(
+                "token_rotation",
+                kind,
+                team_id = installation.team_id.as_deref().unwrap_or("none")
+            );
+            let current = installation.clone();
+            let threshold = Some(self.threshold_seconds);
+            let rotated = async {
+                let rotated = if self.bot {
+                    self.rotator
+                        .check_and_rotate_bot_token(current, threshold)
+                        .await?
+                } else {
+                    self.rotator
+                        .check_and_rotate_user_token(current, threshold)
+                        .await?
+                };
+                info!("Rotated {} token", kind);
+                Ok::<_, Error>(rotated)
+            }
+            .instrument(span)
+            .await?;
+
+            let token = if self.bot {
+                rotated.bot_token.clone()
+            } else {
+                rotated.user_token.clone()
+            };
+            *self.token.lock().unwrap() = token.unwrap_or_default();
+            *installation = rotated;
+        }
+
+        let token = self.token.lock().unwrap().clone();
+        if token.is_empty() {
+            return Err(Error::invalid_input("Installation has no token to rotate"));
+        }
+        Ok(token)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use crate::oauth::installation_store::cache::CacheInstallationStore;
+    use chrono::Utc;
 
     #[test]
     fn test_needs_rotation() {
@@ -291,6 +424,22 @@ mod tests {
         assert!(rotator.needs_rotation(Some(expires_at), 7200));
     }
 
+    #[test]
+    fn test_needs_rotation_with_mock_clock() {
+        let store = Arc::new(CacheInstallationStore::new());
+        let clock = MockClock::from_unix_secs(1_700_000_000);
+        let rotator = TokenRotator::new(store, "client_id", "client_secret")
+            .with_clock(Arc::new(clock.clone()));
+
+        // Expires in 3 hours - outside the 2 hour threshold
+        let expires_at = 1_700_000_000 + 10800;
+        assert!(!rotator.needs_rotation(Some(expires_at), 7200));
+
+        // One hour later it is inside the threshold
+        clock.advance(std::time::Duration::from_secs(3601));
+        assert!(rotator.needs_rotation(Some(expires_at), 7200));
+    }
+
     #[test]
     fn test_token_rotator_creation() {
         let store = Arc::new(CacheInstallationStore::new());
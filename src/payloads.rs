@@ -0,0 +1,476 @@
+//! Typed slash command and interactive component payloads.
+//!
+//! Slack sends these payloads two ways. Over HTTP, a slash command is a
+//! form-encoded body and an interaction is a form with a single `payload`
+//! field holding JSON; use [`SlashCommandPayload::from_form`] and
+//! [`InteractionPayload::from_form`] on a verified request body. Over Socket
+//! Mode, both arrive as JSON objects in the envelope's `payload`; see
+//! [`SocketModeRequest::slash_command`](crate::socket_mode::SocketModeRequest::slash_command)
+//! and [`SocketModeRequest::interaction`](crate::socket_mode::SocketModeRequest::interaction).
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_rs::payloads::SlashCommandPayload;
+//!
+//! let body = "command=%2Fdeploy&text=api+prod&user_id=U123&channel_id=C123\
+//!             &team_id=T123&trigger_id=1.2.abc\
+//!             &response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2F1";
+//! let command = SlashCommandPayload::from_form(body).unwrap();
+//! assert_eq!(command.command, "/deploy");
+//! assert_eq!(command.text, "api prod");
+//! ```
+//!
+//! See: https://api.slack.com/interactivity/slash-commands
+
+use crate::error::{Result, SlackError};
+use crate::models::{Message, Timestamp};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// A slash command invocation.
+///
+/// Fields Slack leaves out of a payload default to empty strings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlashCommandPayload {
+    /// The command that was typed, e.g. `/deploy`.
+    pub command: String,
+
+    /// Text typed after the command.
+    pub text: String,
+
+    /// ID of the user who ran the command.
+    pub user_id: String,
+
+    /// Username of the user who ran the command.
+    pub user_name: String,
+
+    /// ID of the channel the command was run in.
+    pub channel_id: String,
+
+    /// Name of the channel the command was run in.
+    pub channel_name: String,
+
+    /// ID of the workspace.
+    pub team_id: String,
+
+    /// Domain of the workspace.
+    pub team_domain: String,
+
+    /// ID of the Enterprise Grid organization, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enterprise_id: Option<String>,
+
+    /// ID of the app the command belongs to.
+    pub api_app_id: String,
+
+    /// Whether the app is installed org-wide.
+    #[serde(deserialize_with = "bool_or_string")]
+    pub is_enterprise_install: bool,
+
+    /// URL for posting delayed responses.
+    pub response_url: String,
+
+    /// Trigger for opening a modal with `views.open`.
+    pub trigger_id: String,
+}
+
+impl SlashCommandPayload {
+    /// Parses a form-encoded slash command request body.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the body has no `command` field.
+    pub fn from_form(body: &str) -> Result<Self> {
+        let fields: Map<String, Value> = url::form_urlencoded::parse(body.as_bytes())
+            .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+            .collect();
+        Self::from_value(Value::Object(fields))
+    }
+
+    /// Parses a slash command delivered as a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the object has no `command` field.
+    pub fn from_value(payload: Value) -> Result<Self> {
+        if payload.get("command").and_then(Value::as_str).is_none() {
+            return Err(SlackError::Validation(
+                "slash command payload has no command".to_string(),
+            ));
+        }
+        Ok(serde_json::from_value(payload)?)
+    }
+}
+
+/// The user who triggered an interaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionUser {
+    /// User ID.
+    pub id: String,
+
+    /// Username.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// Workspace the user belongs to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_id: Option<String>,
+}
+
+/// A workspace or channel reference in an interaction payload.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InteractionRef {
+    /// Workspace or channel ID.
+    pub id: String,
+
+    /// Channel name, or workspace domain under `domain`.
+    #[serde(default, alias = "domain", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// An action taken on an interactive element, from a `block_actions`
+/// payload.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockAction {
+    /// The element's `action_id`.
+    pub action_id: String,
+
+    /// ID of the block holding the element.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+
+    /// Element type, e.g. `button` or `static_select`.
+    #[serde(rename = "type", default)]
+    pub action_type: String,
+
+    /// Value of a clicked button.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Timestamp of the action.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_ts: Option<String>,
+
+    /// The selected option of a select menu, when there is one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selected_option: Option<Value>,
+}
+
+/// An interactive component payload, such as `block_actions`,
+/// `view_submission` or `shortcut`.
+///
+/// Commonly used fields are typed; the rest of the payload is kept in
+/// [`raw`](Self::raw).
+#[derive(Debug, Clone, PartialEq)]
+pub struct InteractionPayload {
+    /// Payload type, e.g. `block_actions` or `view_submission`.
+    pub interaction_type: String,
+
+    /// The user who triggered the interaction.
+    pub user: Option<InteractionUser>,
+
+    /// The workspace the interaction happened in.
+    pub team: Option<InteractionRef>,
+
+    /// The channel the interaction happened in, if any.
+    pub channel: Option<InteractionRef>,
+
+    /// Trigger for opening a modal with `views.open`.
+    pub trigger_id: Option<String>,
+
+    /// URL for responding to the interaction, if any.
+    pub response_url: Option<String>,
+
+    /// Actions taken, for `block_actions`.
+    pub actions: Vec<BlockAction>,
+
+    /// The full payload.
+    pub raw: Value,
+}
+
+#[derive(Deserialize)]
+struct InteractionFields {
+    #[serde(rename = "type")]
+    interaction_type: String,
+    #[serde(default)]
+    user: Option<InteractionUser>,
+    #[serde(default)]
+    team: Option<InteractionRef>,
+    #[serde(default)]
+    channel: Option<InteractionRef>,
+    #[serde(default)]
+    trigger_id: Option<String>,
+    #[serde(default)]
+    response_url: Option<String>,
+    #[serde(default)]
+    actions: Vec<BlockAction>,
+}
+
+impl InteractionPayload {
+    /// Parses a form-encoded interaction request body, whose `payload`
+    /// field holds the JSON payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the body has no `payload` field,
+    /// or a serialization error if it isn't a valid payload.
+    pub fn from_form(body: &str) -> Result<Self> {
+        let payload = url::form_urlencoded::parse(body.as_bytes())
+            .find(|(key, _)| key == "payload")
+            .map(|(_, value)| value.into_owned())
+            .ok_or_else(|| {
+                SlackError::Validation("interaction body has no payload field".to_string())
+            })?;
+        Self::from_value(serde_json::from_str(&payload)?)
+    }
+
+    /// Parses an interaction payload delivered as a JSON object.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serialization error if the payload has no `type`.
+    pub fn from_value(payload: Value) -> Result<Self> {
+        let fields = InteractionFields::deserialize(&payload)?;
+        Ok(Self {
+            interaction_type: fields.interaction_type,
+            user: fields.user,
+            team: fields.team,
+            channel: fields.channel,
+            trigger_id: fields.trigger_id,
+            response_url: fields.response_url,
+            actions: fields.actions,
+            raw: payload,
+        })
+    }
+
+    /// Returns the `view` object for view interactions such as
+    /// `view_submission`.
+    pub fn view(&self) -> Option<&Value> {
+        self.raw.get("view")
+    }
+}
+
+/// An Events API event, from the `event` of an `event_callback` payload.
+///
+/// Commonly used fields are typed; the full event object is kept in
+/// [`raw`](Self::raw).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventPayload {
+    /// Event type, e.g. `message` or `reaction_added`.
+    pub event_type: String,
+
+    /// Event subtype, e.g. `message_changed`.
+    pub subtype: Option<String>,
+
+    /// ID of the user the event is about.
+    pub user: Option<String>,
+
+    /// ID of the channel the event happened in.
+    pub channel: Option<String>,
+
+    /// Kind of channel for message events, e.g. `channel` or `im`.
+    pub channel_type: Option<String>,
+
+    /// Message text.
+    pub text: Option<String>,
+
+    /// Message timestamp.
+    pub ts: Option<Timestamp>,
+
+    /// Timestamp of the parent message for thread replies.
+    pub thread_ts: Option<Timestamp>,
+
+    /// Workspace the event was delivered for, from the outer payload.
+    pub team_id: Option<String>,
+
+    /// Unique ID of the delivery, from the outer payload.
+    pub event_id: Option<String>,
+
+    /// The full `event` object.
+    pub raw: Value,
+}
+
+#[derive(Deserialize)]
+struct EventFields {
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default, deserialize_with = "string_or_none")]
+    user: Option<String>,
+    #[serde(default, deserialize_with = "string_or_none")]
+    channel: Option<String>,
+    #[serde(default)]
+    channel_type: Option<String>,
+    #[serde(default)]
+    text: Option<String>,
+    #[serde(default)]
+    ts: Option<Timestamp>,
+    #[serde(default)]
+    thread_ts: Option<Timestamp>,
+}
+
+impl EventPayload {
+    /// Parses an Events API payload, the object holding `event`,
+    /// `team_id` and `event_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the payload has no `event`
+    /// object, or a serialization error if the event has no `type`.
+    pub fn from_value(payload: Value) -> Result<Self> {
+        let Some(event) = payload.get("event").filter(|e| e.is_object()) else {
+            return Err(SlackError::Validation(
+                "events payload has no event".to_string(),
+            ));
+        };
+        let fields = EventFields::deserialize(event)?;
+        let outer = |key: &str| payload.get(key).and_then(Value::as_str).map(str::to_string);
+        Ok(Self {
+            event_type: fields.event_type,
+            subtype: fields.subtype,
+            user: fields.user,
+            channel: fields.channel,
+            channel_type: fields.channel_type,
+            text: fields.text,
+            ts: fields.ts,
+            thread_ts: fields.thread_ts,
+            team_id: outer("team_id"),
+            event_id: outer("event_id"),
+            raw: event.clone(),
+        })
+    }
+
+    /// Parses the event as a [`Message`], e.g. for `message` and
+    /// `app_mention` events.
+    ///
+    /// # Errors
+    ///
+    /// Returns a serialization error if the event has no `ts`.
+    pub fn message(&self) -> Result<Message> {
+        Ok(Message::deserialize(&self.raw)?)
+    }
+}
+
+/// Reads a string field, treating objects (such as the `channel` of
+/// `channel_created`) as absent.
+fn string_or_none<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(s) => Some(s),
+        _ => None,
+    })
+}
+
+/// Accepts `true` as well as `"true"`, since form-encoded payloads carry
+/// booleans as strings.
+fn bool_or_string<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<bool, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Bool(b) => b,
+        Value::String(s) => s == "true",
+        _ => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn test_slash_command_from_form() {
+        let body = "token=xyz&team_id=T1DC2JH3J&team_domain=testteamnow&channel_id=G8PSS9T3V\
+                    &channel_name=foobar&user_id=U2CERLKJA&user_name=roadrunner\
+                    &command=%2Fwebhook-collect&text=&is_enterprise_install=false\
+                    &response_url=https%3A%2F%2Fhooks.slack.com%2Fcommands%2FT1DC2JH3J%2F1\
+                    &trigger_id=398738663015.47445629121.803a0bc887a14d10d2c447fce8b6703c";
+
+        let command = SlashCommandPayload::from_form(body).unwrap();
+        assert_eq!(command.command, "/webhook-collect");
+        assert_eq!(command.text, "");
+        assert_eq!(command.user_name, "roadrunner");
+        assert_eq!(
+            command.response_url,
+            "https://hooks.slack.com/commands/T1DC2JH3J/1"
+        );
+        assert!(!command.is_enterprise_install);
+        assert!(command.enterprise_id.is_none());
+    }
+
+    #[test]
+    fn test_slash_command_requires_command() {
+        assert!(matches!(
+            SlashCommandPayload::from_form("text=hi"),
+            Err(SlackError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_interaction_from_form() {
+        let payload = json!({
+            "type": "block_actions",
+            "user": {"id": "U1", "username": "jane", "team_id": "T1"},
+            "team": {"id": "T1", "domain": "acme"},
+            "trigger_id": "1.2.abc",
+            "actions": [{"action_id": "approve", "block_id": "b1", "type": "button", "value": "42"}]
+        });
+        let body = format!(
+            "payload={}",
+            urlencoding::encode(&serde_json::to_string(&payload).unwrap())
+        );
+
+        let interaction = InteractionPayload::from_form(&body).unwrap();
+        assert_eq!(interaction.interaction_type, "block_actions");
+        assert_eq!(interaction.user.unwrap().id, "U1");
+        assert_eq!(interaction.team.unwrap().name.as_deref(), Some("acme"));
+        assert_eq!(interaction.actions[0].value.as_deref(), Some("42"));
+        assert_eq!(interaction.raw, payload);
+    }
+
+    #[test]
+    fn test_event_from_value() {
+        let event = EventPayload::from_value(json!({
+            "team_id": "T1",
+            "event_id": "Ev1",
+            "event": {
+                "type": "message",
+                "channel": "C1",
+                "channel_type": "channel",
+                "user": "U1",
+                "text": "deploy api",
+                "ts": "1700000000.000100"
+            }
+        }))
+        .unwrap();
+        assert_eq!(event.event_type, "message");
+        assert_eq!(event.text.as_deref(), Some("deploy api"));
+        assert_eq!(event.team_id.as_deref(), Some("T1"));
+        let message = event.message().unwrap();
+        assert_eq!(message.ts.as_str(), "1700000000.000100");
+        assert_eq!(message.user.as_deref(), Some("U1"));
+
+        let created = EventPayload::from_value(json!({
+            "event": {"type": "channel_created", "channel": {"id": "C2", "name": "new"}}
+        }))
+        .unwrap();
+        assert!(created.channel.is_none());
+        assert_eq!(created.raw["channel"]["id"], "C2");
+
+        assert!(matches!(
+            EventPayload::from_value(json!({"type": "event_callback"})),
+            Err(SlackError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_interaction_from_form_requires_payload() {
+        assert!(InteractionPayload::from_form("type=block_actions").is_err());
+        assert!(InteractionPayload::from_value(json!({"user": {"id": "U1"}})).is_err());
+    }
+}
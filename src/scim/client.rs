@@ -3,19 +3,144 @@
 //! This module provides both synchronous and asynchronous clients for
 //! interacting with Slack's SCIM API for user and group management.
 
+use crate::constants::headers::RETRY_AFTER;
+use crate::constants::headers::X_SLACK_REQ_ID;
+use crate::environment::SlackEnvironment;
 use crate::error::{Result, SlackError};
-use crate::scim::models::{Group, PatchRequest, ScimResponse, User};
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
-use serde_json::Value;
+use crate::http_retry::{
+    Backoff, ConnectionErrorHandler, HttpResponse, RateLimitErrorHandler, RetryHandler, RetryState,
+    ServerErrorHandler,
+};
+use crate::logging::log_scim_retry;
+use crate::scim::models::{
+    diff_members, Group, GroupBuilder, GroupMember, PatchOperation, PatchRequest, ScimError,
+    ScimResponse, User, UserBuilder,
+};
+use crate::web::internal_utils::{apply_default_headers, get_user_agent, merge_header};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, LOCATION, USER_AGENT};
+use reqwest::{Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use std::collections::{HashMap, HashSet};
+use std::error::Error as _;
 use std::time::Duration;
 
 /// Base URL for the Slack SCIM API.
 pub const SCIM_BASE_URL: &str = "https://api.slack.com/scim/v1/";
 
+/// Maximum number of members added or removed by a single group PATCH.
+///
+/// Larger lists are split across several requests.
+pub const MAX_MEMBERS_PER_PATCH: usize = 1000;
+
+/// Number of users requested per page by [`AsyncScimClient::search_users_all`].
+pub const SCIM_PAGE_SIZE: u32 = 1000;
+
+/// Outcome of [`AsyncScimClient::sync_users`].
+///
+/// In a dry run the lists describe the writes that would have been made:
+/// `created` holds the desired users, `updated` the desired users with the
+/// existing `id` filled in, and `deactivated` the current users.
+#[derive(Debug, Default)]
+pub struct SyncResult {
+    /// Users created in Slack.
+    pub created: Vec<User>,
+    /// Existing users whose attributes were replaced.
+    pub updated: Vec<User>,
+    /// Users missing from the desired list that were deactivated.
+    pub deactivated: Vec<User>,
+    /// Users that could not be synced, with the error for each.
+    pub errors: Vec<(User, SlackError)>,
+}
+
+impl SyncResult {
+    /// Returns true if every write succeeded.
+    pub fn is_success(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Default number of retries for a SCIM request.
+pub const DEFAULT_SCIM_MAX_RETRIES: u32 = 3;
+
+/// Describes a transport error, including its sources, so that the
+/// connection retry handler can recognise refused connections and timeouts.
+fn describe_error(error: &reqwest::Error) -> String {
+    let mut description = if error.is_timeout() {
+        format!("timeout: {}", error)
+    } else if error.is_connect() {
+        format!("connection error: {}", error)
+    } else {
+        error.to_string()
+    };
+    let mut source = error.source();
+    while let Some(cause) = source {
+        description.push_str(": ");
+        description.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    description
+}
+
+/// Returns the key `sync_users` matches users on.
+fn sync_key(user: &User) -> Option<String> {
+    user.user_name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Returns true if any attribute set on `desired` differs from `current`.
+fn needs_update(current: &User, desired: &User) -> bool {
+    fn differs<T: PartialEq>(desired: &Option<T>, current: &Option<T>) -> bool {
+        desired.is_some() && desired != current
+    }
+
+    differs(&desired.external_id, &current.external_id)
+        || differs(&desired.nick_name, &current.nick_name)
+        || differs(&desired.name, &current.name)
+        || differs(&desired.display_name, &current.display_name)
+        || differs(&desired.profile_url, &current.profile_url)
+        || differs(&desired.title, &current.title)
+        || differs(&desired.timezone, &current.timezone)
+        || differs(&desired.active, &current.active)
+        || differs(&desired.emails, &current.emails)
+        || differs(&desired.phone_numbers, &current.phone_numbers)
+        || differs(&desired.photos, &current.photos)
+        || differs(&desired.addresses, &current.addresses)
+        || differs(&desired.roles, &current.roles)
+}
+
+/// Builds the PATCH requests that add or remove `user_ids`, at most
+/// `chunk_size` members per request.
+fn member_patches(user_ids: &[&str], remove: bool, chunk_size: usize) -> Vec<PatchRequest> {
+    user_ids
+        .chunks(chunk_size.max(1))
+        .map(|chunk| {
+            let operations = if remove {
+                chunk
+                    .iter()
+                    .map(|id| PatchOperation::remove_member(id))
+                    .collect()
+            } else {
+                vec![PatchOperation::add_members(chunk)]
+            };
+            PatchRequest::new(operations)
+        })
+        .collect()
+}
+
 /// Asynchronous SCIM API client.
 ///
 /// Provides async methods for managing users and groups via the SCIM 2.0 protocol.
 ///
+/// Failed requests are retried like Web API calls: `429 Too Many Requests`
+/// after its `Retry-After` delay, and 5xx responses and connection errors
+/// with exponential backoff. `POST` requests create resources, so they are
+/// only retried when they cannot have been processed: on `429`, or when the
+/// connection could not be established. A 5xx or timeout after a `POST` was
+/// sent is returned rather than risking a duplicate user or group.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -48,6 +173,15 @@ pub struct AsyncScimClient {
 
     /// HTTP client
     client: reqwest::Client,
+
+    /// Headers added to every request
+    headers: HashMap<String, String>,
+
+    /// Maximum number of retries per request
+    max_retries: u32,
+
+    /// Delay between retries of 5xx responses and connection errors
+    retry_backoff: Backoff,
 }
 
 impl AsyncScimClient {
@@ -62,6 +196,9 @@ impl AsyncScimClient {
             token: token.into(),
             base_url: SCIM_BASE_URL.to_string(),
             client,
+            headers: HashMap::new(),
+            max_retries: DEFAULT_SCIM_MAX_RETRIES,
+            retry_backoff: Backoff::default(),
         }
     }
 
@@ -71,6 +208,61 @@ impl AsyncScimClient {
         self
     }
 
+    /// Uses the SCIM host of the given Slack environment.
+    pub fn with_environment(self, environment: &SlackEnvironment) -> Self {
+        self.with_base_url(environment.scim_url())
+    }
+
+    /// Sets a custom user agent prefix and/or suffix around the SDK's
+    /// default user agent.
+    pub fn user_agent(self, prefix: Option<&str>, suffix: Option<&str>) -> Self {
+        self.header("User-Agent", get_user_agent(prefix, suffix))
+    }
+
+    /// Adds a header to all requests.
+    ///
+    /// `Authorization` and `Content-Type` are set by the client and can't be
+    /// overridden.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        merge_header(&mut self.headers, &key.into(), &value.into());
+        self
+    }
+
+    /// Sets the maximum number of retries per request.
+    ///
+    /// Defaults to [`DEFAULT_SCIM_MAX_RETRIES`]; `0` disables retries.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff between retries.
+    ///
+    /// Defaults to [`Backoff::default`]. Rate-limited requests wait at least
+    /// as long as Slack's `Retry-After` header asks.
+    pub fn with_retry_backoff(mut self, backoff: Backoff) -> Self {
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Creates the handlers deciding the retries of one request.
+    fn retry_handlers(&self) -> Vec<Box<dyn RetryHandler + Send + Sync>> {
+        vec![
+            Box::new(
+                RateLimitErrorHandler::new(self.max_retries)
+                    .with_interval_calculator(self.retry_backoff.clone()),
+            ),
+            Box::new(
+                ServerErrorHandler::new(self.max_retries)
+                    .with_interval_calculator(self.retry_backoff.clone()),
+            ),
+            Box::new(
+                ConnectionErrorHandler::new(self.max_retries)
+                    .with_interval_calculator(self.retry_backoff.clone()),
+            ),
+        ]
+    }
+
     /// Builds headers for SCIM requests.
     fn build_headers(&self) -> Result<HeaderMap> {
         let mut headers = HeaderMap::new();
@@ -80,161 +272,231 @@ impl AsyncScimClient {
 
         headers.insert(AUTHORIZATION, auth_value);
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(&get_user_agent(None, None)).map_err(|e| {
+                SlackError::InvalidInput {
+                    message: e.to_string(),
+                }
+            })?,
+        );
+        apply_default_headers(&mut headers, &self.headers)?;
 
         Ok(headers)
     }
 
-    /// Gets a user by ID.
-    pub async fn get_user(&self, user_id: &str) -> Result<User> {
-        let url = format!("{}Users/{}", self.base_url, user_id);
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to get user: {}", e),
+    /// Sends a request, retrying as described on [`AsyncScimClient`] and
+    /// turning transport failures and non-2xx responses into errors.
+    ///
+    /// Every attempt sends the same serialized body. Error bodies are parsed
+    /// into a [`ScimError`] so callers can inspect the status and Slack's
+    /// error description, e.g. with [`ScimError::is_not_found`].
+    async fn send(&self, request: RequestBuilder, action: &str) -> Result<Response> {
+        let request = request
+            .headers(self.build_headers()?)
+            .build()
+            .map_err(|e| SlackError::http_error(format!("Failed to {}: {}", action, e)))?;
+        let idempotent = request.method() != Method::POST;
+        let mut handlers = self.retry_handlers();
+        let mut state = RetryState::new();
+
+        let response = loop {
+            let attempt = request.try_clone().ok_or_else(|| {
+                SlackError::http_error(format!("Failed to {}: body can't be resent", action))
             })?;
+            let outcome = self.client.execute(attempt).await;
+
+            let (http_response, error) = match &outcome {
+                Ok(response)
+                    if idempotent || response.status() == StatusCode::TOO_MANY_REQUESTS =>
+                {
+                    let http_response = HttpResponse {
+                        status_code: response.status().as_u16(),
+                        headers: response
+                            .headers()
+                            .iter()
+                            .filter_map(|(k, v)| {
+                                v.to_str()
+                                    .ok()
+                                    .map(|v| (k.as_str().to_string(), v.to_string()))
+                            })
+                            .collect(),
+                        body: Vec::new(),
+                    };
+                    (Some(http_response), None)
+                }
+                // Only a refused connection proves a POST was never received
+                Err(e) if idempotent || e.is_connect() => (None, Some(describe_error(e))),
+                _ => (None, None),
+            };
+
+            if let Some(handler) = handlers
+                .iter_mut()
+                .find(|h| h.can_retry(&state, http_response.as_ref(), error.as_deref()))
+            {
+                handler.prepare_for_next_attempt(&mut state);
+                if let Some(error) = error {
+                    state.set_last_error(error);
+                }
+
+                let retry_after = http_response
+                    .as_ref()
+                    .filter(|r| r.status_code == StatusCode::TOO_MANY_REQUESTS.as_u16())
+                    .and_then(|r| r.get_header(RETRY_AFTER))
+                    .and_then(|v| v.trim().parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or_default();
+                let wait = state.duration_until_next_attempt().max(retry_after);
+                log_scim_retry(action, wait, state.current_attempt);
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+
+            break outcome
+                .map_err(|e| SlackError::http_error(format!("Failed to {}: {}", action, e)))?;
+        };
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
+            let request_id = response
+                .headers()
+                .get(X_SLACK_REQ_ID)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string);
             let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+            let mut error = ScimError::from_response(status, &body);
+            error.request_id = request_id;
+            return Err(error.into());
+        }
+
+        Ok(response)
+    }
+
+    /// Parses a successful response body, returning `None` for
+    /// `204 No Content` or an empty body.
+    async fn parse_optional<T: DeserializeOwned>(
+        response: Response,
+        what: &str,
+    ) -> Result<Option<T>> {
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
         }
 
-        let user: User = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse user response: {}", e),
+        let body = response.bytes().await.map_err(|e| {
+            SlackError::http_error(format!("Failed to read {} response: {}", what, e))
         })?;
+        if body.iter().all(u8::is_ascii_whitespace) {
+            return Ok(None);
+        }
 
-        Ok(user)
+        serde_json::from_slice(&body).map(Some).map_err(|e| {
+            SlackError::http_error(format!("Failed to parse {} response: {}", what, e))
+        })
     }
 
-    /// Creates a new user.
-    pub async fn create_user(&self, user: &User) -> Result<User> {
-        let url = format!("{}Users", self.base_url);
-        let headers = self.build_headers()?;
+    /// Parses a successful response body that must contain a resource.
+    async fn parse<T: DeserializeOwned>(response: Response, what: &str) -> Result<T> {
+        Self::parse_optional(response, what).await?.ok_or_else(|| {
+            SlackError::http_error(format!("Failed to parse {} response: empty body", what))
+        })
+    }
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(user)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to create user: {}", e),
-            })?;
+    /// Builds a list URL with the optional SCIM filter and pagination params.
+    fn search_url(
+        &self,
+        resource: &str,
+        filter: Option<&str>,
+        start_index: Option<u32>,
+        count: Option<u32>,
+    ) -> String {
+        let mut url = format!("{}{}", self.base_url, resource);
+        let mut params = Vec::new();
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+        if let Some(f) = filter {
+            params.push(format!("filter={}", urlencoding::encode(f)));
+        }
+        if let Some(si) = start_index {
+            params.push(format!("startIndex={}", si));
+        }
+        if let Some(c) = count {
+            params.push(format!("count={}", c));
         }
 
-        let created_user: User = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse user response: {}", e),
-        })?;
+        if !params.is_empty() {
+            url.push('?');
+            url.push_str(&params.join("&"));
+        }
 
-        Ok(created_user)
+        url
     }
 
-    /// Updates a user.
-    pub async fn update_user(&self, user_id: &str, user: &User) -> Result<User> {
+    /// Gets a user by ID.
+    pub async fn get_user(&self, user_id: &str) -> Result<User> {
         let url = format!("{}Users/{}", self.base_url, user_id);
-        let headers = self.build_headers()?;
+        let response = self.send(self.client.get(&url), "get user").await?;
+        Self::parse(response, "user").await
+    }
 
+    /// Creates a new user.
+    ///
+    /// If the response body omits the `id`, it is taken from the last
+    /// segment of the `Location` header.
+    pub async fn create_user(&self, user: &User) -> Result<User> {
+        let url = format!("{}Users", self.base_url);
         let response = self
-            .client
-            .put(&url)
-            .headers(headers)
-            .json(user)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to update user: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+            .send(self.client.post(&url).json(user), "create user")
+            .await?;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+
+        let mut created: User = Self::parse(response, "user").await?;
+        if created.id.is_none() {
+            created.id = location
+                .as_deref()
+                .and_then(|location| location.trim_end_matches('/').rsplit('/').next())
+                .filter(|id| !id.is_empty())
+                .map(str::to_string);
         }
+        Ok(created)
+    }
 
-        let updated_user: User = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse user response: {}", e),
-        })?;
+    /// Validates a [`UserBuilder`] and creates the resulting user.
+    ///
+    /// Fails with `SlackError::Validation` before any request is made if the
+    /// builder is missing required fields.
+    pub async fn create_user_from(&self, builder: UserBuilder) -> Result<User> {
+        let user = builder.build()?;
+        self.create_user(&user).await
+    }
 
-        Ok(updated_user)
+    /// Updates a user.
+    pub async fn update_user(&self, user_id: &str, user: &User) -> Result<User> {
+        let url = format!("{}Users/{}", self.base_url, user_id);
+        let response = self
+            .send(self.client.put(&url).json(user), "update user")
+            .await?;
+        Self::parse(response, "user").await
     }
 
     /// Patches a user with partial updates.
     pub async fn patch_user(&self, user_id: &str, patch: &PatchRequest) -> Result<User> {
         let url = format!("{}Users/{}", self.base_url, user_id);
-        let headers = self.build_headers()?;
-
         let response = self
-            .client
-            .patch(&url)
-            .headers(headers)
-            .json(patch)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to patch user: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
-
-        let patched_user: User = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse user response: {}", e),
-        })?;
-
-        Ok(patched_user)
+            .send(self.client.patch(&url).json(patch), "patch user")
+            .await?;
+        Self::parse(response, "user").await
     }
 
     /// Deletes a user.
+    ///
+    /// Slack deactivates rather than removes the user. `204 No Content` is
+    /// treated as success.
     pub async fn delete_user(&self, user_id: &str) -> Result<()> {
         let url = format!("{}Users/{}", self.base_url, user_id);
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .delete(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to delete user: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
-
+        self.send(self.client.delete(&url), "delete user").await?;
         Ok(())
     }
 
@@ -245,204 +507,265 @@ impl AsyncScimClient {
         start_index: Option<u32>,
         count: Option<u32>,
     ) -> Result<ScimResponse<User>> {
-        let mut url = format!("{}Users", self.base_url);
-        let mut params = Vec::new();
-
-        if let Some(f) = filter {
-            params.push(format!("filter={}", urlencoding::encode(f)));
-        }
-        if let Some(si) = start_index {
-            params.push(format!("startIndex={}", si));
-        }
-        if let Some(c) = count {
-            params.push(format!("count={}", c));
-        }
+        let url = self.search_url("Users", filter, start_index, count);
+        let response = self.send(self.client.get(&url), "search users").await?;
+        Self::parse(response, "search").await
+    }
 
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
+    /// Fetches every user matching `filter`, following `startIndex`
+    /// pagination until all pages are read.
+    pub async fn search_users_all(&self, filter: Option<&str>) -> Result<Vec<User>> {
+        let mut users = Vec::new();
+        let mut start_index = 1;
+
+        loop {
+            let page = self
+                .search_users(filter, Some(start_index), Some(SCIM_PAGE_SIZE))
+                .await?;
+            let total = page.total_results;
+            let resources = page.resources.unwrap_or_default();
+            if resources.is_empty() {
+                break;
+            }
+
+            start_index += resources.len() as u32;
+            users.extend(resources);
+            if total.is_some_and(|total| start_index > total) {
+                break;
+            }
         }
 
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to search users: {}", e),
-            })?;
+        Ok(users)
+    }
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+    /// Reconciles Slack's users with `desired`.
+    ///
+    /// Users are matched on `user_name`, ignoring case. Desired users that
+    /// don't exist are created, existing users whose attributes differ are
+    /// replaced with the desired representation, and active users missing
+    /// from `desired` are deactivated. Attributes left as `None` on a desired
+    /// user are not compared.
+    ///
+    /// With `dry_run` set, no writes are made and the result lists what would
+    /// have changed.
+    ///
+    /// # Errors
+    ///
+    /// Fails only if the current users cannot be listed; per-user failures
+    /// are collected in [`SyncResult::errors`].
+    pub async fn sync_users(&self, desired: &[User], dry_run: bool) -> Result<SyncResult> {
+        let current = self.search_users_all(None).await?;
+        let mut current_by_key: HashMap<String, &User> = current
+            .iter()
+            .filter_map(|user| sync_key(user).map(|key| (key, user)))
+            .collect();
+
+        let mut result = SyncResult::default();
+        let mut seen = HashSet::new();
+
+        for user in desired {
+            let Some(key) = sync_key(user) else {
+                result.errors.push((
+                    user.clone(),
+                    SlackError::Validation("SCIM user requires a non-empty user_name".to_string()),
+                ));
+                continue;
+            };
+            if !seen.insert(key.clone()) {
+                continue;
+            }
+
+            match current_by_key.remove(&key) {
+                None if dry_run => result.created.push(user.clone()),
+                None => match self.create_user(user).await {
+                    Ok(created) => result.created.push(created),
+                    Err(e) => result.errors.push((user.clone(), e)),
+                },
+                Some(existing) if needs_update(existing, user) => {
+                    let Some(id) = existing.id.as_deref() else {
+                        result.errors.push((
+                            user.clone(),
+                            SlackError::Validation(format!("SCIM user {} has no id", key)),
+                        ));
+                        continue;
+                    };
+                    let replacement = User {
+                        id: Some(id.to_string()),
+                        ..user.clone()
+                    };
+                    if dry_run {
+                        result.updated.push(replacement);
+                        continue;
+                    }
+                    match self.update_user(id, &replacement).await {
+                        Ok(updated) => result.updated.push(updated),
+                        Err(e) => result.errors.push((replacement, e)),
+                    }
+                }
+                Some(_) => {}
+            }
         }
 
-        let scim_response: ScimResponse<User> =
-            response.json().await.map_err(|e| SlackError::HttpError {
-                message: format!("Failed to parse search response: {}", e),
-            })?;
+        let mut removed: Vec<&User> = current_by_key
+            .into_values()
+            .filter(|user| user.active != Some(false))
+            .collect();
+        removed.sort_by(|a, b| a.user_name.cmp(&b.user_name));
+
+        for user in removed {
+            let Some(id) = user.id.as_deref() else {
+                continue;
+            };
+            if dry_run {
+                result.deactivated.push(user.clone());
+                continue;
+            }
+            match self.delete_user(id).await {
+                Ok(()) => result.deactivated.push(User {
+                    active: Some(false),
+                    ..user.clone()
+                }),
+                Err(e) => result.errors.push((user.clone(), e)),
+            }
+        }
 
-        Ok(scim_response)
+        Ok(result)
     }
 
     /// Gets a group by ID.
     pub async fn get_group(&self, group_id: &str) -> Result<Group> {
         let url = format!("{}Groups/{}", self.base_url, group_id);
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to get group: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
-
-        let group: Group = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse group response: {}", e),
-        })?;
-
-        Ok(group)
+        let response = self.send(self.client.get(&url), "get group").await?;
+        Self::parse(response, "group").await
     }
 
     /// Creates a new group.
     pub async fn create_group(&self, group: &Group) -> Result<Group> {
         let url = format!("{}Groups", self.base_url);
-        let headers = self.build_headers()?;
-
         let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(group)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to create group: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
-
-        let created_group: Group = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse group response: {}", e),
-        })?;
+            .send(self.client.post(&url).json(group), "create group")
+            .await?;
+        Self::parse(response, "group").await
+    }
 
-        Ok(created_group)
+    /// Validates a [`GroupBuilder`] and creates the resulting group.
+    ///
+    /// Fails with `SlackError::Validation` before any request is made if the
+    /// builder is missing required fields.
+    pub async fn create_group_from(&self, builder: GroupBuilder) -> Result<Group> {
+        let group = builder.build()?;
+        self.create_group(&group).await
     }
 
     /// Updates a group.
     pub async fn update_group(&self, group_id: &str, group: &Group) -> Result<Group> {
         let url = format!("{}Groups/{}", self.base_url, group_id);
-        let headers = self.build_headers()?;
-
         let response = self
-            .client
-            .put(&url)
-            .headers(headers)
-            .json(group)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to update group: {}", e),
-            })?;
+            .send(self.client.put(&url).json(group), "update group")
+            .await?;
+        Self::parse(response, "group").await
+    }
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+    /// Patches a group with partial updates and returns the updated group.
+    ///
+    /// Slack answers group PATCH requests with `204 No Content`, in which
+    /// case the group is fetched again; use
+    /// [`patch_group_returning`](Self::patch_group_returning) to skip that
+    /// request.
+    pub async fn patch_group(&self, group_id: &str, patch: &PatchRequest) -> Result<Group> {
+        match self.patch_group_returning(group_id, patch).await? {
+            Some(group) => Ok(group),
+            None => self.get_group(group_id).await,
         }
-
-        let updated_group: Group = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse group response: {}", e),
-        })?;
-
-        Ok(updated_group)
     }
 
-    /// Patches a group with partial updates.
-    pub async fn patch_group(&self, group_id: &str, patch: &PatchRequest) -> Result<Group> {
+    /// Patches a group with partial updates, returning the group only if
+    /// Slack sent it back.
+    ///
+    /// `None` is returned for `204 No Content`, which is how Slack usually
+    /// answers group PATCH requests.
+    pub async fn patch_group_returning(
+        &self,
+        group_id: &str,
+        patch: &PatchRequest,
+    ) -> Result<Option<Group>> {
         let url = format!("{}Groups/{}", self.base_url, group_id);
-        let headers = self.build_headers()?;
-
         let response = self
-            .client
-            .patch(&url)
-            .headers(headers)
-            .json(patch)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to patch group: {}", e),
-            })?;
+            .send(self.client.patch(&url).json(patch), "patch group")
+            .await?;
+        Self::parse_optional(response, "group").await
+    }
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
+    /// Adds users to a group and returns the updated group.
+    ///
+    /// Sends one `add` PATCH per [`MAX_MEMBERS_PER_PATCH`] users, so the
+    /// rest of the group is never re-sent. If Slack answers the last PATCH
+    /// with `204 No Content`, the group is fetched again.
+    pub async fn add_group_members(&self, group_id: &str, user_ids: &[&str]) -> Result<Group> {
+        self.patch_group_members(group_id, user_ids, false).await
+    }
 
-        let patched_group: Group = response.json().await.map_err(|e| SlackError::HttpError {
-            message: format!("Failed to parse group response: {}", e),
-        })?;
+    /// Removes users from a group and returns the updated group.
+    ///
+    /// Each user is removed with a `members[value eq "..."]` path filter,
+    /// chunked like [`add_group_members`](Self::add_group_members).
+    pub async fn remove_group_members(&self, group_id: &str, user_ids: &[&str]) -> Result<Group> {
+        self.patch_group_members(group_id, user_ids, true).await
+    }
 
-        Ok(patched_group)
+    /// Brings a group's membership from `current` to `desired` and returns
+    /// the updated group.
+    ///
+    /// Only the difference computed by [`diff_members`] is sent: additions
+    /// first, then removals. If nothing changed, the group is fetched without
+    /// patching it.
+    pub async fn update_group_members(
+        &self,
+        group_id: &str,
+        current: &[GroupMember],
+        desired: &[GroupMember],
+    ) -> Result<Group> {
+        let (to_add, to_remove) = diff_members(current, desired);
+        let to_add: Vec<&str> = to_add.iter().map(|m| m.value.as_str()).collect();
+        let to_remove: Vec<&str> = to_remove.iter().map(|m| m.value.as_str()).collect();
+
+        let mut patches = member_patches(&to_add, false, MAX_MEMBERS_PER_PATCH);
+        patches.extend(member_patches(&to_remove, true, MAX_MEMBERS_PER_PATCH));
+        self.apply_group_patches(group_id, patches).await
     }
 
-    /// Deletes a group.
-    pub async fn delete_group(&self, group_id: &str) -> Result<()> {
-        let url = format!("{}Groups/{}", self.base_url, group_id);
-        let headers = self.build_headers()?;
+    async fn patch_group_members(
+        &self,
+        group_id: &str,
+        user_ids: &[&str],
+        remove: bool,
+    ) -> Result<Group> {
+        let patches = member_patches(user_ids, remove, MAX_MEMBERS_PER_PATCH);
+        self.apply_group_patches(group_id, patches).await
+    }
 
-        let response = self
-            .client
-            .delete(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to delete group: {}", e),
-            })?;
+    /// Sends `patches` in order and returns the resulting group, fetching it
+    /// when the last response has no body.
+    async fn apply_group_patches(
+        &self,
+        group_id: &str,
+        patches: Vec<PatchRequest>,
+    ) -> Result<Group> {
+        let mut updated = None;
+        for patch in &patches {
+            updated = self.patch_group_returning(group_id, patch).await?;
+        }
 
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
+        match updated {
+            Some(group) => Ok(group),
+            None => self.get_group(group_id).await,
         }
+    }
 
+    /// Deletes a group. `204 No Content` is treated as success.
+    pub async fn delete_group(&self, group_id: &str) -> Result<()> {
+        let url = format!("{}Groups/{}", self.base_url, group_id);
+        self.send(self.client.delete(&url), "delete group").await?;
         Ok(())
     }
 
@@ -453,51 +776,9 @@ impl AsyncScimClient {
         start_index: Option<u32>,
         count: Option<u32>,
     ) -> Result<ScimResponse<Group>> {
-        let mut url = format!("{}Groups", self.base_url);
-        let mut params = Vec::new();
-
-        if let Some(f) = filter {
-            params.push(format!("filter={}", urlencoding::encode(f)));
-        }
-        if let Some(si) = start_index {
-            params.push(format!("startIndex={}", si));
-        }
-        if let Some(c) = count {
-            params.push(format!("count={}", c));
-        }
-
-        if !params.is_empty() {
-            url.push('?');
-            url.push_str(&params.join("&"));
-        }
-
-        let headers = self.build_headers()?;
-
-        let response = self
-            .client
-            .get(&url)
-            .headers(headers)
-            .send()
-            .await
-            .map_err(|e| SlackError::HttpError {
-                message: format!("Failed to search groups: {}", e),
-            })?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let body = response.text().await.unwrap_or_default();
-            return Err(SlackError::ApiError {
-                message: format!("SCIM API error ({}): {}", status, body),
-                response: serde_json::from_str(&body).unwrap_or(Value::Null),
-            });
-        }
-
-        let scim_response: ScimResponse<Group> =
-            response.json().await.map_err(|e| SlackError::HttpError {
-                message: format!("Failed to parse search response: {}", e),
-            })?;
-
-        Ok(scim_response)
+        let url = self.search_url("Groups", filter, start_index, count);
+        let response = self.send(self.client.get(&url), "search groups").await?;
+        Self::parse(response, "search").await
     }
 }
 
@@ -548,6 +829,41 @@ impl ScimClient {
         self
     }
 
+    /// Uses the SCIM host of the given Slack environment.
+    pub fn with_environment(mut self, environment: &SlackEnvironment) -> Self {
+        self.inner = self.inner.with_environment(environment);
+        self
+    }
+
+    /// Sets a custom user agent prefix and/or suffix around the SDK's
+    /// default user agent.
+    pub fn user_agent(mut self, prefix: Option<&str>, suffix: Option<&str>) -> Self {
+        self.inner = self.inner.user_agent(prefix, suffix);
+        self
+    }
+
+    /// Adds a header to all requests.
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inner = self.inner.header(key, value);
+        self
+    }
+
+    /// Sets the maximum number of retries per request.
+    ///
+    /// See [`AsyncScimClient::with_max_retries`].
+    pub fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.inner = self.inner.with_max_retries(max_retries);
+        self
+    }
+
+    /// Sets the backoff between retries.
+    ///
+    /// See [`AsyncScimClient::with_retry_backoff`].
+    pub fn with_retry_backoff(mut self, backoff: Backoff) -> Self {
+        self.inner = self.inner.with_retry_backoff(backoff);
+        self
+    }
+
     /// Gets a user by ID.
     pub fn get_user(&self, user_id: &str) -> Result<User> {
         self.runtime.block_on(self.inner.get_user(user_id))
@@ -558,6 +874,11 @@ impl ScimClient {
         self.runtime.block_on(self.inner.create_user(user))
     }
 
+    /// Validates a [`UserBuilder`] and creates the resulting user.
+    pub fn create_user_from(&self, builder: UserBuilder) -> Result<User> {
+        self.runtime.block_on(self.inner.create_user_from(builder))
+    }
+
     /// Updates a user.
     pub fn update_user(&self, user_id: &str, user: &User) -> Result<User> {
         self.runtime.block_on(self.inner.update_user(user_id, user))
@@ -568,7 +889,7 @@ impl ScimClient {
         self.runtime.block_on(self.inner.patch_user(user_id, patch))
     }
 
-    /// Deletes a user.
+    /// Deletes a user. `204 No Content` is treated as success.
     pub fn delete_user(&self, user_id: &str) -> Result<()> {
         self.runtime.block_on(self.inner.delete_user(user_id))
     }
@@ -584,6 +905,17 @@ impl ScimClient {
             .block_on(self.inner.search_users(filter, start_index, count))
     }
 
+    /// Fetches every user matching `filter`.
+    pub fn search_users_all(&self, filter: Option<&str>) -> Result<Vec<User>> {
+        self.runtime.block_on(self.inner.search_users_all(filter))
+    }
+
+    /// Reconciles Slack's users with `desired`.
+    pub fn sync_users(&self, desired: &[User], dry_run: bool) -> Result<SyncResult> {
+        self.runtime
+            .block_on(self.inner.sync_users(desired, dry_run))
+    }
+
     /// Gets a group by ID.
     pub fn get_group(&self, group_id: &str) -> Result<Group> {
         self.runtime.block_on(self.inner.get_group(group_id))
@@ -594,19 +926,59 @@ impl ScimClient {
         self.runtime.block_on(self.inner.create_group(group))
     }
 
+    /// Validates a [`GroupBuilder`] and creates the resulting group.
+    pub fn create_group_from(&self, builder: GroupBuilder) -> Result<Group> {
+        self.runtime.block_on(self.inner.create_group_from(builder))
+    }
+
     /// Updates a group.
     pub fn update_group(&self, group_id: &str, group: &Group) -> Result<Group> {
         self.runtime
             .block_on(self.inner.update_group(group_id, group))
     }
 
-    /// Patches a group with partial updates.
+    /// Patches a group with partial updates and returns the updated group.
     pub fn patch_group(&self, group_id: &str, patch: &PatchRequest) -> Result<Group> {
         self.runtime
             .block_on(self.inner.patch_group(group_id, patch))
     }
 
-    /// Deletes a group.
+    /// Patches a group with partial updates.
+    ///
+    /// Returns `None` when Slack answers with `204 No Content`.
+    pub fn patch_group_returning(
+        &self,
+        group_id: &str,
+        patch: &PatchRequest,
+    ) -> Result<Option<Group>> {
+        self.runtime
+            .block_on(self.inner.patch_group_returning(group_id, patch))
+    }
+
+    /// Adds users to a group and returns the updated group.
+    pub fn add_group_members(&self, group_id: &str, user_ids: &[&str]) -> Result<Group> {
+        self.runtime
+            .block_on(self.inner.add_group_members(group_id, user_ids))
+    }
+
+    /// Removes users from a group and returns the updated group.
+    pub fn remove_group_members(&self, group_id: &str, user_ids: &[&str]) -> Result<Group> {
+        self.runtime
+            .block_on(self.inner.remove_group_members(group_id, user_ids))
+    }
+
+    /// Brings a group's membership from `current` to `desired`.
+    pub fn update_group_members(
+        &self,
+        group_id: &str,
+        current: &[GroupMember],
+        desired: &[GroupMember],
+    ) -> Result<Group> {
+        self.runtime
+            .block_on(self.inner.update_group_members(group_id, current, desired))
+    }
+
+    /// Deletes a group. `204 No Content` is treated as success.
     pub fn delete_group(&self, group_id: &str) -> Result<()> {
         self.runtime.block_on(self.inner.delete_group(group_id))
     }
@@ -671,4 +1043,787 @@ mod tests {
         assert_eq!(client1.token, client2.token);
         assert_eq!(client1.base_url, client2.base_url);
     }
+
+    mod http {
+        use super::*;
+        use serde_json::json;
+        use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        fn client_for(server: &MockServer) -> AsyncScimClient {
+            AsyncScimClient::new("xoxp-test").with_base_url(format!("{}/", server.uri()))
+        }
+
+        fn replace_name() -> PatchRequest {
+            PatchRequest::new(vec![PatchOperation {
+                op: "replace".to_string(),
+                path: Some("displayName".to_string()),
+                value: Some(json!("Platform")),
+            }])
+        }
+
+        #[tokio::test]
+        async fn test_user_agent_and_headers() {
+            let server = MockServer::start().await;
+            let user_agent = get_user_agent(None, Some("billing/2.1"));
+            Mock::given(method("DELETE"))
+                .and(path("/Users/U1"))
+                .and(header("user-agent", user_agent.as_str()))
+                .and(header("x-request-source", "billing"))
+                .and(header("authorization", "Bearer xoxp-test"))
+                .and(header("content-type", "application/json"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let client = client_for(&server)
+                .user_agent(None, Some("billing/2.1"))
+                .header("X-Request-Source", "billing")
+                .header("content-type", "text/plain");
+
+            client.delete_user("U1").await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_delete_and_patch_accept_no_content() {
+            let server = MockServer::start().await;
+            Mock::given(method("DELETE"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(204))
+                .mount(&server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path("/Groups/S1"))
+                .respond_with(ResponseTemplate::new(204))
+                .mount(&server)
+                .await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .and(body_partial_json(
+                    json!({"Operations": [{"op": "replace"}]}),
+                ))
+                .respond_with(ResponseTemplate::new(204))
+                .mount(&server)
+                .await;
+
+            let client = client_for(&server);
+            client.delete_user("U1").await.unwrap();
+            client.delete_group("S1").await.unwrap();
+            assert_eq!(
+                client
+                    .patch_group_returning("S1", &replace_name())
+                    .await
+                    .unwrap(),
+                None
+            );
+        }
+
+        #[tokio::test]
+        async fn test_patch_group_fetches_group_after_no_content() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Groups/S1"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({"id": "S1", "displayName": "Platform"})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let group = client_for(&server)
+                .patch_group("S1", &replace_name())
+                .await
+                .unwrap();
+            assert_eq!(group.display_name.as_deref(), Some("Platform"));
+        }
+
+        #[tokio::test]
+        async fn test_patch_group_returns_body_when_present() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({"id": "S1", "displayName": "Platform"})),
+                )
+                .mount(&server)
+                .await;
+
+            let group = client_for(&server)
+                .patch_group_returning("S1", &replace_name())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(group.display_name.as_deref(), Some("Platform"));
+        }
+
+        #[tokio::test]
+        async fn test_not_found_maps_to_scim_error() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/Users/U404"))
+                .respond_with(ResponseTemplate::new(404).set_body_json(
+                    json!({"Errors": {"description": "No User Found", "code": 404}}),
+                ))
+                .mount(&server)
+                .await;
+
+            match client_for(&server).get_user("U404").await {
+                Err(SlackError::Scim(error)) => {
+                    assert_eq!(error.http_status, 404);
+                    assert_eq!(error.code, Some(404));
+                    assert_eq!(error.message(), "No User Found");
+                    assert!(error.is_not_found());
+                }
+                other => panic!("expected SCIM error, got {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_scim2_error_schema_maps_to_scim_error() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/Users/U404"))
+                .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:Error"],
+                    "detail": "Resource U404 not found",
+                    "status": "404"
+                })))
+                .mount(&server)
+                .await;
+
+            match client_for(&server).get_user("U404").await {
+                Err(SlackError::Scim(error)) => {
+                    assert!(error.is_not_found());
+                    assert_eq!(error.message(), "Resource U404 not found");
+                }
+                other => panic!("expected Scim, got {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_with_environment_uses_scim_host() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/scim/v1/Users/U1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "schemas": ["urn:scim:schemas:core:1.0"],
+                    "id": "U1",
+                    "userName": "ada"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+            let env = SlackEnvironment::Custom {
+                web: "https://slack.invalid/api/".to_string(),
+                scim: format!("{}/scim/v1/", server.uri()),
+                audit: "https://slack.invalid/audit/v1/".to_string(),
+                hooks: "https://slack.invalid/".to_string(),
+            };
+
+            let client = AsyncScimClient::new("xoxp-test").with_environment(&env);
+            assert_eq!(
+                client.get_user("U1").await.unwrap().id.as_deref(),
+                Some("U1")
+            );
+
+            let gov = AsyncScimClient::new("xoxp-test").with_environment(&SlackEnvironment::Gov);
+            assert_eq!(gov.base_url, "https://api.slack-gov.com/scim/v1/");
+        }
+
+        #[tokio::test]
+        async fn test_error_keeps_request_id() {
+            let server = MockServer::start().await;
+            Mock::given(method("DELETE"))
+                .and(path("/Users/U1"))
+                .respond_with(
+                    ResponseTemplate::new(403)
+                        .insert_header("x-slack-req-id", "req-scim-1")
+                        .set_body_json(
+                            json!({"Errors": {"description": "no_permission", "code": 403}}),
+                        ),
+                )
+                .mount(&server)
+                .await;
+
+            let err = client_for(&server).delete_user("U1").await.unwrap_err();
+            assert_eq!(err.slack_request_id(), Some("req-scim-1"));
+            assert_eq!(err.http_status(), Some(403));
+            assert!(
+                err.to_string().contains("(request id: req-scim-1)"),
+                "{}",
+                err
+            );
+        }
+
+        #[tokio::test]
+        async fn test_create_user_reads_id_from_location() {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .and(header("authorization", "Bearer xoxp-test"))
+                .and(body_partial_json(json!({"userName": "jane"})))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .insert_header("Location", "https://api.slack.com/scim/v1/Users/W123")
+                        .set_body_json(json!({"userName": "jane", "active": true})),
+                )
+                .mount(&server)
+                .await;
+
+            let user = client_for(&server)
+                .create_user_from(
+                    UserBuilder::new()
+                        .username("jane")
+                        .email("jane@example.com"),
+                )
+                .await
+                .unwrap();
+            assert_eq!(user.id.as_deref(), Some("W123"));
+            assert_eq!(user.active, Some(true));
+        }
+
+        #[tokio::test]
+        async fn test_patch_user_body() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Users/W123"))
+                .and(body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [{"op": "replace", "path": "active", "value": false}]
+                })))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(json!({"id": "W123", "active": false})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let patch = PatchRequest::new(vec![PatchOperation {
+                op: "replace".to_string(),
+                path: Some("active".to_string()),
+                value: Some(json!(false)),
+            }]);
+            let user = client_for(&server)
+                .patch_user("W123", &patch)
+                .await
+                .unwrap();
+            assert_eq!(user.active, Some(false));
+        }
+
+        #[tokio::test]
+        async fn test_delete_user_sends_delete() {
+            let server = MockServer::start().await;
+            Mock::given(method("DELETE"))
+                .and(path("/Users/W123"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            client_for(&server).delete_user("W123").await.unwrap();
+        }
+
+        #[tokio::test]
+        async fn test_search_users_filter_query() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/Users"))
+                .and(query_param("filter", r#"userName eq "jane""#))
+                .and(query_param("count", "10"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "totalResults": 1,
+                    "Resources": [{"id": "W123", "userName": "jane"}]
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let response = client_for(&server)
+                .search_users(Some(r#"userName eq "jane""#), None, Some(10))
+                .await
+                .unwrap();
+            assert_eq!(response.total_results, Some(1));
+            assert_eq!(response.resources.unwrap()[0].id.as_deref(), Some("W123"));
+        }
+
+        #[tokio::test]
+        async fn test_search_users_two_pages() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/Users"))
+                .and(query_param("startIndex", "1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "totalResults": 3,
+                    "startIndex": 1,
+                    "itemsPerPage": 2,
+                    "Resources": [{"id": "W1"}, {"id": "W2"}]
+                })))
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Users"))
+                .and(query_param("startIndex", "3"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "totalResults": 3,
+                    "startIndex": 3,
+                    "itemsPerPage": 1,
+                    "Resources": [{"id": "W3"}]
+                })))
+                .mount(&server)
+                .await;
+
+            let client = client_for(&server);
+            let mut ids = Vec::new();
+            let mut start_index = 1;
+            loop {
+                let page = client
+                    .search_users(None, Some(start_index), Some(2))
+                    .await
+                    .unwrap();
+                let resources = page.resources.unwrap_or_default();
+                start_index += resources.len() as u32;
+                ids.extend(resources.into_iter().filter_map(|user| user.id));
+                if start_index > page.total_results.unwrap_or(0) {
+                    break;
+                }
+            }
+            assert_eq!(ids, ["W1", "W2", "W3"]);
+        }
+
+        #[tokio::test]
+        async fn test_conflict_maps_to_scim_error() {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .respond_with(ResponseTemplate::new(409).set_body_json(json!({
+                    "Errors": {"description": "username_taken", "code": 409}
+                })))
+                .mount(&server)
+                .await;
+
+            let builder = UserBuilder::new()
+                .username("taken")
+                .email("taken@example.com");
+            let err = client_for(&server)
+                .create_user_from(builder)
+                .await
+                .unwrap_err();
+            assert!(matches!(&err, SlackError::Scim(e) if e.is_conflict()));
+            assert!(err.to_string().contains("username_taken"));
+        }
+
+        #[test]
+        fn test_member_patches_chunking() {
+            let patches = member_patches(&["U1", "U2", "U3"], false, 2);
+            assert_eq!(patches.len(), 2);
+            assert_eq!(
+                serde_json::to_value(&patches[1]).unwrap(),
+                json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [{"op": "add", "path": "members", "value": [{"value": "U3"}]}]
+                })
+            );
+
+            let patches = member_patches(&["U1", "U2", "U3"], true, 2);
+            assert_eq!(patches.len(), 2);
+            assert_eq!(patches[0].operations.len(), 2);
+            assert!(member_patches(&[], true, 2).is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_add_group_members_patch_body() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .and(body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [{
+                        "op": "add",
+                        "path": "members",
+                        "value": [{"value": "U1"}, {"value": "U2"}]
+                    }]
+                })))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Groups/S1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "id": "S1",
+                    "members": [{"value": "U1"}, {"value": "U2"}]
+                })))
+                .mount(&server)
+                .await;
+
+            let group = client_for(&server)
+                .add_group_members("S1", &["U1", "U2"])
+                .await
+                .unwrap();
+            assert_eq!(group.members.unwrap().len(), 2);
+        }
+
+        #[tokio::test]
+        async fn test_remove_group_members_patch_body() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .and(body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [
+                        {"op": "remove", "path": "members[value eq \"U1\"]"},
+                        {"op": "remove", "path": "members[value eq \"U2\"]"}
+                    ]
+                })))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(json!({"id": "S1", "members": []})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let group = client_for(&server)
+                .remove_group_members("S1", &["U1", "U2"])
+                .await
+                .unwrap();
+            assert_eq!(group.id.as_deref(), Some("S1"));
+        }
+
+        #[tokio::test]
+        async fn test_update_group_members_sends_minimal_patch() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .and(body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [{"op": "add", "path": "members", "value": [{"value": "U3"}]}]
+                })))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("PATCH"))
+                .and(path("/Groups/S1"))
+                .and(body_json(json!({
+                    "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                    "Operations": [{"op": "remove", "path": "members[value eq \"U1\"]"}]
+                })))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Groups/S1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "id": "S1",
+                    "members": [{"value": "U2"}, {"value": "U3"}]
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let member = |id: &str| GroupMember {
+                value: id.to_string(),
+                display: None,
+            };
+            let group = client_for(&server)
+                .update_group_members(
+                    "S1",
+                    &[member("U1"), member("U2")],
+                    &[member("U2"), member("U3")],
+                )
+                .await
+                .unwrap();
+            assert_eq!(group.members.unwrap().len(), 2);
+        }
+
+        #[tokio::test]
+        async fn test_update_group_members_without_changes_skips_patch() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(0)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Groups/S1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "S1"})))
+                .mount(&server)
+                .await;
+
+            let members = [GroupMember {
+                value: "U1".to_string(),
+                display: None,
+            }];
+            let group = client_for(&server)
+                .update_group_members("S1", &members, &members)
+                .await
+                .unwrap();
+            assert_eq!(group.id.as_deref(), Some("S1"));
+        }
+
+        async fn mount_current_users(server: &MockServer) {
+            Mock::given(method("GET"))
+                .and(path("/Users"))
+                .and(query_param("startIndex", "1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "totalResults": 3,
+                    "Resources": [
+                        {"id": "W1", "userName": "alice", "displayName": "Alice", "active": true},
+                        {"id": "W2", "userName": "bob", "displayName": "Bob", "active": true},
+                        {"id": "W3", "userName": "carol", "active": false}
+                    ]
+                })))
+                .mount(server)
+                .await;
+        }
+
+        fn desired_users() -> Vec<User> {
+            vec![
+                User::new()
+                    .with_user_name("Alice")
+                    .with_display_name("Alice Liddell"),
+                User::new().with_user_name("dave").with_display_name("Dave"),
+            ]
+        }
+
+        #[tokio::test]
+        async fn test_sync_users() {
+            let server = MockServer::start().await;
+            mount_current_users(&server).await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .and(body_partial_json(json!({"userName": "dave"})))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(json!({"id": "W4", "userName": "dave"})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .and(path("/Users/W1"))
+                .and(body_partial_json(
+                    json!({"id": "W1", "displayName": "Alice Liddell"}),
+                ))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "id": "W1", "userName": "alice", "displayName": "Alice Liddell"
+                })))
+                .expect(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("DELETE"))
+                .and(path("/Users/W2"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let result = client_for(&server)
+                .sync_users(&desired_users(), false)
+                .await
+                .unwrap();
+
+            assert!(result.is_success());
+            assert_eq!(result.created[0].id.as_deref(), Some("W4"));
+            assert_eq!(result.updated[0].id.as_deref(), Some("W1"));
+            assert_eq!(result.deactivated.len(), 1);
+            assert_eq!(result.deactivated[0].user_name.as_deref(), Some("bob"));
+        }
+
+        #[tokio::test]
+        async fn test_sync_users_dry_run_and_errors() {
+            let server = MockServer::start().await;
+            mount_current_users(&server).await;
+            Mock::given(method("POST"))
+                .respond_with(ResponseTemplate::new(201))
+                .expect(0)
+                .mount(&server)
+                .await;
+            Mock::given(method("PUT"))
+                .respond_with(ResponseTemplate::new(200))
+                .expect(0)
+                .mount(&server)
+                .await;
+            Mock::given(method("DELETE"))
+                .respond_with(ResponseTemplate::new(204))
+                .expect(0)
+                .mount(&server)
+                .await;
+
+            let mut desired = desired_users();
+            desired.push(User::new().with_display_name("No Name"));
+            let result = client_for(&server)
+                .sync_users(&desired, true)
+                .await
+                .unwrap();
+
+            assert_eq!(result.created[0].user_name.as_deref(), Some("dave"));
+            assert_eq!(result.updated[0].id.as_deref(), Some("W1"));
+            assert_eq!(result.deactivated[0].id.as_deref(), Some("W2"));
+            assert_eq!(result.errors.len(), 1);
+            assert!(matches!(result.errors[0].1, SlackError::Validation(_)));
+        }
+
+        #[test]
+        fn test_sync_client_delete_group_no_content() {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            let server = runtime.block_on(async {
+                let server = MockServer::start().await;
+                Mock::given(method("DELETE"))
+                    .and(path("/Groups/S1"))
+                    .respond_with(ResponseTemplate::new(204))
+                    .expect(1)
+                    .mount(&server)
+                    .await;
+                server
+            });
+
+            let client = ScimClient::new("xoxp-test").with_base_url(format!("{}/", server.uri()));
+            client.delete_group("S1").unwrap();
+
+            runtime.block_on(server.verify());
+        }
+
+        fn retrying_client(server: &MockServer) -> AsyncScimClient {
+            client_for(server).with_retry_backoff(Backoff::constant(Duration::from_millis(1)))
+        }
+
+        #[tokio::test]
+        async fn test_rate_limit_honors_retry_after() {
+            let server = MockServer::start().await;
+            Mock::given(method("GET"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("GET"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "U1"})))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let started = std::time::Instant::now();
+            let user = retrying_client(&server).get_user("U1").await.unwrap();
+            assert_eq!(user.id.as_deref(), Some("U1"));
+            assert!(started.elapsed() >= Duration::from_secs(1));
+        }
+
+        #[tokio::test]
+        async fn test_server_error_retry_resends_patch_body() {
+            let server = MockServer::start().await;
+            Mock::given(method("PATCH"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(503))
+                .up_to_n_times(2)
+                .mount(&server)
+                .await;
+            Mock::given(method("PATCH"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({"id": "U1"})))
+                .mount(&server)
+                .await;
+
+            retrying_client(&server)
+                .patch_user("U1", &replace_name())
+                .await
+                .unwrap();
+
+            let requests = server.received_requests().await.unwrap();
+            assert_eq!(requests.len(), 3);
+            let expected = serde_json::to_vec(&replace_name()).unwrap();
+            assert!(requests.iter().all(|request| request.body == expected));
+        }
+
+        #[tokio::test]
+        async fn test_max_retries_limits_attempts() {
+            let server = MockServer::start().await;
+            Mock::given(method("PUT"))
+                .and(path("/Users/U1"))
+                .respond_with(ResponseTemplate::new(500))
+                .expect(2)
+                .mount(&server)
+                .await;
+
+            let result = retrying_client(&server)
+                .with_max_retries(1)
+                .update_user("U1", &User::new().with_user_name("ada"))
+                .await;
+            match result {
+                Err(SlackError::Scim(error)) => assert_eq!(error.http_status, 500),
+                other => panic!("expected a SCIM error, got {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_create_user_not_retried_after_server_error() {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .respond_with(ResponseTemplate::new(503))
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let result = retrying_client(&server)
+                .create_user(&User::new().with_user_name("ada"))
+                .await;
+            assert!(result.is_err());
+        }
+
+        #[tokio::test]
+        async fn test_create_user_retried_when_rate_limited() {
+            let server = MockServer::start().await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+                .up_to_n_times(1)
+                .mount(&server)
+                .await;
+            Mock::given(method("POST"))
+                .and(path("/Users"))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(json!({"id": "W1", "userName": "ada"})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+
+            let user = retrying_client(&server)
+                .create_user(&User::new().with_user_name("ada"))
+                .await
+                .unwrap();
+            assert_eq!(user.id.as_deref(), Some("W1"));
+        }
+
+        #[tokio::test]
+        async fn test_create_user_retried_when_connection_refused() {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}/", listener.local_addr().unwrap());
+            drop(listener);
+
+            let client = AsyncScimClient::new("xoxp-test")
+                .with_base_url(base_url)
+                .with_max_retries(2)
+                .with_retry_backoff(Backoff::constant(Duration::from_millis(100)));
+            let started = std::time::Instant::now();
+            let result = client.create_user(&User::new().with_user_name("ada")).await;
+
+            assert!(matches!(result, Err(SlackError::HttpError { .. })));
+            assert!(started.elapsed() >= Duration::from_millis(200));
+        }
+    }
 }
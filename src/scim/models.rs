@@ -3,6 +3,7 @@
 //! This module provides data models for SCIM (System for Cross-domain Identity Management)
 //! 2.0 protocol, which is used for user and group provisioning in Slack.
 
+use crate::error::{Result, SlackError};
 use serde::{Deserialize, Serialize};
 
 /// SCIM User representation.
@@ -139,6 +140,131 @@ impl Default for User {
     }
 }
 
+/// Builder for a [`User`] that checks required fields before it is sent.
+///
+/// Slack rejects users without a `userName` or a primary email; `build()`
+/// catches both locally.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::scim::models::UserBuilder;
+///
+/// let user = UserBuilder::new()
+///     .username("jane.doe")
+///     .given_name("Jane")
+///     .family_name("Doe")
+///     .email("jane.doe@example.com")
+///     .active(true)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(user.user_name.as_deref(), Some("jane.doe"));
+/// assert_eq!(user.emails.unwrap()[0].primary, Some(true));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UserBuilder {
+    user_name: Option<String>,
+    display_name: Option<String>,
+    given_name: Option<String>,
+    family_name: Option<String>,
+    emails: Vec<UserEmail>,
+    active: Option<bool>,
+}
+
+impl UserBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user name.
+    pub fn username(mut self, user_name: impl Into<String>) -> Self {
+        self.user_name = Some(user_name.into());
+        self
+    }
+
+    /// Sets the display name.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Sets the given (first) name.
+    pub fn given_name(mut self, given_name: impl Into<String>) -> Self {
+        self.given_name = Some(given_name.into());
+        self
+    }
+
+    /// Sets the family (last) name.
+    pub fn family_name(mut self, family_name: impl Into<String>) -> Self {
+        self.family_name = Some(family_name.into());
+        self
+    }
+
+    /// Adds a work email. The first email added is marked primary.
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        let primary = self.emails.is_empty();
+        self.emails.push(UserEmail {
+            value: email.into(),
+            email_type: Some("work".to_string()),
+            primary: Some(primary),
+        });
+        self
+    }
+
+    /// Sets the active status.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// Builds the user.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the user name is missing or empty,
+    /// or no non-empty primary email was added.
+    pub fn build(self) -> Result<User> {
+        let user_name = match self.user_name {
+            Some(user_name) if !user_name.trim().is_empty() => user_name,
+            _ => {
+                return Err(SlackError::Validation(
+                    "SCIM user requires a non-empty user_name".to_string(),
+                ))
+            }
+        };
+
+        let has_primary_email = self
+            .emails
+            .iter()
+            .any(|email| email.primary == Some(true) && !email.value.trim().is_empty());
+        if !has_primary_email {
+            return Err(SlackError::Validation(
+                "SCIM user requires a primary email".to_string(),
+            ));
+        }
+
+        let name = if self.given_name.is_some() || self.family_name.is_some() {
+            Some(UserName {
+                family_name: self.family_name,
+                given_name: self.given_name,
+            })
+        } else {
+            None
+        };
+
+        Ok(User {
+            user_name: Some(user_name),
+            display_name: self.display_name,
+            name,
+            emails: Some(self.emails),
+            active: self.active,
+            ..User::new()
+        })
+    }
+}
+
 /// User metadata.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct UserMeta {
@@ -315,6 +441,84 @@ impl Default for Group {
     }
 }
 
+/// Builder for a [`Group`] that checks required fields before it is sent.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::scim::models::GroupBuilder;
+///
+/// let group = GroupBuilder::new()
+///     .display_name("Engineering")
+///     .member("U123")
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(group.members.unwrap()[0].value, "U123");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GroupBuilder {
+    display_name: Option<String>,
+    members: Vec<GroupMember>,
+}
+
+impl GroupBuilder {
+    /// Creates an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the display name.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Adds a member by user ID.
+    pub fn member(mut self, user_id: impl Into<String>) -> Self {
+        self.members.push(GroupMember {
+            value: user_id.into(),
+            display: None,
+        });
+        self
+    }
+
+    /// Builds the group.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the display name is missing or
+    /// empty, or a member ID is empty.
+    pub fn build(self) -> Result<Group> {
+        let display_name = match self.display_name {
+            Some(display_name) if !display_name.trim().is_empty() => display_name,
+            _ => {
+                return Err(SlackError::Validation(
+                    "SCIM group requires a non-empty display_name".to_string(),
+                ))
+            }
+        };
+
+        if self.members.iter().any(|m| m.value.trim().is_empty()) {
+            return Err(SlackError::Validation(
+                "SCIM group member IDs must not be empty".to_string(),
+            ));
+        }
+
+        let members = if self.members.is_empty() {
+            None
+        } else {
+            Some(self.members)
+        };
+
+        Ok(Group {
+            display_name: Some(display_name),
+            members,
+            ..Group::new()
+        })
+    }
+}
+
 /// Group metadata.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GroupMeta {
@@ -338,6 +542,48 @@ pub struct GroupMember {
     pub display: Option<String>,
 }
 
+/// Computes the members to add and remove to turn `current` into `desired`.
+///
+/// Members are compared by `value` (the user ID). Both lists keep the order
+/// of their input and contain no duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::scim::models::{diff_members, GroupMember};
+///
+/// let member = |id: &str| GroupMember { value: id.to_string(), display: None };
+/// let (to_add, to_remove) = diff_members(&[member("U1"), member("U2")], &[member("U2"), member("U3")]);
+///
+/// assert_eq!(to_add, vec![member("U3")]);
+/// assert_eq!(to_remove, vec![member("U1")]);
+/// ```
+pub fn diff_members(
+    current: &[GroupMember],
+    desired: &[GroupMember],
+) -> (Vec<GroupMember>, Vec<GroupMember>) {
+    use std::collections::HashSet;
+
+    let current_ids: HashSet<&str> = current.iter().map(|m| m.value.as_str()).collect();
+    let desired_ids: HashSet<&str> = desired.iter().map(|m| m.value.as_str()).collect();
+
+    let mut seen = HashSet::new();
+    let to_add = desired
+        .iter()
+        .filter(|m| !current_ids.contains(m.value.as_str()) && seen.insert(m.value.as_str()))
+        .cloned()
+        .collect();
+
+    let mut seen = HashSet::new();
+    let to_remove = current
+        .iter()
+        .filter(|m| !desired_ids.contains(m.value.as_str()) && seen.insert(m.value.as_str()))
+        .cloned()
+        .collect();
+
+    (to_add, to_remove)
+}
+
 /// SCIM response with pagination support.
 ///
 /// Used for list operations that may return multiple pages of results.
@@ -364,8 +610,16 @@ pub struct ScimResponse<T> {
     pub resources: Option<Vec<T>>,
 }
 
+/// Schema URN of SCIM 2.0 error responses.
+pub const SCIM_ERROR_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:Error";
+
 /// SCIM error response.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+///
+/// Slack's SCIM v1 API reports failures as
+/// `{"Errors": {"description": "...", "code": 404}}`, while SCIM 2.0 servers
+/// use top-level `detail`, `status` and `scimType` fields.
+/// [`ScimError::from_response`] accepts both shapes.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct ScimError {
     /// SCIM schemas
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -378,8 +632,140 @@ pub struct ScimError {
     /// HTTP status code
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<String>,
+
+    /// SCIM 2.0 error type, such as `uniqueness` or `invalidValue`
+    #[serde(rename = "scimType", skip_serializing_if = "Option::is_none")]
+    pub scim_type: Option<String>,
+
+    /// Error description from a Slack `Errors` object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Error code from a Slack `Errors` object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<u16>,
+
+    /// HTTP status of the response that carried this error
+    #[serde(skip)]
+    pub http_status: u16,
+
+    /// `x-slack-req-id` of the response that carried this error
+    #[serde(skip)]
+    pub request_id: Option<String>,
 }
 
+impl ScimError {
+    /// Builds an error from a failed response's status and body.
+    ///
+    /// Bodies that are not JSON, or that match neither error shape, are kept
+    /// as the `detail` so nothing the server said is lost.
+    pub fn from_response(http_status: u16, body: &str) -> Self {
+        let mut error = serde_json::from_str::<serde_json::Value>(body)
+            .ok()
+            .map(|value| {
+                let errors = match &value["Errors"] {
+                    serde_json::Value::Array(items) => items.first().cloned().unwrap_or_default(),
+                    other => other.clone(),
+                };
+                let code = errors["code"]
+                    .as_u64()
+                    .or_else(|| errors["code"].as_str().and_then(|code| code.parse().ok()));
+                Self {
+                    schemas: serde_json::from_value(value["schemas"].clone()).ok(),
+                    detail: value["detail"].as_str().map(str::to_string),
+                    status: match &value["status"] {
+                        serde_json::Value::String(status) => Some(status.clone()),
+                        serde_json::Value::Number(status) => Some(status.to_string()),
+                        _ => None,
+                    },
+                    scim_type: value["scimType"].as_str().map(str::to_string),
+                    description: errors["description"].as_str().map(str::to_string),
+                    code: code.and_then(|code| u16::try_from(code).ok()),
+                    http_status,
+                    request_id: None,
+                }
+            })
+            .unwrap_or_default();
+
+        error.http_status = http_status;
+        if error.description.is_none() && error.detail.is_none() && !body.trim().is_empty() {
+            error.detail = Some(body.to_string());
+        }
+        error
+    }
+
+    /// Returns the most specific message available.
+    pub fn message(&self) -> &str {
+        self.description
+            .as_deref()
+            .or(self.detail.as_deref())
+            .unwrap_or("unknown error")
+    }
+
+    /// Returns true if the body declared the SCIM 2.0 error schema.
+    pub fn has_error_schema(&self) -> bool {
+        self.schemas
+            .as_ref()
+            .is_some_and(|schemas| schemas.iter().any(|schema| schema == SCIM_ERROR_SCHEMA))
+    }
+
+    /// Returns the HTTP status of the error: the response's, or else the
+    /// status or code given in the body.
+    pub fn status_code(&self) -> Option<u16> {
+        if self.http_status != 0 {
+            return Some(self.http_status);
+        }
+        self.status
+            .as_deref()
+            .and_then(|status| status.parse().ok())
+            .or(self.code)
+    }
+
+    /// Returns true if the request conflicts with an existing resource,
+    /// e.g. a taken username (`409 Conflict` or `scimType` `uniqueness`).
+    pub fn is_conflict(&self) -> bool {
+        self.status_code() == Some(409) || self.scim_type.as_deref() == Some("uniqueness")
+    }
+
+    /// Returns true if the resource does not exist (`404 Not Found`).
+    pub fn is_not_found(&self) -> bool {
+        self.status_code() == Some(404)
+    }
+
+    /// Returns true if an attribute value was rejected: `scimType`
+    /// `invalidValue`, or a `400 Bad Request` without a `scimType`, which
+    /// is how Slack's v1 API reports bad values.
+    pub fn is_invalid_value(&self) -> bool {
+        match self.scim_type.as_deref() {
+            Some(scim_type) => scim_type == "invalidValue",
+            None => self.status_code() == Some(400),
+        }
+    }
+}
+
+impl std::fmt::Display for ScimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SCIM API error ({}): {}",
+            self.http_status,
+            self.message()
+        )?;
+        if let Some(code) = self.code {
+            write!(f, " (code {})", code)?;
+        }
+        if let Some(scim_type) = &self.scim_type {
+            write!(f, " (scimType {})", scim_type)?;
+        }
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id: {})", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ScimError {}
+
 /// SCIM patch operation.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PatchOperation {
@@ -395,6 +781,68 @@ pub struct PatchOperation {
     pub value: Option<serde_json::Value>,
 }
 
+impl PatchOperation {
+    /// Adds the given users to a group's `members`.
+    pub fn add_members<S: AsRef<str>>(user_ids: &[S]) -> Self {
+        let members: Vec<GroupMember> = user_ids
+            .iter()
+            .map(|id| GroupMember {
+                value: id.as_ref().to_string(),
+                display: None,
+            })
+            .collect();
+        Self {
+            op: "add".to_string(),
+            path: Some("members".to_string()),
+            value: Some(serde_json::to_value(members).unwrap_or_default()),
+        }
+    }
+
+    /// Removes one user from a group's `members`, using a
+    /// `members[value eq "..."]` path filter.
+    pub fn remove_member(user_id: &str) -> Self {
+        let escaped = user_id.replace('\\', "\\\\").replace('"', "\\\"");
+        Self {
+            op: "remove".to_string(),
+            path: Some(format!("members[value eq \"{}\"]", escaped)),
+            value: None,
+        }
+    }
+
+    /// Sets a user's `active` attribute to false.
+    pub fn deactivate_user() -> Self {
+        Self::replace("active", serde_json::Value::Bool(false))
+    }
+
+    /// Sets a user's `active` attribute to true.
+    pub fn activate_user() -> Self {
+        Self::replace("active", serde_json::Value::Bool(true))
+    }
+
+    /// Replaces a user's or group's `displayName`.
+    pub fn set_display_name(name: &str) -> Self {
+        Self::replace("displayName", serde_json::Value::from(name))
+    }
+
+    /// Replaces a user's `emails` with a single primary work address.
+    pub fn set_email(email: &str) -> Self {
+        let emails = vec![UserEmail {
+            value: email.to_string(),
+            email_type: Some("work".to_string()),
+            primary: Some(true),
+        }];
+        Self::replace("emails", serde_json::to_value(emails).unwrap_or_default())
+    }
+
+    fn replace(path: &str, value: serde_json::Value) -> Self {
+        Self {
+            op: "replace".to_string(),
+            path: Some(path.to_string()),
+            value: Some(value),
+        }
+    }
+}
+
 /// SCIM patch request.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PatchRequest {
@@ -414,6 +862,16 @@ impl PatchRequest {
             operations,
         }
     }
+
+    /// Creates a patch request deactivating a user.
+    pub fn for_user_deactivation() -> Self {
+        Self::new(vec![PatchOperation::deactivate_user()])
+    }
+
+    /// Creates a patch request replacing a user's or group's display name.
+    pub fn for_display_name_update(name: &str) -> Self {
+        Self::new(vec![PatchOperation::set_display_name(name)])
+    }
 }
 
 #[cfg(test)]
@@ -440,6 +898,48 @@ mod tests {
         assert_eq!(user.active, Some(true));
     }
 
+    #[test]
+    fn test_user_builder_validates() {
+        let user = UserBuilder::new()
+            .username("jane")
+            .display_name("Jane")
+            .email("jane@example.com")
+            .email("jane@home.example.com")
+            .build()
+            .unwrap();
+        let emails = user.emails.unwrap();
+        assert_eq!(emails[0].primary, Some(true));
+        assert_eq!(emails[1].primary, Some(false));
+        assert!(user.name.is_none());
+        assert!(user.schemas.is_some());
+
+        let missing_name = UserBuilder::new().email("jane@example.com").build();
+        assert!(matches!(missing_name, Err(SlackError::Validation(_))));
+
+        let blank_name = UserBuilder::new()
+            .username("  ")
+            .email("jane@example.com")
+            .build();
+        assert!(blank_name.is_err());
+
+        let missing_email = UserBuilder::new().username("jane").build();
+        assert!(matches!(missing_email, Err(SlackError::Validation(_))));
+    }
+
+    #[test]
+    fn test_group_builder_validates() {
+        let group = GroupBuilder::new().display_name("Ops").build().unwrap();
+        assert_eq!(group.display_name, Some("Ops".to_string()));
+        assert!(group.members.is_none());
+
+        assert!(GroupBuilder::new().member("U1").build().is_err());
+        assert!(GroupBuilder::new()
+            .display_name("Ops")
+            .member("")
+            .build()
+            .is_err());
+    }
+
     #[test]
     fn test_user_serialization() {
         let user = User::new()
@@ -498,6 +998,24 @@ mod tests {
         assert_eq!(group, deserialized);
     }
 
+    #[test]
+    fn test_diff_members() {
+        let member = |id: &str| GroupMember {
+            value: id.to_string(),
+            display: None,
+        };
+
+        let current = vec![member("U1"), member("U2"), member("U3")];
+        let desired = vec![member("U4"), member("U2"), member("U4"), member("U5")];
+        let (to_add, to_remove) = diff_members(&current, &desired);
+        assert_eq!(to_add, vec![member("U4"), member("U5")]);
+        assert_eq!(to_remove, vec![member("U1"), member("U3")]);
+
+        let (to_add, to_remove) = diff_members(&current, &current);
+        assert!(to_add.is_empty());
+        assert!(to_remove.is_empty());
+    }
+
     #[test]
     fn test_scim_response() {
         let response: ScimResponse<User> = ScimResponse {
@@ -514,6 +1032,80 @@ mod tests {
         assert_eq!(response.items_per_page, Some(5));
     }
 
+    #[test]
+    fn test_scim_error_from_slack_body() {
+        let error = ScimError::from_response(
+            409,
+            r#"{"Errors": {"description": "username_taken", "code": 409}}"#,
+        );
+        assert_eq!(error.http_status, 409);
+        assert_eq!(error.code, Some(409));
+        assert_eq!(error.message(), "username_taken");
+        assert_eq!(
+            error.to_string(),
+            "SCIM API error (409): username_taken (code 409)"
+        );
+    }
+
+    #[test]
+    fn test_scim_error_from_other_bodies() {
+        let scim2 = ScimError::from_response(
+            404,
+            r#"{"schemas": ["urn:ietf:params:scim:api:messages:2.0:Error"], "detail": "User not found", "status": "404"}"#,
+        );
+        assert_eq!(scim2.message(), "User not found");
+        assert_eq!(scim2.status, Some("404".to_string()));
+
+        let plain = ScimError::from_response(502, "Bad Gateway");
+        assert_eq!(plain.detail, Some("Bad Gateway".to_string()));
+        assert_eq!(plain.code, None);
+
+        let empty = ScimError::from_response(500, "");
+        assert_eq!(empty.message(), "unknown error");
+    }
+
+    #[test]
+    fn test_scim_error_type_helpers() {
+        let taken = ScimError::from_response(
+            409,
+            r#"{"schemas": ["urn:ietf:params:scim:api:messages:2.0:Error"], "detail": "userName is taken", "status": 409, "scimType": "uniqueness"}"#,
+        );
+        assert!(taken.has_error_schema());
+        assert_eq!(taken.scim_type.as_deref(), Some("uniqueness"));
+        assert!(taken.is_conflict());
+        assert!(!taken.is_not_found());
+        assert!(!taken.is_invalid_value());
+        assert_eq!(
+            taken.to_string(),
+            "SCIM API error (409): userName is taken (scimType uniqueness)"
+        );
+
+        let invalid = ScimError::from_response(
+            400,
+            r#"{"schemas": ["urn:ietf:params:scim:api:messages:2.0:Error"], "detail": "bad email", "status": "400", "scimType": "invalidValue"}"#,
+        );
+        assert!(invalid.is_invalid_value());
+        let syntax = ScimError {
+            scim_type: Some("invalidSyntax".to_string()),
+            ..invalid.clone()
+        };
+        assert!(!syntax.is_invalid_value());
+
+        let slack_invalid = ScimError::from_response(
+            400,
+            r#"{"Errors": {"description": "invalid_email", "code": 400}}"#,
+        );
+        assert!(!slack_invalid.has_error_schema());
+        assert!(slack_invalid.is_invalid_value());
+
+        let parsed: ScimError = serde_json::from_str(
+            r#"{"schemas": ["urn:ietf:params:scim:api:messages:2.0:Error"], "detail": "gone", "status": "404"}"#,
+        )
+        .unwrap();
+        assert_eq!(parsed.http_status, 0);
+        assert!(parsed.is_not_found());
+    }
+
     #[test]
     fn test_patch_operation() {
         let op = PatchOperation {
@@ -526,6 +1118,60 @@ mod tests {
         assert_eq!(op.path, Some("active".to_string()));
     }
 
+    #[test]
+    fn test_member_patch_operations() {
+        let add = PatchOperation::add_members(&["U1", "U2"]);
+        assert_eq!(
+            serde_json::to_value(&add).unwrap(),
+            json!({"op": "add", "path": "members", "value": [{"value": "U1"}, {"value": "U2"}]})
+        );
+
+        let remove = PatchOperation::remove_member("U1");
+        assert_eq!(
+            serde_json::to_value(&remove).unwrap(),
+            json!({"op": "remove", "path": "members[value eq \"U1\"]"})
+        );
+
+        let quoted = PatchOperation::remove_member(r#"a"b"#);
+        assert_eq!(quoted.path.as_deref(), Some(r#"members[value eq "a\"b"]"#));
+    }
+
+    #[test]
+    fn test_user_patch_operations() {
+        assert_eq!(
+            serde_json::to_value(PatchOperation::deactivate_user()).unwrap(),
+            json!({"op": "replace", "path": "active", "value": false})
+        );
+        assert_eq!(
+            serde_json::to_value(PatchOperation::activate_user()).unwrap(),
+            json!({"op": "replace", "path": "active", "value": true})
+        );
+        assert_eq!(
+            serde_json::to_value(PatchOperation::set_display_name("Ada")).unwrap(),
+            json!({"op": "replace", "path": "displayName", "value": "Ada"})
+        );
+        assert_eq!(
+            serde_json::to_value(PatchOperation::set_email("ada@example.com")).unwrap(),
+            json!({
+                "op": "replace",
+                "path": "emails",
+                "value": [{"value": "ada@example.com", "type": "work", "primary": true}]
+            })
+        );
+
+        assert_eq!(
+            PatchRequest::for_user_deactivation(),
+            PatchRequest::new(vec![PatchOperation::deactivate_user()])
+        );
+        assert_eq!(
+            serde_json::to_value(PatchRequest::for_display_name_update("Ada")).unwrap(),
+            json!({
+                "schemas": ["urn:ietf:params:scim:api:messages:2.0:PatchOp"],
+                "Operations": [{"op": "replace", "path": "displayName", "value": "Ada"}]
+            })
+        );
+    }
+
     #[test]
     fn test_patch_request() {
         let operations = vec![PatchOperation {
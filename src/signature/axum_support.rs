@@ -0,0 +1,180 @@
+//! Signature verification for axum requests.
+//!
+//! Available with the `axum` feature.
+
+use super::SignatureVerifier;
+use crate::constants::headers;
+use axum::body::{Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+/// Largest request body buffered for verification. Slack payloads are far
+/// smaller; larger bodies fail with an error instead of being buffered.
+pub const MAX_AXUM_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Buffers the body of an axum request and verifies its Slack signature.
+///
+/// The request is consumed to read the body, so the bytes are returned
+/// alongside the result for the handler to parse.
+///
+/// # Errors
+///
+/// Returns an error if the body can't be read or is larger than
+/// [`MAX_AXUM_BODY_BYTES`]. A missing or wrong signature is not an error;
+/// it yields `false`.
+///
+/// # Example
+///
+/// ```no_run
+/// use axum::body::Body;
+/// use axum::http::{Request, StatusCode};
+/// use slack_rs::payloads::SlashCommandPayload;
+/// use slack_rs::signature::{verify_axum_request, SignatureVerifier};
+///
+/// async fn handle(req: Request<Body>) -> Result<String, StatusCode> {
+///     let verifier = SignatureVerifier::new("your_signing_secret");
+///     let (valid, body) = verify_axum_request(&verifier, req)
+///         .await
+///         .map_err(|_| StatusCode::BAD_REQUEST)?;
+///     if !valid {
+///         return Err(StatusCode::UNAUTHORIZED);
+///     }
+///     let command = SlashCommandPayload::from_form(&String::from_utf8_lossy(&body))
+///         .map_err(|_| StatusCode::BAD_REQUEST)?;
+///     Ok(format!("Running {}", command.command))
+/// }
+/// ```
+pub async fn verify_axum_request(
+    verifier: &SignatureVerifier,
+    req: Request<Body>,
+) -> Result<(bool, Bytes), axum::Error> {
+    let (parts, body) = req.into_parts();
+    let body = axum::body::to_bytes(body, MAX_AXUM_BODY_BYTES).await?;
+    Ok((is_valid_headers(verifier, &parts.headers, &body), body))
+}
+
+/// axum middleware that rejects requests without a valid Slack signature.
+///
+/// Invalid requests get `401 Unauthorized` and unreadable bodies
+/// `400 Bad Request`. Verified requests are passed on with their body
+/// restored, so handlers can use the usual extractors.
+///
+/// # Example
+///
+/// ```no_run
+/// use axum::{middleware, routing::post, Router};
+/// use slack_rs::signature::{verify_slack_signature, SignatureVerifier};
+/// use std::sync::Arc;
+///
+/// async fn events(body: String) -> &'static str {
+///     "ok"
+/// }
+///
+/// let verifier = Arc::new(SignatureVerifier::new("your_signing_secret"));
+/// let app: Router = Router::new()
+///     .route("/slack/events", post(events))
+///     .layer(middleware::from_fn_with_state(verifier, verify_slack_signature));
+/// ```
+pub async fn verify_slack_signature(
+    State(verifier): State<Arc<SignatureVerifier>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let (parts, body) = req.into_parts();
+    let body = match axum::body::to_bytes(body, MAX_AXUM_BODY_BYTES).await {
+        Ok(body) => body,
+        Err(_) => return StatusCode::BAD_REQUEST.into_response(),
+    };
+    if !is_valid_headers(&verifier, &parts.headers, &body) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    next.run(Request::from_parts(parts, Body::from(body))).await
+}
+
+fn is_valid_headers(verifier: &SignatureVerifier, headers: &HeaderMap, body: &[u8]) -> bool {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+    match (
+        header(headers::SLACK_REQUEST_TIMESTAMP),
+        header(headers::SLACK_SIGNATURE),
+    ) {
+        (Some(timestamp), Some(signature)) => verifier.is_valid(body, timestamp, signature),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use axum::{middleware, routing::post, Router};
+    use pretty_assertions::assert_eq;
+    use tower::ServiceExt;
+
+    const TIMESTAMP: &str = "1531420618";
+
+    fn verifier() -> SignatureVerifier {
+        SignatureVerifier::new("8f742231b10e8888abcd99yyyzzz85a5")
+            .with_clock(Arc::new(MockClock::from_unix_secs(1531420618)))
+    }
+
+    fn signed_request(body: &str, signature: &str) -> Request {
+        Request::post("/slack/events")
+            .header(headers::SLACK_REQUEST_TIMESTAMP, TIMESTAMP)
+            .header(headers::SLACK_SIGNATURE, signature)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_verify_axum_request_returns_body() {
+        let verifier = verifier();
+        let signature = verifier.generate_signature(TIMESTAMP, b"text=hi");
+
+        let (valid, body) = verify_axum_request(&verifier, signed_request("text=hi", &signature))
+            .await
+            .unwrap();
+        assert!(valid);
+        assert_eq!(&body[..], b"text=hi");
+
+        let (valid, _) = verify_axum_request(&verifier, signed_request("text=hi", "v0=bad"))
+            .await
+            .unwrap();
+        assert!(!valid);
+
+        let unsigned = Request::post("/").body(Body::from("text=hi")).unwrap();
+        let (valid, _) = verify_axum_request(&verifier, unsigned).await.unwrap();
+        assert!(!valid);
+    }
+
+    #[tokio::test]
+    async fn test_middleware_rejects_invalid_signatures() {
+        let verifier = verifier();
+        let signature = verifier.generate_signature(TIMESTAMP, b"text=hi");
+        let app = Router::new()
+            .route("/slack/events", post(|body: String| async move { body }))
+            .layer(middleware::from_fn_with_state(
+                Arc::new(verifier),
+                verify_slack_signature,
+            ));
+
+        let response = app
+            .clone()
+            .oneshot(signed_request("text=hi", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"text=hi");
+
+        let response = app
+            .oneshot(signed_request("text=bye", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
@@ -20,12 +20,25 @@
 //!     println!("Valid request from Slack!");
 //! }
 //! ```
+//!
+//! With the `axum` feature, [`verify_axum_request`] and the
+//! [`verify_slack_signature`] middleware verify axum requests directly.
+
+#[cfg(feature = "axum")]
+mod axum_support;
+
+#[cfg(feature = "axum")]
+pub use axum_support::{verify_axum_request, verify_slack_signature, MAX_AXUM_BODY_BYTES};
 
+use crate::clock::{self, Clock};
 use crate::constants::{headers, signature, time};
 use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::fmt;
+use std::sync::Arc;
+use std::time::UNIX_EPOCH;
 
 type HmacSha256 = Hmac<Sha256>;
 
@@ -38,6 +51,7 @@ type HmacSha256 = Hmac<Sha256>;
 #[derive(Debug, Clone)]
 pub struct SignatureVerifier {
     signing_secret: String,
+    clock: Arc<dyn Clock>,
 }
 
 impl SignatureVerifier {
@@ -48,9 +62,18 @@ impl SignatureVerifier {
     pub fn new(signing_secret: impl Into<String>) -> Self {
         Self {
             signing_secret: signing_secret.into(),
+            clock: clock::system_clock(),
         }
     }
 
+    /// Sets the clock used to check request timestamps.
+    ///
+    /// Defaults to the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Generates an HMAC-SHA256 signature for a request.
     ///
     /// # Arguments
@@ -87,6 +110,19 @@ impl SignatureVerifier {
     /// # Returns
     /// `true` if the request is valid and not expired
     pub fn is_valid_request(&self, body: &[u8], headers: &HashMap<String, String>) -> bool {
+        self.diagnose(body, headers).is_valid()
+    }
+
+    /// Explains whether a request would pass verification, and if not,
+    /// which check fails.
+    ///
+    /// Useful when setting up an endpoint: pass the body and headers of a
+    /// request that was rejected.
+    ///
+    /// # Arguments
+    /// * `body` - The request body, exactly as received
+    /// * `headers` - The request headers (case-insensitive)
+    pub fn diagnose(&self, body: &[u8], headers: &HashMap<String, String>) -> SignatureDiagnosis {
         // Normalize headers to lowercase
         let normalized: HashMap<String, String> = headers
             .iter()
@@ -95,15 +131,23 @@ impl SignatureVerifier {
 
         let timestamp = match normalized.get(headers::SLACK_REQUEST_TIMESTAMP) {
             Some(ts) => ts.as_str(),
-            None => return false,
+            None => {
+                return SignatureDiagnosis::MissingHeader {
+                    header: headers::SLACK_REQUEST_TIMESTAMP.to_string(),
+                }
+            }
         };
 
         let signature = match normalized.get(headers::SLACK_SIGNATURE) {
             Some(sig) => sig.as_str(),
-            None => return false,
+            None => {
+                return SignatureDiagnosis::MissingHeader {
+                    header: headers::SLACK_SIGNATURE.to_string(),
+                }
+            }
         };
 
-        self.is_valid(body, timestamp, signature)
+        self.check(body, timestamp, signature)
     }
 
     /// Verifies if a request is valid with explicit parameters.
@@ -116,48 +160,111 @@ impl SignatureVerifier {
     /// # Returns
     /// `true` if the signature is valid and not expired
     pub fn is_valid(&self, body: &[u8], timestamp: &str, signature: &str) -> bool {
+        self.check(body, timestamp, signature).is_valid()
+    }
+
+    fn check(&self, body: &[u8], timestamp: &str, signature: &str) -> SignatureDiagnosis {
         // Check timestamp expiration (5-minute window)
         let timestamp_num = match timestamp.parse::<u64>() {
             Ok(ts) => ts,
-            Err(_) => return false,
+            Err(_) => {
+                return SignatureDiagnosis::InvalidTimestamp {
+                    timestamp: timestamp.to_string(),
+                }
+            }
         };
 
-        let now = SystemTime::now()
+        let now = self
+            .clock
+            .now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards")
             .as_secs();
 
-        if now.abs_diff(timestamp_num) > time::MAX_REQUEST_AGE_SECS {
-            return false;
+        let skew_secs = now.abs_diff(timestamp_num);
+        if skew_secs > time::MAX_REQUEST_AGE_SECS {
+            return SignatureDiagnosis::Expired { skew_secs };
+        }
+
+        if !signature.starts_with(signature::SIGNATURE_PREFIX) {
+            return SignatureDiagnosis::MalformedSignature;
         }
 
         // Generate expected signature
         let calculated_signature = self.generate_signature(timestamp, body);
 
         // Use constant-time comparison to prevent timing attacks
-        constant_time_compare(&calculated_signature, signature)
+        if constant_time_compare(&calculated_signature, signature) {
+            SignatureDiagnosis::Valid
+        } else {
+            SignatureDiagnosis::Mismatch
+        }
     }
+}
 
-    /// Verifies a request with a custom clock (for testing).
-    #[cfg(test)]
-    pub fn is_valid_with_clock(
-        &self,
-        body: &[u8],
-        timestamp: &str,
-        signature: &str,
-        current_time: u64,
-    ) -> bool {
-        let timestamp_num = match timestamp.parse::<u64>() {
-            Ok(ts) => ts,
-            Err(_) => return false,
-        };
+/// Outcome of [`SignatureVerifier::diagnose`]: the first check a request
+/// fails, or `Valid`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum SignatureDiagnosis {
+    /// The request passes verification.
+    Valid,
+    /// A required header is absent.
+    MissingHeader {
+        /// Lowercase name of the missing header.
+        header: String,
+    },
+    /// The timestamp header is not a Unix timestamp in seconds.
+    InvalidTimestamp {
+        /// The header's value.
+        timestamp: String,
+    },
+    /// The timestamp is more than five minutes from the local clock, either
+    /// because the request is replayed or the clock is skewed.
+    Expired {
+        /// Seconds between the timestamp and the local clock.
+        skew_secs: u64,
+    },
+    /// The signature header does not start with `v0=`.
+    MalformedSignature,
+    /// The signature does not match the body.
+    Mismatch,
+}
 
-        if current_time.abs_diff(timestamp_num) > time::MAX_REQUEST_AGE_SECS {
-            return false;
-        }
+impl SignatureDiagnosis {
+    /// Returns true for [`SignatureDiagnosis::Valid`].
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+}
 
-        let calculated_signature = self.generate_signature(timestamp, body);
-        constant_time_compare(&calculated_signature, signature)
+impl fmt::Display for SignatureDiagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Valid => f.write_str("signature is valid"),
+            Self::MissingHeader { header } => write!(f, "missing {} header", header),
+            Self::InvalidTimestamp { timestamp } => write!(
+                f,
+                "request timestamp {:?} is not a Unix timestamp in seconds",
+                timestamp
+            ),
+            Self::Expired { skew_secs } => write!(
+                f,
+                "request timestamp is {}s from the local clock (limit {}s); \
+                 the request is stale or the clock is skewed",
+                skew_secs,
+                time::MAX_REQUEST_AGE_SECS
+            ),
+            Self::MalformedSignature => write!(
+                f,
+                "signature does not start with {:?}",
+                signature::SIGNATURE_PREFIX
+            ),
+            Self::Mismatch => f.write_str(
+                "signature does not match the body; check the signing secret \
+                 and that the raw body is verified before it is parsed",
+            ),
+        }
     }
 }
 
@@ -178,6 +285,7 @@ fn constant_time_compare(a: &str, b: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
     use pretty_assertions::assert_eq;
 
     // Test data from Slack documentation
@@ -204,27 +312,21 @@ mod tests {
         assert_eq!(signature, VALID_SIGNATURE);
     }
 
+    fn verifier_at(unix_secs: u64) -> SignatureVerifier {
+        SignatureVerifier::new(SIGNING_SECRET)
+            .with_clock(Arc::new(MockClock::from_unix_secs(unix_secs)))
+    }
+
     #[test]
     fn test_is_valid_request() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
-        let mut headers = HashMap::new();
-        headers.insert(
-            "x-slack-request-timestamp".to_string(),
-            TIMESTAMP.to_string(),
-        );
-        headers.insert("x-slack-signature".to_string(), VALID_SIGNATURE.to_string());
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
 
-        assert!(verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            TIMESTAMP,
-            VALID_SIGNATURE,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(verifier.is_valid(BODY.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
     }
 
     #[test]
     fn test_is_valid_request_with_headers() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
         let mut headers = HashMap::new();
         headers.insert(
             "x-slack-request-timestamp".to_string(),
@@ -232,18 +334,12 @@ mod tests {
         );
         headers.insert("x-slack-signature".to_string(), VALID_SIGNATURE.to_string());
 
-        // Mock the clock by using is_valid_with_clock
-        assert!(verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            TIMESTAMP,
-            VALID_SIGNATURE,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(verifier.is_valid_request(BODY.as_bytes(), &headers));
     }
 
     #[test]
     fn test_is_valid_request_case_insensitive_headers() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
         let mut headers = HashMap::new();
         headers.insert(
             "X-Slack-Request-Timestamp".to_string(),
@@ -251,98 +347,74 @@ mod tests {
         );
         headers.insert("X-Slack-Signature".to_string(), VALID_SIGNATURE.to_string());
 
-        let normalized: HashMap<String, String> = headers
-            .iter()
-            .map(|(k, v)| (k.to_lowercase(), v.clone()))
-            .collect();
+        assert!(verifier.is_valid_request(BODY.as_bytes(), &headers));
+    }
 
-        let timestamp = normalized.get("x-slack-request-timestamp").unwrap();
-        let signature = normalized.get("x-slack-signature").unwrap();
+    #[test]
+    fn test_is_valid_request_missing_headers() {
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
 
-        assert!(verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            timestamp,
-            signature,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(!verifier.is_valid_request(BODY.as_bytes(), &HashMap::new()));
     }
 
     #[test]
     fn test_is_valid_request_invalid_body() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
         let modified_body = format!("{}------", BODY);
 
-        assert!(!verifier.is_valid_with_clock(
-            modified_body.as_bytes(),
-            TIMESTAMP,
-            VALID_SIGNATURE,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(!verifier.is_valid(modified_body.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
     }
 
     #[test]
     fn test_is_valid_request_invalid_signature() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
         let invalid_sig = "v0=invalid";
 
-        assert!(!verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            TIMESTAMP,
-            invalid_sig,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(!verifier.is_valid(BODY.as_bytes(), TIMESTAMP, invalid_sig));
     }
 
     #[test]
     fn test_is_valid_request_expiration() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
-
         // Request is from timestamp 1531420618
         // Current time is 301 seconds later (just over 5 minutes)
-        let expired_time = MOCK_CLOCK_TIME + 301;
-
-        assert!(!verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            TIMESTAMP,
-            VALID_SIGNATURE,
-            expired_time
-        ));
+        let verifier = verifier_at(MOCK_CLOCK_TIME + 301);
+
+        assert!(!verifier.is_valid(BODY.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
     }
 
     #[test]
     fn test_is_valid_request_within_expiration() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
-
         // Request is from timestamp 1531420618
         // Current time is 299 seconds later (just under 5 minutes)
-        let valid_time = MOCK_CLOCK_TIME + 299;
-
-        assert!(verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            TIMESTAMP,
-            VALID_SIGNATURE,
-            valid_time
-        ));
+        let verifier = verifier_at(MOCK_CLOCK_TIME + 299);
+
+        assert!(verifier.is_valid(BODY.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
+    }
+
+    #[test]
+    fn test_is_valid_request_expires_as_clock_advances() {
+        let clock = MockClock::from_unix_secs(MOCK_CLOCK_TIME);
+        let verifier = SignatureVerifier::new(SIGNING_SECRET).with_clock(Arc::new(clock.clone()));
+
+        assert!(verifier.is_valid(BODY.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
+
+        clock.advance(std::time::Duration::from_secs(301));
+        assert!(!verifier.is_valid(BODY.as_bytes(), TIMESTAMP, VALID_SIGNATURE));
     }
 
     #[test]
     fn test_is_valid_request_empty_body() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
         let empty_sig = verifier.generate_signature(TIMESTAMP, b"");
 
-        assert!(verifier.is_valid_with_clock(b"", TIMESTAMP, &empty_sig, MOCK_CLOCK_TIME));
+        assert!(verifier.is_valid(b"", TIMESTAMP, &empty_sig));
     }
 
     #[test]
     fn test_is_valid_invalid_timestamp_format() {
-        let verifier = SignatureVerifier::new(SIGNING_SECRET);
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
 
-        assert!(!verifier.is_valid_with_clock(
-            BODY.as_bytes(),
-            "not-a-number",
-            VALID_SIGNATURE,
-            MOCK_CLOCK_TIME
-        ));
+        assert!(!verifier.is_valid(BODY.as_bytes(), "not-a-number", VALID_SIGNATURE));
     }
 
     #[test]
@@ -379,4 +451,51 @@ mod tests {
 
         assert!(debug_str.contains("SignatureVerifier"));
     }
+
+    #[test]
+    fn test_diagnose_names_failing_check() {
+        let headers = |timestamp: &str, signature: &str| {
+            HashMap::from([
+                (
+                    "X-Slack-Request-Timestamp".to_string(),
+                    timestamp.to_string(),
+                ),
+                ("X-Slack-Signature".to_string(), signature.to_string()),
+            ])
+        };
+        let verifier = verifier_at(MOCK_CLOCK_TIME);
+
+        assert_eq!(
+            verifier.diagnose(BODY.as_bytes(), &headers(TIMESTAMP, VALID_SIGNATURE)),
+            SignatureDiagnosis::Valid
+        );
+        assert_eq!(
+            verifier.diagnose(BODY.as_bytes(), &HashMap::new()),
+            SignatureDiagnosis::MissingHeader {
+                header: "x-slack-request-timestamp".to_string()
+            }
+        );
+        assert_eq!(
+            verifier.diagnose(BODY.as_bytes(), &headers("soon", VALID_SIGNATURE)),
+            SignatureDiagnosis::InvalidTimestamp {
+                timestamp: "soon".to_string()
+            }
+        );
+        assert_eq!(
+            verifier_at(MOCK_CLOCK_TIME + 400)
+                .diagnose(BODY.as_bytes(), &headers(TIMESTAMP, VALID_SIGNATURE)),
+            SignatureDiagnosis::Expired { skew_secs: 400 }
+        );
+        assert_eq!(
+            verifier.diagnose(BODY.as_bytes(), &headers(TIMESTAMP, "a2114d57")),
+            SignatureDiagnosis::MalformedSignature
+        );
+        let mismatch = verifier.diagnose(b"edited", &headers(TIMESTAMP, VALID_SIGNATURE));
+        assert_eq!(mismatch, SignatureDiagnosis::Mismatch);
+        assert!(mismatch.to_string().contains("signing secret"));
+        assert_eq!(
+            serde_json::to_value(SignatureDiagnosis::Expired { skew_secs: 400 }).unwrap(),
+            serde_json::json!({"result": "expired", "skew_secs": 400})
+        );
+    }
 }
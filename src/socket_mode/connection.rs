@@ -8,6 +8,7 @@ use crate::socket_mode::types::{SocketModeRequest, SocketModeResponse};
 use futures::{SinkExt, StreamExt};
 use serde_json::Value;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::net::TcpStream;
 use tokio::sync::Mutex;
 use tokio_tungstenite::{connect_async, tungstenite::Message, MaybeTlsStream, WebSocketStream};
@@ -23,6 +24,9 @@ pub struct SocketModeConnection {
 
     /// Whether the connection is active
     connected: Arc<Mutex<bool>>,
+
+    /// When the latest pong arrived on the current stream
+    last_pong: Arc<std::sync::Mutex<Option<Instant>>>,
 }
 
 impl SocketModeConnection {
@@ -32,17 +36,27 @@ impl SocketModeConnection {
             stream: Arc::new(Mutex::new(None)),
             url: url.into(),
             connected: Arc::new(Mutex::new(false)),
+            last_pong: Arc::new(std::sync::Mutex::new(None)),
         }
     }
 
     /// Connects to the WebSocket server.
     pub async fn connect(&self) -> Result<()> {
-        let (ws_stream, _) = connect_async(&self.url)
+        self.connect_to(&self.url).await
+    }
+
+    /// Connects to the given WebSocket URL, replacing any existing stream.
+    ///
+    /// Socket Mode URLs are single-use, so each reconnect obtains a fresh URL
+    /// from `apps.connections.open` and connects to it with this method.
+    pub async fn connect_to(&self, url: &str) -> Result<()> {
+        let (ws_stream, _) = connect_async(url)
             .await
             .map_err(|e| SlackError::SocketMode(format!("Failed to connect: {}", e)))?;
 
         let mut stream = self.stream.lock().await;
         *stream = Some(ws_stream);
+        *self.last_pong.lock().unwrap() = None;
 
         let mut connected = self.connected.lock().await;
         *connected = true;
@@ -100,6 +114,11 @@ impl SocketModeConnection {
                     drop(stream);
                     Box::pin(self.receive_message()).await
                 }
+                Some(Ok(Message::Pong(_))) => {
+                    *self.last_pong.lock().unwrap() = Some(Instant::now());
+                    drop(stream);
+                    Box::pin(self.receive_message()).await
+                }
                 Some(Ok(_)) => {
                     // Skip other message types
                     drop(stream);
@@ -135,6 +154,24 @@ impl SocketModeConnection {
         }
     }
 
+    /// Sends a ping frame; the server answers with a pong.
+    pub async fn send_ping(&self) -> Result<()> {
+        let mut stream = self.stream.lock().await;
+
+        if let Some(ws) = stream.as_mut() {
+            ws.send(Message::Ping(Vec::new()))
+                .await
+                .map_err(|e| SlackError::SocketMode(format!("Failed to send ping: {}", e)))
+        } else {
+            Err(SlackError::NotConnected)
+        }
+    }
+
+    /// Returns when the latest pong arrived on the current stream.
+    pub fn last_pong(&self) -> Option<Instant> {
+        *self.last_pong.lock().unwrap()
+    }
+
     /// Returns a clone of the connection URL.
     pub fn url(&self) -> &str {
         &self.url
@@ -173,4 +210,55 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), SlackError::NotConnected));
     }
+
+    /// Connects to a local WebSocket server and returns a receiver of the
+    /// text frames the connection sends, exactly as they went out.
+    async fn capture_frames() -> (
+        SocketModeConnection,
+        tokio::sync::mpsc::UnboundedReceiver<String>,
+    ) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(frame)) = ws.next().await {
+                if let Message::Text(text) = frame {
+                    let _ = tx.send(text);
+                }
+            }
+        });
+
+        let conn = SocketModeConnection::new(url.as_str());
+        conn.connect().await.unwrap();
+        (conn, rx)
+    }
+
+    #[tokio::test]
+    async fn test_acknowledgment_frames() {
+        let (conn, mut frames) = capture_frames().await;
+
+        let responses = [
+            SocketModeResponse::ack("env-1").unwrap(),
+            SocketModeResponse::ack_with("env-2", serde_json::json!({"text": "Deployed"})).unwrap(),
+            SocketModeResponse::ack_with("env-3", Value::Null).unwrap(),
+        ];
+        for response in &responses {
+            conn.send_acknowledgment(response).await.unwrap();
+        }
+
+        let mut sent = Vec::new();
+        for _ in &responses {
+            sent.push(frames.recv().await.unwrap());
+        }
+        assert_eq!(
+            sent,
+            [
+                r#"{"envelope_id":"env-1"}"#,
+                r#"{"envelope_id":"env-2","payload":{"text":"Deployed"}}"#,
+                r#"{"envelope_id":"env-3"}"#,
+            ]
+        );
+    }
 }
@@ -0,0 +1,160 @@
+//! Connection health tracking for Socket Mode.
+//!
+//! Slack's WebSocket connections can drop without a close frame. The client
+//! pings the server periodically and tracks the pongs it gets back; the
+//! resulting [`ConnectionHealth`] is reported to `on_health_change` hooks.
+
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Health of a Socket Mode connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionHealth {
+    /// The server is answering pings. `last_pong` is when the latest pong
+    /// arrived, or when the connection opened if none has yet.
+    Healthy {
+        /// Time of the latest pong.
+        last_pong: Instant,
+    },
+    /// Pings went unanswered within the pong timeout, so the connection is
+    /// being replaced.
+    Degraded {
+        /// Consecutive pings without a pong.
+        missed_pongs: usize,
+    },
+    /// There is no open connection.
+    Disconnected,
+}
+
+impl ConnectionHealth {
+    /// Returns true for [`ConnectionHealth::Healthy`].
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, Self::Healthy { .. })
+    }
+}
+
+/// Type alias for connection health change hooks.
+pub type HealthChangeHandler = Arc<dyn Fn(&ConnectionHealth) + Send + Sync>;
+
+/// Current health plus the hooks to notify when it changes.
+#[derive(Default)]
+pub(crate) struct HealthMonitor {
+    state: Mutex<HealthState>,
+    handlers: Mutex<Vec<HealthChangeHandler>>,
+}
+
+struct HealthState {
+    health: ConnectionHealth,
+    missed_pongs: usize,
+}
+
+impl Default for HealthState {
+    fn default() -> Self {
+        Self {
+            health: ConnectionHealth::Disconnected,
+            missed_pongs: 0,
+        }
+    }
+}
+
+impl HealthMonitor {
+    /// Returns the current health.
+    pub(crate) fn health(&self) -> ConnectionHealth {
+        self.state.lock().unwrap().health
+    }
+
+    /// Registers a hook called with the new health after each change.
+    pub(crate) fn on_change(&self, handler: HealthChangeHandler) {
+        self.handlers.lock().unwrap().push(handler);
+    }
+
+    /// Records a newly opened connection.
+    pub(crate) fn connected(&self, at: Instant) {
+        self.set(ConnectionHealth::Healthy { last_pong: at }, false);
+    }
+
+    /// Records a pong, clearing any missed pongs.
+    pub(crate) fn pong(&self, at: Instant) {
+        self.set(ConnectionHealth::Healthy { last_pong: at }, true);
+    }
+
+    /// Records a ping that went unanswered and returns the number missed in
+    /// a row.
+    pub(crate) fn missed_pong(&self) -> usize {
+        let missed_pongs = {
+            let mut state = self.state.lock().unwrap();
+            state.missed_pongs += 1;
+            state.missed_pongs
+        };
+        self.set(ConnectionHealth::Degraded { missed_pongs }, false);
+        missed_pongs
+    }
+
+    /// Records that the connection was closed.
+    pub(crate) fn disconnected(&self) {
+        self.set(ConnectionHealth::Disconnected, false);
+    }
+
+    /// Stores `health` and notifies the hooks unless only the time of the
+    /// latest pong changed.
+    fn set(&self, health: ConnectionHealth, reset_missed: bool) {
+        let changed = {
+            let mut state = self.state.lock().unwrap();
+            if reset_missed {
+                state.missed_pongs = 0;
+            }
+            let changed =
+                !(state.health.is_healthy() && health.is_healthy()) && state.health != health;
+            state.health = health;
+            changed
+        };
+        if changed {
+            let handlers = self.handlers.lock().unwrap().clone();
+            for handler in handlers {
+                handler(&health);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_transitions_notify_hooks() {
+        let monitor = HealthMonitor::default();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let recorder = Arc::clone(&seen);
+        monitor.on_change(Arc::new(move |health| {
+            recorder.lock().unwrap().push(*health);
+        }));
+
+        let start = Instant::now();
+        monitor.connected(start);
+        monitor.pong(start + Duration::from_secs(30));
+        assert_eq!(monitor.missed_pong(), 1);
+        monitor.disconnected();
+        monitor.connected(start + Duration::from_secs(60));
+        assert_eq!(monitor.missed_pong(), 2);
+        monitor.pong(start + Duration::from_secs(90));
+
+        assert_eq!(
+            *seen.lock().unwrap(),
+            vec![
+                ConnectionHealth::Healthy { last_pong: start },
+                ConnectionHealth::Degraded { missed_pongs: 1 },
+                ConnectionHealth::Disconnected,
+                ConnectionHealth::Healthy {
+                    last_pong: start + Duration::from_secs(60)
+                },
+                ConnectionHealth::Degraded { missed_pongs: 2 },
+                ConnectionHealth::Healthy {
+                    last_pong: start + Duration::from_secs(90)
+                },
+            ]
+        );
+        assert_eq!(monitor.missed_pong(), 1);
+    }
+}
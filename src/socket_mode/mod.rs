@@ -14,7 +14,10 @@
 //! - **WebSocket-based**: No public HTTP endpoint required
 //! - **Automatic reconnection**: Handles connection drops gracefully
 //! - **Event handlers**: Register callbacks for different event types
-//! - **Auto-acknowledgment**: Automatically acknowledges messages (configurable)
+//! - **Event streams**: Receive requests from a channel instead of, or as well
+//!   as, callbacks
+//! - **Auto-acknowledgment**: Automatically acknowledges messages (configurable),
+//!   optionally only when handlers succeed, with a hook for failed requests
 //!
 //! # Requirements
 //!
@@ -84,6 +87,11 @@
 //! }
 //! ```
 //!
+//! ## Synchronous applications
+//!
+//! Applications without a Tokio runtime can use [`blocking::SocketModeClient`],
+//! which runs the async client on an internal background runtime.
+//!
 //! ## Manual acknowledgment
 //!
 //! ```no_run
@@ -118,10 +126,21 @@
 //! }
 //! ```
 
+pub mod app;
+pub mod blocking;
 pub mod client;
 pub mod connection;
+pub mod health;
+pub mod router;
+pub mod stream;
 pub mod types;
 
 // Re-export commonly used types
-pub use client::{EventHandler, SocketModeClient};
+pub use app::SocketModeApp;
+pub use client::{
+    AckPolicy, EventHandler, HandlerFailure, HandlerFailureHandler, SocketModeClient,
+};
+pub use health::{ConnectionHealth, HealthChangeHandler};
+pub use router::{MessagePattern, TypedEventHandler};
+pub use stream::{SocketModeEventReceiver, SocketModeEventSender};
 pub use types::{SocketModeMessageType, SocketModeRequest, SocketModeResponse};
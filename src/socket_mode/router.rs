@@ -0,0 +1,225 @@
+//! Routing of Events API requests to handlers by event type or message text.
+//!
+//! Both Socket Mode clients keep an [`EventRouter`] behind their
+//! `on_event_typed`, `on_message_matching` and `on_unhandled_event` methods.
+//! For each `events_api` request, every route that matches runs in
+//! registration order; catch-all handlers run only when no route matched.
+
+use crate::error::Result;
+use crate::events::EventType;
+use crate::payloads::EventPayload;
+use crate::socket_mode::types::SocketModeRequest;
+use regex::Regex;
+use std::sync::Arc;
+
+/// Handler for a parsed Events API event.
+///
+/// Receives the event and the request envelope it arrived in, e.g. for its
+/// `envelope_id`.
+pub type TypedEventHandler =
+    Arc<dyn Fn(&EventPayload, &SocketModeRequest) -> Result<()> + Send + Sync>;
+
+/// Text a message must match to reach a handler registered with
+/// `on_message_matching`.
+///
+/// A `&str` or `String` matches messages containing it; a [`Regex`] matches
+/// messages it finds a match in.
+#[derive(Debug, Clone)]
+pub enum MessagePattern {
+    /// Matches messages whose text contains the string.
+    Substring(String),
+    /// Matches messages whose text the regex finds a match in.
+    Regex(Regex),
+}
+
+impl MessagePattern {
+    /// Returns true if `text` matches the pattern.
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Substring(needle) => text.contains(needle.as_str()),
+            Self::Regex(regex) => regex.is_match(text),
+        }
+    }
+}
+
+impl From<&str> for MessagePattern {
+    fn from(needle: &str) -> Self {
+        Self::Substring(needle.to_string())
+    }
+}
+
+impl From<String> for MessagePattern {
+    fn from(needle: String) -> Self {
+        Self::Substring(needle)
+    }
+}
+
+impl From<Regex> for MessagePattern {
+    fn from(regex: Regex) -> Self {
+        Self::Regex(regex)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Matcher {
+    Event(EventType),
+    Message(MessagePattern),
+}
+
+impl Matcher {
+    fn matches(&self, event: &EventPayload) -> bool {
+        match self {
+            Self::Event(event_type) => event_type.matches(&event.raw),
+            Self::Message(pattern) => {
+                event.event_type == "message"
+                    && event
+                        .text
+                        .as_deref()
+                        .is_some_and(|text| pattern.is_match(text))
+            }
+        }
+    }
+}
+
+/// Event routes registered on a Socket Mode client.
+#[derive(Clone, Default)]
+pub(crate) struct EventRouter {
+    routes: Vec<(Matcher, TypedEventHandler)>,
+    fallback: Vec<TypedEventHandler>,
+}
+
+impl EventRouter {
+    /// Returns true if nothing has been registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.routes.is_empty() && self.fallback.is_empty()
+    }
+
+    /// Adds a route for events of `event_type`.
+    pub(crate) fn on_event(&mut self, event_type: EventType, handler: TypedEventHandler) {
+        self.routes.push((Matcher::Event(event_type), handler));
+    }
+
+    /// Adds a route for messages whose text matches `pattern`.
+    pub(crate) fn on_message(&mut self, pattern: MessagePattern, handler: TypedEventHandler) {
+        self.routes.push((Matcher::Message(pattern), handler));
+    }
+
+    /// Adds a handler for events no route matched.
+    pub(crate) fn on_unhandled(&mut self, handler: TypedEventHandler) {
+        self.fallback.push(handler);
+    }
+
+    /// Runs the handlers for an `events_api` request, stopping at the first
+    /// error.
+    pub(crate) fn dispatch(&self, request: &SocketModeRequest) -> Result<()> {
+        let event = request.event()?;
+        let mut matched = false;
+        for (matcher, handler) in &self.routes {
+            if matcher.matches(&event) {
+                matched = true;
+                handler(&event, request)?;
+            }
+        }
+        if !matched {
+            for handler in &self.fallback {
+                handler(&event, request)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for EventRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventRouter")
+            .field(
+                "routes",
+                &self.routes.iter().map(|(m, _)| m).collect::<Vec<_>>(),
+            )
+            .field("fallback", &self.fallback.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::sync::Mutex;
+
+    fn recorder(log: &Arc<Mutex<Vec<String>>>, name: &str) -> TypedEventHandler {
+        let log = Arc::clone(log);
+        let name = name.to_string();
+        Arc::new(move |event: &EventPayload, request: &SocketModeRequest| {
+            log.lock().unwrap().push(format!(
+                "{}:{}:{}",
+                name, event.event_type, request.envelope_id
+            ));
+            Ok(())
+        })
+    }
+
+    fn envelope(event: serde_json::Value) -> SocketModeRequest {
+        SocketModeRequest::new("events_api", "env-1", json!({"event": event}))
+    }
+
+    #[test]
+    fn test_dispatch_runs_matching_routes_in_order() {
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut router = EventRouter::default();
+        router.on_event("message".into(), recorder(&log, "first"));
+        router.on_event(EventType::ReactionAdded, recorder(&log, "reaction"));
+        router.on_message("deploy".into(), recorder(&log, "deploy"));
+        router.on_message(
+            Regex::new(r"^rollback \w+$").unwrap().into(),
+            recorder(&log, "rollback"),
+        );
+        router.on_event("message".into(), recorder(&log, "second"));
+        router.on_unhandled(recorder(&log, "fallback"));
+
+        router
+            .dispatch(&envelope(json!({"type": "message", "text": "deploy api"})))
+            .unwrap();
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "first:message:env-1",
+                "deploy:message:env-1",
+                "second:message:env-1"
+            ]
+        );
+
+        log.lock().unwrap().clear();
+        router
+            .dispatch(&envelope(
+                json!({"type": "reaction_added", "reaction": "+1"}),
+            ))
+            .unwrap();
+        assert_eq!(*log.lock().unwrap(), ["reaction:reaction_added:env-1"]);
+
+        log.lock().unwrap().clear();
+        router
+            .dispatch(&envelope(
+                json!({"type": "team_join", "user": {"id": "U1"}}),
+            ))
+            .unwrap();
+        assert_eq!(*log.lock().unwrap(), ["fallback:team_join:env-1"]);
+    }
+
+    #[test]
+    fn test_message_pattern() {
+        assert!(MessagePattern::from("ship").is_match("ship it"));
+        assert!(!MessagePattern::from("Ship").is_match("ship it"));
+        let pattern = MessagePattern::from(Regex::new(r"(?i)^ship \d+$").unwrap());
+        assert!(pattern.is_match("SHIP 42"));
+        assert!(!pattern.is_match("ship it"));
+    }
+
+    #[test]
+    fn test_dispatch_rejects_non_event_requests() {
+        let router = EventRouter::default();
+        let request = SocketModeRequest::new("slash_commands", "env-1", json!({}));
+        assert!(router.dispatch(&request).is_err());
+    }
+}
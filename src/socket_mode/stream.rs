@@ -0,0 +1,134 @@
+//! Channel-based delivery of Socket Mode requests.
+//!
+//! [`SocketModeClient::events_stream`](super::SocketModeClient::events_stream)
+//! returns a sender/receiver pair. While [`start`](super::SocketModeClient::start)
+//! runs, every request the client receives is sent into the channel after the
+//! registered handlers have run and the request has been acknowledged. The
+//! receive loop never waits for a slow consumer: requests that do not fit in
+//! a full channel are queued in order and delivered as it drains.
+
+use crate::error::{Result, SlackError};
+use crate::socket_mode::types::SocketModeRequest;
+use futures::Stream;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::sync::mpsc;
+
+/// Sending half of a Socket Mode event channel.
+///
+/// The client keeps a clone and feeds it from its receive loop; the returned
+/// copy can be used to inject requests, e.g. in tests.
+#[derive(Debug, Clone)]
+pub struct SocketModeEventSender(mpsc::Sender<SocketModeRequest>);
+
+impl SocketModeEventSender {
+    /// Sends a request, waiting for buffer space if the channel is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::SocketMode` if the receiver has been dropped.
+    pub async fn send(&self, request: SocketModeRequest) -> Result<()> {
+        self.0
+            .send(request)
+            .await
+            .map_err(|_| SlackError::SocketMode("Socket Mode event stream closed".to_string()))
+    }
+
+    /// Sends a request without waiting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::SocketMode` if the channel is full or the
+    /// receiver has been dropped.
+    pub fn try_send(&self, request: SocketModeRequest) -> Result<()> {
+        self.0.try_send(request).map_err(|e| {
+            let reason = match e {
+                mpsc::error::TrySendError::Full(_) => "full",
+                mpsc::error::TrySendError::Closed(_) => "closed",
+            };
+            SlackError::SocketMode(format!("Socket Mode event stream {}", reason))
+        })
+    }
+
+    /// Returns true once the receiver has been dropped.
+    pub fn is_closed(&self) -> bool {
+        self.0.is_closed()
+    }
+}
+
+/// Receiving half of a Socket Mode event channel.
+///
+/// Implements [`Stream`], so it can be used with `StreamExt` combinators.
+#[derive(Debug)]
+pub struct SocketModeEventReceiver(mpsc::Receiver<SocketModeRequest>);
+
+impl SocketModeEventReceiver {
+    /// Receives the next request, or None once every sender is gone.
+    pub async fn recv(&mut self) -> Option<SocketModeRequest> {
+        self.0.recv().await
+    }
+
+    /// Returns the underlying Tokio receiver.
+    pub fn into_inner(self) -> mpsc::Receiver<SocketModeRequest> {
+        self.0
+    }
+}
+
+impl Stream for SocketModeEventReceiver {
+    type Item = SocketModeRequest;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.0.poll_recv(cx)
+    }
+}
+
+/// Feeds one event channel from the receive loop without waiting on it.
+///
+/// Requests go into an unbounded queue that a task drains into the channel,
+/// so a full channel delays delivery instead of dropping acknowledged
+/// requests or stalling the loop, and their order is kept.
+#[derive(Debug)]
+pub(crate) struct EventForwarder {
+    sender: SocketModeEventSender,
+    queue: Option<mpsc::UnboundedSender<SocketModeRequest>>,
+}
+
+impl EventForwarder {
+    pub(crate) fn new(sender: SocketModeEventSender) -> Self {
+        Self {
+            sender,
+            queue: None,
+        }
+    }
+
+    /// Queues a request for the channel, starting the draining task on
+    /// first use. Must be called from within a Tokio runtime.
+    pub(crate) fn forward(&mut self, request: SocketModeRequest) {
+        let sender = &self.sender;
+        let queue = self.queue.get_or_insert_with(|| {
+            let (queue, mut pending) = mpsc::unbounded_channel();
+            let sender = sender.clone();
+            tokio::spawn(async move {
+                while let Some(request) = pending.recv().await {
+                    if sender.send(request).await.is_err() {
+                        break;
+                    }
+                }
+            });
+            queue
+        });
+        // Fails only once the receiver is gone, which `is_closed` reports
+        let _ = queue.send(request);
+    }
+
+    /// Returns true once the channel's receiver has been dropped.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+}
+
+/// Creates a channel buffering up to `buffer` requests.
+pub(crate) fn channel(buffer: usize) -> (SocketModeEventSender, SocketModeEventReceiver) {
+    let (tx, rx) = mpsc::channel(buffer.max(1));
+    (SocketModeEventSender(tx), SocketModeEventReceiver(rx))
+}
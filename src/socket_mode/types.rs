@@ -2,7 +2,10 @@
 //!
 //! This module defines the request and response types used in Socket Mode communication.
 
-use serde::{Deserialize, Serialize};
+use crate::error::{Result, SlackError};
+use crate::payloads::{EventPayload, InteractionPayload, SlashCommandPayload};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Serialize, Serializer};
 use serde_json::Value;
 
 /// A Socket Mode request envelope received from Slack.
@@ -15,10 +18,12 @@ pub struct SocketModeRequest {
     #[serde(rename = "type")]
     pub message_type: String,
 
-    /// The unique envelope ID for acknowledgment
+    /// The unique envelope ID for acknowledgment (empty for `hello` and `disconnect`)
+    #[serde(default)]
     pub envelope_id: String,
 
     /// The actual payload data
+    #[serde(default)]
     pub payload: Value,
 
     /// Whether this request accepts a response payload
@@ -68,23 +73,125 @@ impl SocketModeRequest {
         self.retry_reason = Some(reason.into());
         self
     }
+
+    /// Parses the payload of a `slash_commands` request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` for other message types or a
+    /// payload without a command.
+    pub fn slash_command(&self) -> Result<SlashCommandPayload> {
+        self.expect_type(SocketModeMessageType::SlashCommands)?;
+        SlashCommandPayload::from_value(self.payload.clone())
+    }
+
+    /// Parses the payload of an `interactive` request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` for other message types, or a
+    /// serialization error if the payload has no `type`.
+    pub fn interaction(&self) -> Result<InteractionPayload> {
+        self.expect_type(SocketModeMessageType::Interactive)?;
+        InteractionPayload::from_value(self.payload.clone())
+    }
+
+    /// Parses the event of an `events_api` request.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` for other message types or a
+    /// payload without an event.
+    pub fn event(&self) -> Result<EventPayload> {
+        self.expect_type(SocketModeMessageType::EventsApi)?;
+        EventPayload::from_value(self.payload.clone())
+    }
+
+    fn expect_type(&self, expected: SocketModeMessageType) -> Result<()> {
+        if self.message_type != expected.as_str() {
+            return Err(SlackError::Validation(format!(
+                "expected a {} request, got {}",
+                expected.as_str(),
+                self.message_type
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// A Socket Mode response (acknowledgment) to send back to Slack.
 ///
 /// After receiving a Socket Mode request, the client must acknowledge it
-/// by sending a response with the same envelope_id.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+/// by sending a response with the same envelope_id. The frame is
+/// `{"envelope_id":"..."}`, plus `"payload"` when one is set; a missing or
+/// null payload is left out.
+///
+/// # Examples
+///
+/// ```
+/// use slack_rs::socket_mode::SocketModeResponse;
+/// use serde_json::json;
+///
+/// let ack = SocketModeResponse::ack_with("env-1", json!({"text": "Done"}))?;
+/// assert_eq!(
+///     serde_json::to_string(&ack)?,
+///     r#"{"envelope_id":"env-1","payload":{"text":"Done"}}"#
+/// );
+/// assert!(SocketModeResponse::ack("").is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Deserialize, PartialEq)]
 pub struct SocketModeResponse {
     /// The envelope ID from the request being acknowledged
     pub envelope_id: String,
 
     /// Optional response payload
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
     pub payload: Option<Value>,
 }
 
+impl Serialize for SocketModeResponse {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let payload = self.payload.as_ref().filter(|payload| !payload.is_null());
+        let mut frame = serializer
+            .serialize_struct("SocketModeResponse", 1 + usize::from(payload.is_some()))?;
+        frame.serialize_field("envelope_id", &self.envelope_id)?;
+        if let Some(payload) = payload {
+            frame.serialize_field("payload", payload)?;
+        }
+        frame.end()
+    }
+}
+
 impl SocketModeResponse {
+    /// Creates an acknowledgment of the request with `envelope_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if `envelope_id` is empty; Slack
+    /// silently drops such frames.
+    pub fn ack(envelope_id: impl Into<String>) -> Result<Self> {
+        let envelope_id = envelope_id.into();
+        if envelope_id.trim().is_empty() {
+            return Err(SlackError::Validation(
+                "Socket Mode acknowledgment needs a non-empty envelope_id".to_string(),
+            ));
+        }
+        Ok(Self::new(envelope_id))
+    }
+
+    /// Creates an acknowledgment carrying a response payload, such as a
+    /// slash command reply or a `view_submission` response action.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if `envelope_id` is empty.
+    pub fn ack_with(envelope_id: impl Into<String>, payload: Value) -> Result<Self> {
+        let mut response = Self::ack(envelope_id)?;
+        response.payload = Some(payload);
+        Ok(response)
+    }
+
     /// Creates a new Socket Mode response to acknowledge a request.
     pub fn new(envelope_id: impl Into<String>) -> Self {
         Self {
@@ -222,6 +329,20 @@ mod tests {
         assert_eq!(req.retry_reason, Some("timeout".to_string()));
     }
 
+    #[test]
+    fn test_socket_mode_hello_deserialization() {
+        let json = json!({
+            "type": "hello",
+            "num_connections": 1,
+            "connection_info": {"app_id": "A123"}
+        });
+
+        let request: SocketModeRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(request.message_type, "hello");
+        assert_eq!(request.envelope_id, "");
+        assert_eq!(request.payload, Value::Null);
+    }
+
     #[test]
     fn test_socket_mode_response_new() {
         let resp = SocketModeResponse::new("envelope-123");
@@ -239,6 +360,32 @@ mod tests {
         assert_eq!(resp.payload, Some(payload));
     }
 
+    #[test]
+    fn test_socket_mode_response_ack() {
+        let ack = SocketModeResponse::ack("env-1").unwrap();
+        assert_eq!(
+            serde_json::to_string(&ack).unwrap(),
+            r#"{"envelope_id":"env-1"}"#
+        );
+
+        let ack = SocketModeResponse::ack_with("env-2", Value::Null).unwrap();
+        assert_eq!(
+            serde_json::to_string(&ack).unwrap(),
+            r#"{"envelope_id":"env-2"}"#
+        );
+
+        for envelope_id in ["", "  "] {
+            assert!(matches!(
+                SocketModeResponse::ack(envelope_id),
+                Err(SlackError::Validation(_))
+            ));
+            assert!(matches!(
+                SocketModeResponse::ack_with(envelope_id, json!({})),
+                Err(SlackError::Validation(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_socket_mode_response_serialization() {
         let resp = SocketModeResponse::new("env-xyz");
@@ -288,4 +435,79 @@ mod tests {
         assert_eq!(SocketModeMessageType::Hello.as_str(), "hello");
         assert_eq!(SocketModeMessageType::Unknown.as_str(), "unknown");
     }
+
+    #[test]
+    fn test_slash_command_envelope() {
+        let request: SocketModeRequest = serde_json::from_value(json!({
+            "envelope_id": "1d3c79ab-0ffb-41f3-a080-d19e85f53649",
+            "payload": {
+                "token": "xyz",
+                "team_id": "T111",
+                "team_domain": "acme",
+                "channel_id": "C111",
+                "channel_name": "general",
+                "user_id": "U111",
+                "user_name": "jane",
+                "command": "/hello-socket-mode",
+                "text": "world",
+                "api_app_id": "A111",
+                "is_enterprise_install": "false",
+                "response_url": "https://hooks.slack.com/commands/T111/1/abc",
+                "trigger_id": "111.222.xxx"
+            },
+            "type": "slash_commands",
+            "accepts_response_payload": true
+        }))
+        .unwrap();
+
+        let command = request.slash_command().unwrap();
+        assert_eq!(command.command, "/hello-socket-mode");
+        assert_eq!(command.text, "world");
+        assert_eq!(command.channel_id, "C111");
+        assert!(!command.is_enterprise_install);
+        assert!(request.interaction().is_err());
+    }
+
+    #[test]
+    fn test_block_actions_envelope() {
+        let request: SocketModeRequest = serde_json::from_value(json!({
+            "envelope_id": "57d6a792-4d35-4d0b-b6aa-3361493e1caf",
+            "payload": {
+                "type": "block_actions",
+                "user": {"id": "U111", "username": "jane", "team_id": "T111"},
+                "api_app_id": "A111",
+                "container": {"type": "message", "message_ts": "1621453950.000400", "channel_id": "C111"},
+                "trigger_id": "111.222.xxx",
+                "team": {"id": "T111", "domain": "acme"},
+                "channel": {"id": "C111", "name": "general"},
+                "response_url": "https://hooks.slack.com/actions/T111/111/xxx",
+                "actions": [{
+                    "action_id": "approve",
+                    "block_id": "deploy",
+                    "text": {"type": "plain_text", "text": "Approve"},
+                    "value": "v42",
+                    "type": "button",
+                    "action_ts": "1621455447.185523"
+                }]
+            },
+            "type": "interactive",
+            "accepts_response_payload": false
+        }))
+        .unwrap();
+
+        let interaction = request.interaction().unwrap();
+        assert_eq!(interaction.interaction_type, "block_actions");
+        assert_eq!(
+            interaction.channel.unwrap().name.as_deref(),
+            Some("general")
+        );
+        assert_eq!(interaction.actions.len(), 1);
+        assert_eq!(interaction.actions[0].action_id, "approve");
+        assert_eq!(interaction.actions[0].value.as_deref(), Some("v42"));
+        assert_eq!(interaction.raw["container"]["channel_id"], "C111");
+        assert!(matches!(
+            request.slash_command(),
+            Err(SlackError::Validation(_))
+        ));
+    }
 }
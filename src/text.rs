@@ -0,0 +1,102 @@
+//! Text formatting helpers for Slack mrkdwn.
+//!
+//! Each helper escapes its arguments with [`mrkdwn::escape`], so user input
+//! such as `a < b` can't open a control sequence. Slack has no escapes for
+//! the formatting characters themselves (`*`, `_`, `` ` ``), so text that
+//! contains them may still render unexpectedly.
+//!
+//! # Examples
+//!
+//! ```
+//! use slack_rs::text::{bold, link, mention_channel, mention_user};
+//!
+//! let text = format!(
+//!     "{} merged {} into {}",
+//!     mention_user("U123"),
+//!     link("https://example.com/pr/1", "PR #1"),
+//!     mention_channel("C456"),
+//! );
+//! assert_eq!(text, "<@U123> merged <https://example.com/pr/1|PR #1> into <#C456>");
+//! assert_eq!(bold("R&D"), "*R&amp;D*");
+//! ```
+//!
+//! See: https://api.slack.com/reference/surfaces/formatting
+
+use crate::models::mrkdwn::{self, escape};
+
+/// Formats a user mention, e.g. `<@U123456>`.
+pub fn mention_user(user_id: &str) -> String {
+    format!("<@{}>", escape(user_id))
+}
+
+/// Formats a channel link, e.g. `<#C123456>`.
+pub fn mention_channel(channel_id: &str) -> String {
+    mrkdwn::format_channel(&escape(channel_id))
+}
+
+/// Formats a user group mention, e.g. `<!subteam^S123456>`.
+pub fn mention_usergroup(usergroup_id: &str) -> String {
+    mrkdwn::format_user_group(&escape(usergroup_id))
+}
+
+/// Formats bold text, e.g. `*text*`.
+pub fn bold(s: &str) -> String {
+    format!("*{}*", escape(s))
+}
+
+/// Formats italic text, e.g. `_text_`.
+pub fn italic(s: &str) -> String {
+    format!("_{}_", escape(s))
+}
+
+/// Formats inline code, e.g. `` `text` ``.
+pub fn code(s: &str) -> String {
+    format!("`{}`", escape(s))
+}
+
+/// Formats a preformatted block, e.g. ```` ```text``` ````.
+pub fn code_block(s: &str) -> String {
+    format!("```{}```", escape(s))
+}
+
+/// Formats a link with a label, e.g. `<https://example.com|Example>`.
+///
+/// A `|` in the URL is percent-encoded, since it would otherwise end the URL
+/// and start the label.
+pub fn link(url: &str, label: &str) -> String {
+    format!("<{}|{}>", escape(url).replace('|', "%7C"), escape(label))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_mentions() {
+        assert_eq!(mention_user("U123"), "<@U123>");
+        assert_eq!(mention_channel("C123"), "<#C123>");
+        assert_eq!(mention_usergroup("S123"), "<!subteam^S123>");
+        assert_eq!(mention_user("U1><!channel"), "<@U1&gt;&lt;!channel>");
+    }
+
+    #[test]
+    fn test_styles_escape_control_characters() {
+        assert_eq!(bold("a < b"), "*a &lt; b*");
+        assert_eq!(italic("Q&A"), "_Q&amp;A_");
+        assert_eq!(code("Vec<u8>"), "`Vec&lt;u8&gt;`");
+        assert_eq!(code_block("x > 1"), "```x &gt; 1```");
+    }
+
+    #[test]
+    fn test_link() {
+        assert_eq!(
+            link("https://example.com/?a=1&b=2", "Tom & Jerry"),
+            "<https://example.com/?a=1&amp;b=2|Tom &amp; Jerry>"
+        );
+        assert_eq!(
+            link("https://example.com/a|b", "x"),
+            "<https://example.com/a%7Cb|x>"
+        );
+    }
+}
@@ -1,7 +1,23 @@
 //! Internal utility functions for the Web API client.
 
-use serde_json::Value;
-use std::collections::HashMap;
+use crate::clock::{self, Clock};
+use crate::constants::limits::MAX_INVITE_USERS;
+use crate::constants::rate_limits::RateLimit;
+use crate::constants::status_codes;
+use crate::constants::time::MAX_SCHEDULE_AHEAD_DAYS;
+use crate::error::{Result, SlackError};
+use crate::http_retry::{HttpResponse, RetryHandler, RetryState};
+use crate::web::requests::{EmojiSource, InviteReport};
+use chrono::{DateTime, Utc};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Constructs the User-Agent header string.
 ///
@@ -76,8 +92,68 @@ pub fn get_url(base_url: &str, api_method: &str) -> String {
     format!("{}{}", base, method)
 }
 
+/// Headers set by the client itself, which client-wide defaults can't
+/// replace.
+const PROTECTED_HEADERS: [&str; 2] = ["Authorization", "Content-Type"];
+
+/// Inserts a header, replacing any existing value whose name matches
+/// case-insensitively. The existing spelling of the name is kept, so each
+/// header is sent exactly once.
+pub(crate) fn merge_header(headers: &mut HashMap<String, String>, key: &str, value: &str) {
+    match headers
+        .keys()
+        .find(|k| k.eq_ignore_ascii_case(key))
+        .cloned()
+    {
+        Some(existing) => headers.insert(existing, value.to_string()),
+        None => headers.insert(key.to_string(), value.to_string()),
+    };
+}
+
+/// Returns true for headers client-wide defaults can't replace.
+pub(crate) fn is_protected_header(key: &str) -> bool {
+    PROTECTED_HEADERS
+        .iter()
+        .any(|protected| protected.eq_ignore_ascii_case(key))
+}
+
+/// Adds client-wide default headers to a `HeaderMap`, skipping
+/// `Authorization` and `Content-Type`.
+///
+/// # Errors
+///
+/// Returns `SlackError::InvalidInput` for a header name or value that isn't
+/// valid in HTTP.
+pub(crate) fn apply_default_headers(
+    headers: &mut reqwest::header::HeaderMap,
+    defaults: &HashMap<String, String>,
+) -> Result<()> {
+    use reqwest::header::{HeaderName, HeaderValue};
+
+    for (key, value) in defaults {
+        if is_protected_header(key) {
+            continue;
+        }
+        let name =
+            HeaderName::from_bytes(key.as_bytes()).map_err(|e| SlackError::InvalidInput {
+                message: format!("Invalid header name {}: {}", key, e),
+            })?;
+        let value = HeaderValue::from_str(value).map_err(|e| SlackError::InvalidInput {
+            message: format!("Invalid value for header {}: {}", key, e),
+        })?;
+        headers.insert(name, value);
+    }
+    Ok(())
+}
+
 /// Constructs the headers needed for a request.
 ///
+/// Headers are merged in a fixed order, later ones winning: the SDK's
+/// `Content-Type`, `User-Agent` and `Authorization`, then the client's
+/// default headers, then the request's headers. Default headers can't
+/// replace `Authorization` or `Content-Type`. Names are compared
+/// case-insensitively, so each header appears once.
+///
 /// # Arguments
 ///
 /// * `token` - Optional Slack API token
@@ -114,10 +190,7 @@ pub fn get_headers(
     }
     // For files, let reqwest set the multipart boundary automatically
 
-    // Add User-Agent if not present
-    if !default_headers.contains_key("User-Agent") {
-        headers.insert("User-Agent".to_string(), get_user_agent(None, None));
-    }
+    headers.insert("User-Agent".to_string(), get_user_agent(None, None));
 
     // Add authorization header
     if let Some(t) = token {
@@ -126,19 +199,608 @@ pub fn get_headers(
 
     // Merge default headers (from client initialization)
     for (key, value) in default_headers {
-        headers.insert(key.clone(), value.clone());
+        if !is_protected_header(key) {
+            merge_header(&mut headers, key, value);
+        }
     }
 
     // Merge request-specific headers (highest priority)
     if let Some(req_headers) = request_headers {
         for (key, value) in req_headers {
-            headers.insert(key.clone(), value.clone());
+            merge_header(&mut headers, key, value);
         }
     }
 
     headers
 }
 
+/// Number of characters of an unparseable response body kept in
+/// `SlackError::UnexpectedResponse`.
+pub(crate) const BODY_SNIPPET_CHARS: usize = 500;
+
+/// Parses a Web API response body as JSON.
+///
+/// The Web API always answers with a JSON object, so an empty body or one
+/// that isn't JSON, such as an HTML error page from a proxy, is reported as
+/// `SlackError::UnexpectedResponse` with the start of the body.
+pub(crate) fn parse_response_body(
+    status: u16,
+    headers: &reqwest::header::HeaderMap,
+    body: &[u8],
+) -> Result<Value> {
+    let parsed = if body.iter().all(u8::is_ascii_whitespace) {
+        None
+    } else {
+        serde_json::from_slice(body).ok()
+    };
+    parsed.ok_or_else(|| SlackError::UnexpectedResponse {
+        status,
+        content_type: headers
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string),
+        body_snippet: String::from_utf8_lossy(body)
+            .chars()
+            .take(BODY_SNIPPET_CHARS)
+            .collect(),
+    })
+}
+
+/// Serializes a JSON request body, rejecting it if it exceeds `max_bytes`.
+///
+/// The limit applies to the serialized JSON, which is what Slack measures,
+/// rather than to the in-memory value.
+pub(crate) fn serialize_json_body(
+    value: &impl Serialize,
+    max_bytes: Option<usize>,
+) -> Result<Vec<u8>> {
+    let body = serde_json::to_vec(value)?;
+
+    if let Some(max_bytes) = max_bytes {
+        if body.len() > max_bytes {
+            return Err(SlackError::Validation(format!(
+                "payload too large: {} bytes (limit {} bytes)",
+                body.len(),
+                max_bytes
+            )));
+        }
+    }
+
+    Ok(body)
+}
+
+/// Flattens request parameters into key/value pairs for form-encoded bodies
+/// and query strings.
+///
+/// Strings are sent as-is; objects and arrays are JSON-encoded, which is how
+/// Slack expects structured values such as `blocks` outside a JSON body.
+pub(crate) fn form_pairs(params: &Value) -> Vec<(String, String)> {
+    let Some(map) = params.as_object() else {
+        return Vec::new();
+    };
+    map.iter()
+        .map(|(key, value)| {
+            let value = match value {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+/// Serializes request parameters as an `application/x-www-form-urlencoded`
+/// body, rejecting it if it exceeds `max_bytes`.
+pub(crate) fn serialize_form_body(params: &Value, max_bytes: Option<usize>) -> Result<Vec<u8>> {
+    let body = url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(form_pairs(params))
+        .finish()
+        .into_bytes();
+
+    if let Some(max_bytes) = max_bytes {
+        if body.len() > max_bytes {
+            return Err(SlackError::Validation(format!(
+                "payload too large: {} bytes (limit {} bytes)",
+                body.len(),
+                max_bytes
+            )));
+        }
+    }
+
+    Ok(body)
+}
+
+/// Compresses a request body with gzip.
+pub(crate) fn gzip_body(body: &[u8]) -> Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(body)?;
+    Ok(encoder.finish()?)
+}
+
+/// Generates a `client_msg_id` for `chat.postMessage`.
+///
+/// Sending the same ID on every attempt lets Slack deduplicate retried posts.
+pub(crate) fn generate_client_msg_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Rejects a `Retry-After` wait longer than `max_wait`.
+///
+/// Returns `SlackError::Ratelimited` so the caller can decide when to try
+/// again instead of the client blocking for the whole wait.
+pub(crate) fn check_retry_wait(retry_after: Duration, max_wait: Option<Duration>) -> Result<()> {
+    match max_wait {
+        Some(max_wait) if retry_after > max_wait => Err(SlackError::Ratelimited { retry_after }),
+        _ => Ok(()),
+    }
+}
+
+/// Returns whether the bearer token may be sent to `url`.
+///
+/// Only `slack.com` and its subdomains qualify, plus the origin of the
+/// client's configured base URL so that proxies and test servers work.
+pub(crate) fn is_trusted_download_host(url: &reqwest::Url, base_url: &str) -> bool {
+    let Some(host) = url.host_str() else {
+        return false;
+    };
+    if url.scheme() == "https" && (host == "slack.com" || host.ends_with(".slack.com")) {
+        return true;
+    }
+    reqwest::Url::parse(base_url).is_ok_and(|base| base.origin() == url.origin())
+}
+
+/// Resolves the `Location` header of a redirect response against the URL
+/// that produced it.
+pub(crate) fn redirect_target(
+    url: &reqwest::Url,
+    headers: &reqwest::header::HeaderMap,
+) -> Result<reqwest::Url> {
+    let location = headers
+        .get(reqwest::header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .ok_or_else(|| SlackError::http_error("Redirect response without a Location header"))?;
+    url.join(location).map_err(|e| {
+        SlackError::http_error(format!("Invalid redirect location '{}': {}", location, e))
+    })
+}
+
+/// Builds the error for a failed file download.
+///
+/// A 403 from Slack usually means the token lacks the `files:read` scope,
+/// so it is reported as an `ApiError` carrying that hint.
+pub(crate) fn download_error(status: u16, headers: &reqwest::header::HeaderMap) -> SlackError {
+    let error = if status == status_codes::FORBIDDEN {
+        SlackError::api_error_with_response(
+            "File download forbidden (403); the token may be missing the files:read scope",
+            serde_json::json!({"ok": false, "error": "missing_scope", "needed": "files:read"}),
+        )
+    } else {
+        SlackError::http_error(format!("File download failed with status {}", status))
+    };
+    error.with_response_info(status, headers)
+}
+
+/// Checks a download's size against the client's limit.
+pub(crate) fn check_download_size(size: u64, max_bytes: Option<usize>) -> Result<()> {
+    match max_bytes {
+        Some(max) if size > max as u64 => Err(SlackError::Validation(format!(
+            "File download exceeds maximum size of {} bytes",
+            max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Parses the `emoji` map of an `emoji.list` response.
+///
+/// With `resolve_aliases`, each alias is followed through the map until it
+/// reaches an uploaded emoji (becoming `Url`) or a name the map does not
+/// contain, such as a standard emoji (becoming `Alias` of that name). Aliases
+/// caught in a cycle are left pointing at their direct target.
+pub(crate) fn emoji_map(data: &Value, resolve_aliases: bool) -> HashMap<String, EmojiSource> {
+    let raw: HashMap<String, EmojiSource> = data["emoji"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter_map(|(name, value)| {
+            let value = value.as_str()?;
+            let source = match value.strip_prefix("alias:") {
+                Some(target) => EmojiSource::Alias(target.to_string()),
+                None => EmojiSource::Url(value.to_string()),
+            };
+            Some((name.clone(), source))
+        })
+        .collect();
+
+    if !resolve_aliases {
+        return raw;
+    }
+
+    raw.iter()
+        .map(|(name, source)| {
+            let mut seen = HashSet::from([name.as_str()]);
+            let mut current = source;
+            let resolved = loop {
+                match current {
+                    EmojiSource::Url(_) => break current,
+                    EmojiSource::Alias(target) => {
+                        if !seen.insert(target.as_str()) {
+                            break source;
+                        }
+                        match raw.get(target) {
+                            Some(next) => current = next,
+                            None => break current,
+                        }
+                    }
+                }
+            };
+            (name.clone(), resolved.clone())
+        })
+        .collect()
+}
+
+/// Spaces out calls to one API method to stay under its rate limit.
+///
+/// A token bucket allows `burst_size` calls at once and then one call per
+/// [`RateLimit::min_interval`]. When Slack still rate-limits a call,
+/// [`defer`](Self::defer) holds further calls back for the `Retry-After`
+/// period.
+#[derive(Debug)]
+pub(crate) struct RequestPacer {
+    limit: RateLimit,
+    clock: Arc<dyn Clock>,
+    tokens: f64,
+    last_refill: Option<Instant>,
+}
+
+impl RequestPacer {
+    /// Creates a pacer for the given rate limit, starting with a full burst.
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            clock: clock::system_clock(),
+            tokens: f64::from(limit.burst_size),
+            last_refill: None,
+        }
+    }
+
+    /// Uses the given clock instead of the system clock.
+    #[cfg(test)]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Reserves the next call slot and returns how long to wait before
+    /// making the call.
+    pub fn reserve(&mut self) -> Duration {
+        let now = self.clock.instant();
+        let interval = self.limit.min_interval().as_secs_f64();
+
+        // Refilling starts again once any deferral has passed
+        let start = self.last_refill.map_or(now, |last| last.max(now));
+        if let Some(last) = self.last_refill {
+            let refilled = now.saturating_duration_since(last).as_secs_f64() / interval;
+            self.tokens = (self.tokens + refilled).min(f64::from(self.limit.burst_size));
+        }
+        self.last_refill = Some(start);
+
+        self.tokens -= 1.0;
+        let deficit = if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens * interval)
+        };
+
+        start.saturating_duration_since(now) + deficit
+    }
+
+    /// Holds back further calls for `retry_after` after Slack rate-limited a
+    /// call. The next call may go as soon as that period ends; later ones
+    /// are spaced by the rate limit again.
+    pub fn defer(&mut self, retry_after: Duration) {
+        self.tokens = self.tokens.min(1.0);
+        self.last_refill = Some(self.clock.instant() + retry_after);
+    }
+}
+
+/// Returns how long Slack asked to wait if `error` is a rate-limit error.
+pub(crate) fn rate_limit_wait(error: &SlackError, default: Duration) -> Option<Duration> {
+    match error {
+        SlackError::Ratelimited { retry_after } => Some(*retry_after),
+        _ if error.api_error_code() == Some("ratelimited") || error.http_status() == Some(429) => {
+            let retry_after = error
+                .response_headers()
+                .and_then(|headers| headers.get("retry-after"))
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_secs);
+            Some(retry_after.unwrap_or(default))
+        }
+        _ => None,
+    }
+}
+
+/// Rewrites a failed step of `send_dm` into an error naming the Slack error
+/// code and the user it was for.
+pub(crate) fn dm_error(mut error: SlackError, user: &str) -> SlackError {
+    if let SlackError::ApiError {
+        message, response, ..
+    } = &mut error
+    {
+        let code = response["error"].as_str().unwrap_or("unknown_error");
+        *message = format!("send_dm failed: {} (user: {})", code, user);
+    }
+    error
+}
+
+/// Extracts the IM channel ID from a `conversations.open` response.
+///
+/// Fails with an `im_open_failed` API error if the response has no channel.
+pub(crate) fn dm_channel_id(data: &Value, user: &str) -> Result<String> {
+    data["channel"]["id"]
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| {
+            SlackError::api_error_with_response(
+                format!("send_dm failed: im_open_failed (user: {})", user),
+                data.clone(),
+            )
+        })
+}
+
+/// Checks that `post_at` lies between `now` and Slack's scheduling limit,
+/// returning it as Unix seconds.
+///
+/// The errors name the code Slack would return (`time_in_past` or
+/// `time_too_far`), so the request is never sent.
+pub(crate) fn check_post_at(post_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<i64> {
+    if post_at <= now {
+        return Err(SlackError::Validation(format!(
+            "time_in_past: post_at {} is not in the future",
+            post_at.to_rfc3339()
+        )));
+    }
+    if post_at > now + chrono::Duration::days(MAX_SCHEDULE_AHEAD_DAYS) {
+        return Err(SlackError::Validation(format!(
+            "time_too_far: post_at {} is more than {} days ahead",
+            post_at.to_rfc3339(),
+            MAX_SCHEDULE_AHEAD_DAYS
+        )));
+    }
+    Ok(post_at.timestamp())
+}
+
+/// Builds `chat.scheduledMessages.list` parameters for `channel`, using the
+/// range bounds as `oldest` and `latest`.
+///
+/// Slack treats both bounds as inclusive whole seconds, so the window sent
+/// can be wider than `range`; callers filter the results against it.
+pub(crate) fn scheduled_messages_params(
+    channel: &str,
+    range: &impl RangeBounds<DateTime<Utc>>,
+) -> Value {
+    let mut params = Map::new();
+    params.insert("channel".to_string(), Value::String(channel.to_string()));
+    if let Bound::Included(oldest) | Bound::Excluded(oldest) = range.start_bound() {
+        params.insert("oldest".to_string(), oldest.timestamp().to_string().into());
+    }
+    if let Bound::Included(latest) | Bound::Excluded(latest) = range.end_bound() {
+        params.insert("latest".to_string(), latest.timestamp().to_string().into());
+    }
+    Value::Object(params)
+}
+
+/// Normalizes an emoji name for `reactions.add`/`reactions.remove`.
+///
+/// Strips surrounding whitespace and colons and lowercases the rest, so
+/// `":ThumbsUp:"` becomes `"thumbsup"`. Skin tone suffixes such as
+/// `thumbsup::skin-tone-2` are kept.
+pub(crate) fn reaction_name(name: &str) -> Result<String> {
+    let name = name.trim().trim_matches(':');
+    if name.is_empty() {
+        return Err(SlackError::Validation(
+            "Reaction name must not be empty".to_string(),
+        ));
+    }
+    if name.chars().any(char::is_whitespace) {
+        return Err(SlackError::Validation(format!(
+            "Reaction name '{}' must not contain whitespace",
+            name
+        )));
+    }
+    Ok(name.to_lowercase())
+}
+
+/// Treats an API error with the given code as success when `tolerate` is set.
+///
+/// Returns `Ok(true)` if the call succeeded and `Ok(false)` if it failed
+/// with `code`, meaning the target was already in the requested state.
+pub(crate) fn tolerate_error_code<T>(
+    result: Result<T>,
+    code: &str,
+    tolerate: bool,
+) -> Result<bool> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(SlackError::ApiError { response, .. }) if tolerate && response["error"] == code => {
+            Ok(false)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Extracts the member IDs from a `usergroups.users.list` response.
+pub(crate) fn usergroup_member_ids(data: &Value) -> Vec<String> {
+    data["users"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|id| id.as_str().map(str::to_string))
+        .collect()
+}
+
+/// Fails with `ConcurrentModification` if `current` does not hold the same
+/// members as `expected`, ignoring order.
+pub(crate) fn check_expected_members(
+    usergroup_id: &str,
+    current: &[String],
+    expected: Option<&[String]>,
+) -> Result<()> {
+    let Some(expected) = expected else {
+        return Ok(());
+    };
+
+    let current_set: HashSet<&str> = current.iter().map(String::as_str).collect();
+    let expected_set: HashSet<&str> = expected.iter().map(String::as_str).collect();
+    if current_set != expected_set {
+        return Err(SlackError::ConcurrentModification(format!(
+            "members of usergroup {} changed: expected {:?}, found {:?}",
+            usergroup_id, expected, current
+        )));
+    }
+    Ok(())
+}
+
+/// Adds or removes `user_ids` from `current`, keeping the existing order.
+///
+/// Returns `None` if nothing changes. Removing every member is rejected
+/// because `usergroups.users.update` requires at least one user; disable the
+/// usergroup instead.
+pub(crate) fn change_members<S: AsRef<str>>(
+    current: &[String],
+    user_ids: &[S],
+    remove: bool,
+) -> Result<Option<Vec<String>>> {
+    let mut members = current.to_vec();
+    if remove {
+        let removed: HashSet<&str> = user_ids.iter().map(AsRef::as_ref).collect();
+        members.retain(|id| !removed.contains(id.as_str()));
+    } else {
+        for id in user_ids {
+            let id = id.as_ref();
+            if !members.iter().any(|member| member == id) {
+                members.push(id.to_string());
+            }
+        }
+    }
+
+    if members == current {
+        return Ok(None);
+    }
+    if members.is_empty() {
+        return Err(SlackError::Validation(
+            "Cannot remove every member of a usergroup; disable it instead".to_string(),
+        ));
+    }
+    Ok(Some(members))
+}
+
+/// Asks the retry handlers whether an `ok: false` response should be
+/// retried, handing them a snapshot of the response body.
+///
+/// Only successful responses with `"ok": false` bodies are considered;
+/// rate limits and server errors are retried by status before the body is
+/// read.
+pub(crate) fn should_retry_response(
+    handlers: &[Box<dyn RetryHandler + Send + Sync>],
+    attempt: usize,
+    status_code: u16,
+    headers: &reqwest::header::HeaderMap,
+    data: &Value,
+) -> bool {
+    if !(200..300).contains(&status_code) || data.get("ok").and_then(Value::as_bool) != Some(false)
+    {
+        return false;
+    }
+
+    let snapshot = HttpResponse {
+        status_code,
+        headers: headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect(),
+        body: serde_json::to_vec(data).unwrap_or_default(),
+    };
+    let mut state = RetryState::new();
+    state.current_attempt = u32::try_from(attempt).unwrap_or(u32::MAX);
+    state.set_last_response(snapshot);
+
+    handlers
+        .iter()
+        .any(|handler| handler.can_retry(&state, state.last_response.as_ref(), None))
+}
+
+/// Checks the chunk size for a bulk `conversations.invite`.
+pub(crate) fn check_invite_chunk_size(chunk_size: usize) -> Result<()> {
+    if chunk_size == 0 || chunk_size > MAX_INVITE_USERS {
+        return Err(SlackError::Validation(format!(
+            "Invite chunk size must be between 1 and {}, got {}",
+            MAX_INVITE_USERS, chunk_size
+        )));
+    }
+    Ok(())
+}
+
+/// Records the outcome of one `conversations.invite` call for `chunk`.
+///
+/// With `force`, Slack lists users it could not invite in `errors[]`, and
+/// everyone else in the chunk was invited. A failure without `errors[]`
+/// applies to the whole chunk, except `already_in_channel` for a single
+/// user.
+pub(crate) fn record_invite_outcome<S: AsRef<str>>(
+    report: &mut InviteReport,
+    chunk: &[S],
+    outcome: std::result::Result<&Value, &SlackError>,
+) {
+    let (errors, failure) = match outcome {
+        Ok(data) => (data["errors"].as_array(), None),
+        Err(SlackError::ApiError { response, .. }) => match response["errors"].as_array() {
+            Some(errors) => (Some(errors), None),
+            None => (
+                None,
+                Some(
+                    response["error"]
+                        .as_str()
+                        .unwrap_or("unknown_error")
+                        .to_string(),
+                ),
+            ),
+        },
+        Err(e) => (None, Some(e.to_string())),
+    };
+
+    if let Some(code) = failure {
+        for user in chunk {
+            let user = user.as_ref().to_string();
+            if code == "already_in_channel" && chunk.len() == 1 {
+                report.already_in_channel.push(user);
+            } else {
+                report.failed.push((user, code.clone()));
+            }
+        }
+        return;
+    }
+
+    let user_errors: HashMap<&str, &str> = errors
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            Some((
+                entry["user"].as_str()?,
+                entry["error"].as_str().unwrap_or("unknown_error"),
+            ))
+        })
+        .collect();
+    for user in chunk {
+        let user = user.as_ref();
+        match user_errors.get(user) {
+            None => report.invited.push(user.to_string()),
+            Some(&"already_in_channel") => report.already_in_channel.push(user.to_string()),
+            Some(code) => report.failed.push((user.to_string(), code.to_string())),
+        }
+    }
+}
+
 /// Converts boolean values to "0" or "1" strings.
 ///
 /// Slack APIs accept "0"/"1" as boolean values, which is more reliable
@@ -206,10 +868,49 @@ pub fn next_cursor_is_present(data: &Value) -> bool {
 // For now, let's provide a simple implementation that doesn't require external crates
 // We'll use a simpler version detection
 
+/// Client settings read by `from_env` on the Web API client builders.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct EnvConfig {
+    pub token: String,
+    pub base_url: Option<String>,
+    pub timeout: Duration,
+    pub max_retries: usize,
+}
+
+/// Reads `SLACK_BOT_TOKEN` (required), `SLACK_API_BASE_URL`,
+/// `SLACK_TIMEOUT_SECS` (default 30) and `SLACK_MAX_RETRIES` (default 3)
+/// through `lookup`. Empty values count as unset.
+pub(crate) fn env_config(lookup: impl Fn(&str) -> Option<String>) -> Result<EnvConfig> {
+    let var = |name: &str| lookup(name).filter(|value| !value.trim().is_empty());
+    let number = |name: &str, default: u64| -> Result<u64> {
+        match var(name) {
+            Some(value) => value.trim().parse().map_err(|_| SlackError::InvalidInput {
+                message: format!(
+                    "Environment variable {} must be a non-negative integer, got {:?}",
+                    name, value
+                ),
+            }),
+            None => Ok(default),
+        }
+    };
+
+    let token = var("SLACK_BOT_TOKEN").ok_or_else(|| SlackError::InvalidInput {
+        message: "Environment variable SLACK_BOT_TOKEN is not set".to_string(),
+    })?;
+    Ok(EnvConfig {
+        token,
+        base_url: var("SLACK_API_BASE_URL"),
+        timeout: Duration::from_secs(number("SLACK_TIMEOUT_SECS", 30)?),
+        max_retries: number("SLACK_MAX_RETRIES", 3)? as usize,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flate2::read::GzDecoder;
     use serde_json::json;
+    use std::io::Read;
 
     #[test]
     fn test_get_url() {
@@ -304,6 +1005,92 @@ mod tests {
         assert_eq!(headers.get("X-Request"), Some(&"request-value".to_string()));
     }
 
+    #[test]
+    fn test_get_headers_defaults_cannot_replace_protected_headers() {
+        let mut default_headers = HashMap::new();
+        default_headers.insert("authorization".to_string(), "Bearer other".to_string());
+        default_headers.insert("Content-Type".to_string(), "text/plain".to_string());
+        default_headers.insert("user-agent".to_string(), "custom".to_string());
+
+        let mut request_headers = HashMap::new();
+        request_headers.insert("USER-AGENT".to_string(), "per-call".to_string());
+
+        let headers = get_headers(Some("xoxb-token"), true, false, &default_headers, None);
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["Authorization"], "Bearer xoxb-token");
+        assert_eq!(headers["Content-Type"], "application/json;charset=utf-8");
+        assert_eq!(headers["User-Agent"], "custom");
+
+        let headers = get_headers(
+            Some("xoxb-token"),
+            true,
+            false,
+            &default_headers,
+            Some(&request_headers),
+        );
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers["User-Agent"], "per-call");
+    }
+
+    #[test]
+    fn test_apply_default_headers() {
+        use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bearer xoxp-1"));
+        let mut defaults = HashMap::new();
+        defaults.insert("Authorization".to_string(), "Bearer other".to_string());
+        defaults.insert("X-Request-Source".to_string(), "billing".to_string());
+
+        apply_default_headers(&mut headers, &defaults).unwrap();
+        assert_eq!(headers[AUTHORIZATION], "Bearer xoxp-1");
+        assert_eq!(headers["x-request-source"], "billing");
+
+        defaults.insert("bad header".to_string(), "x".to_string());
+        assert!(apply_default_headers(&mut headers, &defaults).is_err());
+    }
+
+    #[test]
+    fn test_parse_response_body() {
+        use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+
+        let mut html = HeaderMap::new();
+        html.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+
+        assert_eq!(
+            parse_response_body(200, &HeaderMap::new(), br#"{"ok": true}"#).unwrap(),
+            json!({"ok": true})
+        );
+
+        let page = format!("<html><body>{}</body></html>", "Bad Gateway ".repeat(100));
+        match parse_response_body(502, &html, page.as_bytes()) {
+            Err(SlackError::UnexpectedResponse {
+                status,
+                content_type,
+                body_snippet,
+            }) => {
+                assert_eq!(status, 502);
+                assert_eq!(content_type.as_deref(), Some("text/html"));
+                assert_eq!(body_snippet.chars().count(), BODY_SNIPPET_CHARS);
+                assert!(body_snippet.starts_with("<html><body>Bad Gateway"));
+            }
+            other => panic!("expected UnexpectedResponse, got {:?}", other),
+        }
+
+        let empty = parse_response_body(204, &HeaderMap::new(), b"").unwrap_err();
+        assert_eq!(
+            empty.to_string(),
+            "Unexpected response (HTTP 204, no content type): empty body"
+        );
+        assert_eq!(empty.http_status(), Some(204));
+
+        let truncated = parse_response_body(200, &HeaderMap::new(), br#"{"ok": true, "chan"#);
+        assert!(matches!(
+            truncated,
+            Err(SlackError::UnexpectedResponse { body_snippet, .. }) if body_snippet == r#"{"ok": true, "chan"#
+        ));
+    }
+
     #[test]
     fn test_convert_bool_to_0_or_1() {
         assert_eq!(convert_bool_to_0_or_1(&json!(true)), json!("1"));
@@ -365,4 +1152,364 @@ mod tests {
         });
         assert!(next_cursor_is_present(&data4));
     }
+
+    #[test]
+    fn test_serialize_json_body_limit() {
+        let payload = json!({"blocks": [{"type": "section", "text": "x".repeat(100)}]});
+        let size = serde_json::to_vec(&payload).unwrap().len();
+
+        assert!(serialize_json_body(&payload, None).is_ok());
+        assert!(serialize_json_body(&payload, Some(size)).is_ok());
+
+        match serialize_json_body(&payload, Some(size - 1)) {
+            Err(SlackError::Validation(msg)) => {
+                assert!(msg.starts_with(&format!("payload too large: {} bytes", size)))
+            }
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_gzip_body_round_trip() {
+        let body = br#"{"text":"hello hello hello hello"}"#;
+        let compressed = gzip_body(body).unwrap();
+
+        let mut decompressed = Vec::new();
+        GzDecoder::new(compressed.as_slice())
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, body);
+    }
+
+    #[test]
+    fn test_check_retry_wait() {
+        let wait = Duration::from_secs(60);
+        assert!(check_retry_wait(wait, None).is_ok());
+        assert!(check_retry_wait(wait, Some(Duration::from_secs(60))).is_ok());
+        assert!(matches!(
+            check_retry_wait(wait, Some(Duration::from_secs(10))),
+            Err(SlackError::Ratelimited { retry_after }) if retry_after == wait
+        ));
+    }
+
+    #[test]
+    fn test_check_post_at() {
+        let now = chrono::Utc::now();
+
+        assert_eq!(
+            check_post_at(now + chrono::Duration::hours(1), now).unwrap(),
+            (now + chrono::Duration::hours(1)).timestamp()
+        );
+
+        let err = check_post_at(now - chrono::Duration::seconds(1), now).unwrap_err();
+        assert!(err.to_string().contains("time_in_past"), "{}", err);
+
+        let err = check_post_at(now + chrono::Duration::days(121), now).unwrap_err();
+        assert!(matches!(&err, SlackError::Validation(m) if m.starts_with("time_too_far")));
+    }
+
+    #[test]
+    fn test_scheduled_messages_params() {
+        let oldest = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let latest = DateTime::from_timestamp(1_700_086_400, 0).unwrap();
+
+        assert_eq!(
+            scheduled_messages_params("C1", &(oldest..latest)),
+            json!({"channel": "C1", "oldest": "1700000000", "latest": "1700086400"})
+        );
+        assert_eq!(
+            scheduled_messages_params("C1", &(oldest..)),
+            json!({"channel": "C1", "oldest": "1700000000"})
+        );
+        assert_eq!(
+            scheduled_messages_params("C1", &..),
+            json!({"channel": "C1"})
+        );
+    }
+
+    #[test]
+    fn test_reaction_name() {
+        assert_eq!(reaction_name(":ThumbsUp:").unwrap(), "thumbsup");
+        assert_eq!(
+            reaction_name("thumbsup::skin-tone-2").unwrap(),
+            "thumbsup::skin-tone-2"
+        );
+        assert!(reaction_name("::").is_err());
+        assert!(reaction_name("thumbs up").is_err());
+    }
+
+    #[test]
+    fn test_tolerate_error_code() {
+        let already = || -> Result<()> {
+            Err(SlackError::api_error_with_response(
+                "already_reacted",
+                json!({"ok": false, "error": "already_reacted"}),
+            ))
+        };
+
+        assert!(tolerate_error_code(Ok(()), "already_reacted", true).unwrap());
+        assert!(!tolerate_error_code(already(), "already_reacted", true).unwrap());
+        assert!(tolerate_error_code(already(), "already_reacted", false).is_err());
+        assert!(tolerate_error_code(already(), "no_reaction", true).is_err());
+    }
+
+    #[test]
+    fn test_change_members() {
+        let current = vec!["U1".to_string(), "U2".to_string()];
+
+        assert_eq!(
+            change_members(&current, &["U3", "U1"], false).unwrap(),
+            Some(vec!["U1".to_string(), "U2".to_string(), "U3".to_string()])
+        );
+        assert_eq!(
+            change_members(&current, &["U1"], true).unwrap(),
+            Some(vec!["U2".to_string()])
+        );
+        assert_eq!(change_members(&current, &["U2"], false).unwrap(), None);
+        assert_eq!(change_members(&current, &["U9"], true).unwrap(), None);
+        assert!(change_members(&current, &["U1", "U2"], true).is_err());
+    }
+
+    #[test]
+    fn test_check_expected_members() {
+        let current = vec!["U1".to_string(), "U2".to_string()];
+
+        assert!(check_expected_members("S1", &current, None).is_ok());
+        assert!(check_expected_members(
+            "S1",
+            &current,
+            Some(&["U2".to_string(), "U1".to_string()])
+        )
+        .is_ok());
+        assert!(matches!(
+            check_expected_members("S1", &current, Some(&["U1".to_string()])),
+            Err(SlackError::ConcurrentModification(_))
+        ));
+    }
+
+    #[test]
+    fn test_record_invite_outcome_partial() {
+        let mut report = InviteReport::default();
+        let data = json!({
+            "ok": true,
+            "errors": [
+                {"user": "U2", "ok": false, "error": "already_in_channel"},
+                {"user": "U3", "ok": false, "error": "user_is_restricted"}
+            ]
+        });
+
+        record_invite_outcome(&mut report, &["U1", "U2", "U3"], Ok(&data));
+
+        assert_eq!(report.invited, ["U1"]);
+        assert_eq!(report.already_in_channel, ["U2"]);
+        assert_eq!(
+            report.failed,
+            [("U3".to_string(), "user_is_restricted".to_string())]
+        );
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn test_record_invite_outcome_whole_chunk_failure() {
+        let mut report = InviteReport::default();
+        let error = SlackError::api_error_with_response(
+            "failed",
+            json!({"ok": false, "error": "channel_not_found"}),
+        );
+
+        record_invite_outcome(&mut report, &["U1", "U2"], Err(&error));
+        assert_eq!(report.failed.len(), 2);
+        assert!(report.invited.is_empty());
+
+        let already = SlackError::api_error_with_response(
+            "failed",
+            json!({"ok": false, "error": "already_in_channel"}),
+        );
+        record_invite_outcome(&mut report, &["U3"], Err(&already));
+        assert_eq!(report.already_in_channel, ["U3"]);
+    }
+
+    #[test]
+    fn test_is_trusted_download_host() {
+        let trusted = |url: &str| {
+            is_trusted_download_host(&reqwest::Url::parse(url).unwrap(), "https://slack.com/api/")
+        };
+
+        assert!(trusted(
+            "https://files.slack.com/files-pri/T1-F1/download/a.txt"
+        ));
+        assert!(trusted("https://slack.com/x"));
+        assert!(!trusted("http://files.slack.com/a.txt"));
+        assert!(!trusted("https://slack.com.evil.example/a.txt"));
+        assert!(!trusted("https://cdn.example.com/a.txt"));
+
+        let local = reqwest::Url::parse("http://127.0.0.1:9000/file").unwrap();
+        assert!(is_trusted_download_host(
+            &local,
+            "http://127.0.0.1:9000/api/"
+        ));
+        assert!(!is_trusted_download_host(
+            &local,
+            "http://127.0.0.1:9001/api/"
+        ));
+    }
+
+    #[test]
+    fn test_download_error_forbidden_hint() {
+        let err = download_error(403, &reqwest::header::HeaderMap::new());
+        assert!(err.to_string().contains("files:read"));
+        assert_eq!(err.http_status(), Some(403));
+        assert!(matches!(err, SlackError::ApiError { .. }));
+
+        let err = download_error(404, &reqwest::header::HeaderMap::new());
+        assert!(matches!(
+            err,
+            SlackError::HttpError {
+                status: Some(404),
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_emoji_map_resolves_aliases() {
+        let data = json!({
+            "ok": true,
+            "emoji": {
+                "party_parrot": "https://emoji.slack-edge.com/T1/party_parrot/abc.gif",
+                "parrot": "alias:party_parrot",
+                "parrot_again": "alias:parrot",
+                "thumbs": "alias:thumbsup"
+            }
+        });
+
+        let raw = emoji_map(&data, false);
+        assert_eq!(
+            raw["parrot_again"],
+            EmojiSource::Alias("parrot".to_string())
+        );
+
+        let resolved = emoji_map(&data, true);
+        let url = EmojiSource::Url("https://emoji.slack-edge.com/T1/party_parrot/abc.gif".into());
+        assert_eq!(resolved["parrot"], url);
+        assert_eq!(resolved["parrot_again"], url);
+        assert_eq!(
+            resolved["thumbs"],
+            EmojiSource::Alias("thumbsup".to_string())
+        );
+    }
+
+    #[test]
+    fn test_emoji_map_alias_cycle() {
+        let data = json!({
+            "emoji": {
+                "a": "alias:b",
+                "b": "alias:c",
+                "c": "alias:a",
+                "self": "alias:self"
+            }
+        });
+
+        let resolved = emoji_map(&data, true);
+        assert_eq!(resolved["a"], EmojiSource::Alias("b".to_string()));
+        assert_eq!(resolved["c"], EmojiSource::Alias("a".to_string()));
+        assert_eq!(resolved["self"], EmojiSource::Alias("self".to_string()));
+    }
+
+    #[test]
+    fn test_request_pacer_burst_then_interval() {
+        use crate::clock::MockClock;
+
+        let clock = MockClock::default();
+        // 20 per minute (one every 3s) with a burst of 2
+        let mut pacer =
+            RequestPacer::new(RateLimit::new(20, 2)).with_clock(Arc::new(clock.clone()));
+
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+        assert_eq!(pacer.reserve(), Duration::from_secs(3));
+        assert_eq!(pacer.reserve(), Duration::from_secs(6));
+
+        // After waiting out both reservations the next call waits one interval
+        clock.advance(Duration::from_secs(6));
+        assert_eq!(pacer.reserve(), Duration::from_secs(3));
+
+        // A long idle period refills the burst, but no further
+        clock.advance(Duration::from_secs(60));
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+        assert_eq!(pacer.reserve(), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_request_pacer_defer() {
+        use crate::clock::MockClock;
+
+        let clock = MockClock::default();
+        let mut pacer =
+            RequestPacer::new(RateLimit::new(20, 5)).with_clock(Arc::new(clock.clone()));
+
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+        pacer.defer(Duration::from_secs(30));
+        assert_eq!(pacer.reserve(), Duration::from_secs(30));
+        assert_eq!(pacer.reserve(), Duration::from_secs(33));
+
+        clock.advance(Duration::from_secs(40));
+        assert_eq!(pacer.reserve(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rate_limit_wait() {
+        let default = Duration::from_secs(3);
+        let limited = SlackError::Ratelimited {
+            retry_after: Duration::from_secs(20),
+        };
+        assert_eq!(
+            rate_limit_wait(&limited, default),
+            Some(Duration::from_secs(20))
+        );
+
+        let api = SlackError::api_error_with_response("x", json!({"error": "ratelimited"}));
+        assert_eq!(rate_limit_wait(&api, default), Some(default));
+
+        let other = SlackError::api_error_with_response("x", json!({"error": "error_bad_upload"}));
+        assert_eq!(rate_limit_wait(&other, default), None);
+    }
+
+    #[test]
+    fn test_env_config() {
+        let vars: HashMap<&str, &str> = [
+            ("SLACK_BOT_TOKEN", "xoxb-env"),
+            ("SLACK_API_BASE_URL", "http://localhost:8080/api/"),
+            ("SLACK_TIMEOUT_SECS", "5"),
+            ("SLACK_MAX_RETRIES", ""),
+        ]
+        .into_iter()
+        .collect();
+        let config = env_config(|name| vars.get(name).map(|v| v.to_string())).unwrap();
+        assert_eq!(
+            config,
+            EnvConfig {
+                token: "xoxb-env".to_string(),
+                base_url: Some("http://localhost:8080/api/".to_string()),
+                timeout: Duration::from_secs(5),
+                max_retries: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn test_env_config_errors() {
+        let missing = env_config(|_| None).unwrap_err();
+        assert!(missing.to_string().contains("SLACK_BOT_TOKEN"));
+
+        let invalid = env_config(|name| match name {
+            "SLACK_BOT_TOKEN" => Some("xoxb-env".to_string()),
+            "SLACK_MAX_RETRIES" => Some("many".to_string()),
+            _ => None,
+        })
+        .unwrap_err();
+        assert!(matches!(invalid, SlackError::InvalidInput { .. }));
+        assert!(invalid.to_string().contains("SLACK_MAX_RETRIES"));
+    }
 }
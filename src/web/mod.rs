@@ -75,11 +75,38 @@
 //! ```
 
 pub mod async_client;
+pub mod builders;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod client;
+pub mod diagnostics;
 pub mod internal_utils;
+pub mod manifest;
+pub mod requests;
 pub mod response;
+pub mod snapshot;
+pub mod token_check;
+pub mod token_provider;
+pub mod types;
 
 // Re-export main types
-pub use async_client::{AsyncWebClient, AsyncWebClientBuilder};
+pub use async_client::{AsyncWebClient, AsyncWebClientBuilder, UploadProgress};
+pub use builders::{
+    ChatPostMessageBuilder, ChatUpdateBuilder, ConversationsListFilter, MethodRequest,
+};
 pub use client::{WebClient, WebClientBuilder};
-pub use response::SlackResponse;
+pub use diagnostics::{Diagnostics, TokenType};
+pub use manifest::AppManifest;
+pub use requests::{
+    ConversationType, ConversationsHistoryParams, ConversationsHistoryRequest,
+    ConversationsListRequest, EmojiSource, InviteReport, MessageArgs, RequestEncoding,
+    RequestOptions, ScheduledMessage, TimestampParam, UserRef, UsersConversationsRequest,
+};
+pub use response::{SerializableHeaders, SerializableSlackResponse, SlackResponse};
+pub use snapshot::{SnapshotOptions, SnapshotProgress};
+pub use token_provider::TokenProvider;
+pub use types::{
+    AuthTestResponse, Channel, ConversationHistoryResponse, ConversationInfo,
+    ConversationInfoResponse, ConversationTopic, Member, PostMessageResponse, ResponseMetadata,
+    UserInfoResponse, UserProfile,
+};
@@ -0,0 +1,92 @@
+//! Per-workspace token lookup for multi-tenant apps.
+//!
+//! An app distributed to many workspaces holds one bot token per workspace.
+//! A [`TokenProvider`] set on the client picks the token for each request
+//! from the `team_id` in its [`RequestOptions`](crate::web::RequestOptions),
+//! so one [`AsyncWebClient`](crate::web::AsyncWebClient) can serve every
+//! workspace.
+//!
+//! Every [`InstallationStore`] is a `TokenProvider` that returns the
+//! workspace's latest bot token.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use slack_rs::oauth::installation_store::file::FileInstallationStore;
+//! use slack_rs::web::{AsyncWebClient, RequestOptions};
+//! use std::sync::Arc;
+//!
+//! # async fn example() -> slack_rs::error::Result<()> {
+//! let store = FileInstallationStore::new("./data/installations");
+//! let client = AsyncWebClient::builder()
+//!     .token_provider(Arc::new(store))
+//!     .build();
+//!
+//! let opts = RequestOptions::new().team_id("T123456");
+//! client
+//!     .api_call_with_options(
+//!         "chat.postMessage",
+//!         Some(serde_json::json!({"channel": "C123456", "text": "Hello"})),
+//!         opts,
+//!     )
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::error::{Result, SlackError};
+use crate::oauth::installation_store::InstallationStore;
+use async_trait::async_trait;
+
+/// Looks up the token to use for a workspace.
+#[async_trait]
+pub trait TokenProvider: Send + Sync {
+    /// Returns the token for `team_id`.
+    ///
+    /// # Errors
+    ///
+    /// Implementations should return `SlackError::NotFound` when the
+    /// workspace has no token.
+    async fn get_token(&self, team_id: &str) -> Result<String>;
+}
+
+#[async_trait]
+impl<T: InstallationStore + ?Sized> TokenProvider for T {
+    async fn get_token(&self, team_id: &str) -> Result<String> {
+        if let Some(bot) = self.find_bot(None, Some(team_id), false).await? {
+            return Ok(bot.bot_token);
+        }
+        self.find_installation(None, Some(team_id), None, false)
+            .await?
+            .and_then(|installation| installation.bot_token)
+            .ok_or_else(|| SlackError::not_found(format!("No bot token for team {}", team_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::oauth::installation_store::cache::CacheInstallationStore;
+    use crate::oauth::models::Installation;
+
+    #[tokio::test]
+    async fn test_installation_store_provides_bot_tokens() {
+        let store = CacheInstallationStore::new();
+        store
+            .save(
+                Installation::new("U1")
+                    .team_id("T1")
+                    .bot_token("xoxb-t1")
+                    .bot_id("B1")
+                    .bot_user_id("U2"),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(store.get_token("T1").await.unwrap(), "xoxb-t1");
+        assert!(matches!(
+            store.get_token("T2").await,
+            Err(SlackError::NotFound(_))
+        ));
+    }
+}
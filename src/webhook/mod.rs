@@ -56,14 +56,26 @@
 //! - Custom headers and timeouts
 //! - Automatic retry with exponential backoff
 //! - Proxy support
+//! - Periodic delivery via `AsyncWebhookClient::send_periodic` (requires the `scheduler` feature)
 
+use crate::environment::SlackEnvironment;
 use crate::error::{Result, SlackError};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use crate::logging::summarize_text;
+use crate::models::blocks::{builder_blocks, checked_builder_blocks, validate_block_values};
+use crate::web::internal_utils::serialize_json_body;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
 use serde::Serialize;
 use serde_json::Value;
 use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
+use tracing::debug;
+
+#[cfg(feature = "scheduler")]
+mod scheduler;
+
+/// Wait before retrying a 429 response that has no usable `Retry-After`.
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
 
 /// Response from a webhook request.
 ///
@@ -106,6 +118,119 @@ impl WebhookResponse {
     pub fn is_rate_limited(&self) -> bool {
         self.status_code == 429
     }
+
+    /// Returns how long Slack asked to wait before retrying, from the
+    /// `Retry-After` header.
+    ///
+    /// Both delta-seconds (`30`) and HTTP-date
+    /// (`Wed, 21 Oct 2015 07:28:00 GMT`) values are understood; a date in
+    /// the past gives a zero duration. Returns None if the header is
+    /// missing or malformed.
+    pub fn retry_after(&self) -> Option<Duration> {
+        self.retry_after_at(chrono::Utc::now())
+    }
+
+    fn retry_after_at(&self, now: chrono::DateTime<chrono::Utc>) -> Option<Duration> {
+        let value = self
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+            .map(|(_, value)| value.trim())?;
+
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+        let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+        Some(
+            (date.with_timezone(&chrono::Utc) - now)
+                .to_std()
+                .unwrap_or_default(),
+        )
+    }
+}
+
+/// One-line summary such as
+/// `POST https://hooks.slack.com/... -> 429 rate_limited (retry-after: 30s)`.
+///
+/// Only the URL's origin is shown, since the path of a webhook URL is its
+/// secret. The body is redacted and cut to
+/// [`MAX_SUMMARY_CHARS`](crate::logging::MAX_SUMMARY_CHARS) characters.
+impl std::fmt::Display for WebhookResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let origin = reqwest::Url::parse(&self.url)
+            .map(|url| url.origin().ascii_serialization())
+            .unwrap_or_default();
+        write!(f, "POST {}/... -> {}", origin, self.status_code)?;
+
+        let body = summarize_text(&self.body);
+        if !body.is_empty() {
+            write!(f, " {}", body)?;
+        }
+
+        let retry_after = self
+            .headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("retry-after"))
+            .map(|(_, value)| value);
+        if let Some(retry_after) = retry_after {
+            write!(f, " (retry-after: {}s)", summarize_text(retry_after))?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that `url` extends one of the given hooks base URLs, such as
+/// `https://hooks.slack.com/`, with a non-empty path.
+fn validate_webhook_url(url: &str, hooks_urls: &[&str]) -> Result<()> {
+    let is_hooks_url = reqwest::Url::parse(url).is_ok_and(|parsed| {
+        hooks_urls.iter().any(|hooks_url| {
+            reqwest::Url::parse(hooks_url).is_ok_and(|base| {
+                parsed.origin() == base.origin()
+                    && parsed.path().starts_with(base.path())
+                    && parsed.path().len() > base.path().len()
+            })
+        })
+    });
+
+    if is_hooks_url {
+        Ok(())
+    } else {
+        Err(SlackError::Validation(
+            "URL does not look like a Slack webhook URL".to_string(),
+        ))
+    }
+}
+
+/// Hooks base URLs accepted by `validated`: commercial Slack and GovSlack.
+fn default_hooks_urls() -> [&'static str; 2] {
+    [
+        SlackEnvironment::Commercial.hooks_url(),
+        SlackEnvironment::Gov.hooks_url(),
+    ]
+}
+
+/// Adds the legacy overrides that are set to `body`.
+///
+/// Only webhooks from the legacy Incoming WebHooks integration honour them,
+/// so their use is logged at debug level to explain a message that ignored
+/// them.
+fn insert_legacy_overrides(
+    body: &mut serde_json::Map<String, Value>,
+    overrides: [(&str, Option<String>); 4],
+) {
+    let mut fields = Vec::new();
+    for (name, value) in overrides {
+        if let Some(value) = value {
+            body.insert(name.to_string(), Value::String(value));
+            fields.push(name);
+        }
+    }
+    if !fields.is_empty() {
+        debug!(
+            ?fields,
+            "Sending legacy webhook overrides; webhooks created by Slack apps ignore them"
+        );
+    }
 }
 
 /// Synchronous webhook client for sending messages to Slack.
@@ -118,10 +243,53 @@ pub struct WebhookClient {
     timeout: Duration,
     default_headers: HashMap<String, String>,
     proxy: Option<String>,
+    max_payload_bytes: Option<usize>,
     client: reqwest::blocking::Client,
 }
 
 impl WebhookClient {
+    /// Creates a new webhook client, first checking that `url` is a
+    /// Slack hooks URL.
+    ///
+    /// Accepts `https://hooks.slack.com/...` and GovSlack's
+    /// `https://hooks.slack-gov.com/...` URLs, which covers incoming webhooks
+    /// (`/services/`) and response URLs (`/commands/`, `/actions/`). This
+    /// catches a mistyped or wrong URL before the first send rather than as
+    /// a 404 later. Use [`new`](Self::new) for webhooks served from a custom
+    /// domain, which this check would reject.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the URL is not on a Slack hooks
+    /// host.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slack_rs::webhook::WebhookClient;
+    ///
+    /// assert!(WebhookClient::validated("https://hooks.slack.com/services/T00/B00/XXX").is_ok());
+    /// assert!(WebhookClient::validated("https://example.com/hook").is_err());
+    /// ```
+    pub fn validated(url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        validate_webhook_url(&url, &default_hooks_urls())?;
+        Ok(Self::new(url))
+    }
+
+    /// Creates a new webhook client, first checking that `url` is under
+    /// the hooks base URL of the given Slack environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the URL is not under
+    /// [`SlackEnvironment::hooks_url`].
+    pub fn validated_for(url: impl Into<String>, environment: &SlackEnvironment) -> Result<Self> {
+        let url = url.into();
+        validate_webhook_url(&url, &[environment.hooks_url()])?;
+        Ok(Self::new(url))
+    }
+
     /// Creates a new webhook client.
     ///
     /// # Arguments
@@ -152,6 +320,7 @@ impl WebhookClient {
             timeout: Duration::from_secs(30),
             default_headers,
             proxy: None,
+            max_payload_bytes: None,
             client,
         }
     }
@@ -194,6 +363,15 @@ impl WebhookClient {
         self
     }
 
+    /// Rejects payloads whose serialized JSON exceeds `bytes`.
+    ///
+    /// Oversized payloads fail with `SlackError::Validation` before sending.
+    /// There is no limit by default.
+    pub fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = Some(bytes);
+        self
+    }
+
     /// Creates a new message builder.
     ///
     /// # Example
@@ -229,7 +407,10 @@ impl WebhookClient {
             request_headers.extend(h.clone());
         }
 
+        let payload = serialize_json_body(body, self.max_payload_bytes)?;
+
         let mut header_map = HeaderMap::new();
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         for (key, value) in request_headers {
             if let (Ok(name), Ok(val)) = (HeaderName::from_str(&key), HeaderValue::from_str(&value))
             {
@@ -241,7 +422,7 @@ impl WebhookClient {
             .client
             .post(&self.url)
             .headers(header_map)
-            .json(body)
+            .body(payload)
             .send()
             .map_err(SlackError::Http)?;
 
@@ -272,7 +453,7 @@ impl WebhookClient {
 pub struct SendMessageBuilder<'a> {
     client: &'a WebhookClient,
     text: Option<String>,
-    blocks: Option<Value>,
+    blocks: Option<std::result::Result<Value, String>>,
     attachments: Option<Value>,
     response_type: Option<String>,
     replace_original: Option<bool>,
@@ -280,6 +461,10 @@ pub struct SendMessageBuilder<'a> {
     unfurl_links: Option<bool>,
     unfurl_media: Option<bool>,
     metadata: Option<Value>,
+    channel: Option<String>,
+    username: Option<String>,
+    icon_emoji: Option<String>,
+    icon_url: Option<String>,
     headers: Option<HashMap<String, String>>,
 }
 
@@ -296,6 +481,10 @@ impl<'a> SendMessageBuilder<'a> {
             unfurl_links: None,
             unfurl_media: None,
             metadata: None,
+            channel: None,
+            username: None,
+            icon_emoji: None,
+            icon_url: None,
             headers: None,
         }
     }
@@ -308,9 +497,10 @@ impl<'a> SendMessageBuilder<'a> {
 
     /// Sets the blocks for the message.
     ///
-    /// Accepts either a `Vec<Block>` or raw JSON value.
+    /// Accepts either a `Vec<Block>` or raw JSON value. Blocks given as a
+    /// JSON-encoded string, e.g. copied from a fetched message, are decoded.
     pub fn blocks<T: Serialize>(mut self, blocks: T) -> Self {
-        self.blocks = serde_json::to_value(blocks).ok();
+        self.blocks = Some(builder_blocks(blocks));
         self
     }
 
@@ -356,6 +546,43 @@ impl<'a> SendMessageBuilder<'a> {
         self
     }
 
+    /// Overrides the channel a legacy incoming webhook posts to.
+    ///
+    /// Webhooks created by Slack apps always post to their configured
+    /// channel and silently ignore this, as they do the username and icon
+    /// overrides.
+    pub fn channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Overrides the display name of a legacy incoming webhook.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Overrides the icon of a legacy incoming webhook with an emoji, such
+    /// as `:ghost:`.
+    ///
+    /// Only one icon is sent: this replaces any [`icon_url`](Self::icon_url)
+    /// set earlier.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<String>) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self.icon_url = None;
+        self
+    }
+
+    /// Overrides the icon of a legacy incoming webhook with an image URL.
+    ///
+    /// Only one icon is sent: this replaces any
+    /// [`icon_emoji`](Self::icon_emoji) set earlier.
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self.icon_emoji = None;
+        self
+    }
+
     /// Adds a custom header for this request only.
     pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.headers
@@ -365,14 +592,54 @@ impl<'a> SendMessageBuilder<'a> {
     }
 
     /// Executes the webhook request.
+    ///
+    /// Blocks are checked with
+    /// [`validate_blocks`](crate::models::blocks::validate_blocks) first.
     pub fn execute(self) -> Result<WebhookResponse> {
+        let client = self.client;
+        let (body, headers) = self.into_body()?;
+        client.send_dict(&body, headers.as_ref())
+    }
+
+    /// Executes the webhook request, re-sending it up to `max_retries`
+    /// times while Slack responds with 429.
+    ///
+    /// Before each retry the thread sleeps for the response's
+    /// [`retry_after`](WebhookResponse::retry_after), or one second if it
+    /// has none. The last response is returned, so a request still rate
+    /// limited after the final retry returns its 429 response.
+    pub fn execute_with_retries(self, max_retries: usize) -> Result<WebhookResponse> {
+        let client = self.client;
+        let (body, headers) = self.into_body()?;
+
+        let mut attempt = 0;
+        loop {
+            let response = client.send_dict(&body, headers.as_ref())?;
+            if !response.is_rate_limited() || attempt >= max_retries {
+                return Ok(response);
+            }
+            attempt += 1;
+            let wait = response.retry_after().unwrap_or(DEFAULT_RETRY_AFTER);
+            debug!(attempt, "Webhook rate limited; retrying after {:?}", wait);
+            std::thread::sleep(wait);
+        }
+    }
+
+    /// Builds the request body and extra headers.
+    fn into_body(
+        self,
+    ) -> Result<(
+        serde_json::Map<String, Value>,
+        Option<HashMap<String, String>>,
+    )> {
         let mut body = serde_json::Map::new();
 
         if let Some(text) = self.text {
             body.insert("text".to_string(), Value::String(text));
         }
-        if let Some(blocks) = self.blocks {
-            body.insert("blocks".to_string(), blocks);
+        if let Some(blocks) = checked_builder_blocks(&self.blocks)? {
+            validate_block_values(blocks)?;
+            body.insert("blocks".to_string(), blocks.clone());
         }
         if let Some(attachments) = self.attachments {
             body.insert("attachments".to_string(), attachments);
@@ -398,8 +665,17 @@ impl<'a> SendMessageBuilder<'a> {
         if let Some(metadata) = self.metadata {
             body.insert("metadata".to_string(), metadata);
         }
+        insert_legacy_overrides(
+            &mut body,
+            [
+                ("channel", self.channel),
+                ("username", self.username),
+                ("icon_emoji", self.icon_emoji),
+                ("icon_url", self.icon_url),
+            ],
+        );
 
-        self.client.send_dict(&body, self.headers.as_ref())
+        Ok((body, self.headers))
     }
 }
 
@@ -413,10 +689,53 @@ pub struct AsyncWebhookClient {
     timeout: Duration,
     default_headers: HashMap<String, String>,
     proxy: Option<String>,
+    max_payload_bytes: Option<usize>,
     client: reqwest::Client,
 }
 
 impl AsyncWebhookClient {
+    /// Creates a new async webhook client, first checking that `url` is a
+    /// Slack hooks URL.
+    ///
+    /// Accepts `https://hooks.slack.com/...` and GovSlack's
+    /// `https://hooks.slack-gov.com/...` URLs, which covers incoming webhooks
+    /// (`/services/`) and response URLs (`/commands/`, `/actions/`). This
+    /// catches a mistyped or wrong URL before the first send rather than as
+    /// a 404 later. Use [`new`](Self::new) for webhooks served from a custom
+    /// domain, which this check would reject.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the URL is not on a Slack hooks
+    /// host.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use slack_rs::webhook::AsyncWebhookClient;
+    ///
+    /// assert!(AsyncWebhookClient::validated("https://hooks.slack.com/services/T00/B00/XXX").is_ok());
+    /// assert!(AsyncWebhookClient::validated("https://example.com/hook").is_err());
+    /// ```
+    pub fn validated(url: impl Into<String>) -> Result<Self> {
+        let url = url.into();
+        validate_webhook_url(&url, &default_hooks_urls())?;
+        Ok(Self::new(url))
+    }
+
+    /// Creates a new async webhook client, first checking that `url` is under
+    /// the hooks base URL of the given Slack environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SlackError::Validation` if the URL is not under
+    /// [`SlackEnvironment::hooks_url`].
+    pub fn validated_for(url: impl Into<String>, environment: &SlackEnvironment) -> Result<Self> {
+        let url = url.into();
+        validate_webhook_url(&url, &[environment.hooks_url()])?;
+        Ok(Self::new(url))
+    }
+
     /// Creates a new async webhook client.
     ///
     /// # Arguments
@@ -447,6 +766,7 @@ impl AsyncWebhookClient {
             timeout: Duration::from_secs(30),
             default_headers,
             proxy: None,
+            max_payload_bytes: None,
             client,
         }
     }
@@ -489,6 +809,15 @@ impl AsyncWebhookClient {
         self
     }
 
+    /// Rejects payloads whose serialized JSON exceeds `bytes`.
+    ///
+    /// Oversized payloads fail with `SlackError::Validation` before sending.
+    /// There is no limit by default.
+    pub fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = Some(bytes);
+        self
+    }
+
     /// Creates a new message builder.
     ///
     /// # Example
@@ -524,7 +853,10 @@ impl AsyncWebhookClient {
             request_headers.extend(h.clone());
         }
 
+        let payload = serialize_json_body(body, self.max_payload_bytes)?;
+
         let mut header_map = HeaderMap::new();
+        header_map.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         for (key, value) in request_headers {
             if let (Ok(name), Ok(val)) = (HeaderName::from_str(&key), HeaderValue::from_str(&value))
             {
@@ -536,7 +868,7 @@ impl AsyncWebhookClient {
             .client
             .post(&self.url)
             .headers(header_map)
-            .json(body)
+            .body(payload)
             .send()
             .await
             .map_err(SlackError::Http)?;
@@ -568,7 +900,7 @@ impl AsyncWebhookClient {
 pub struct AsyncSendMessageBuilder<'a> {
     client: &'a AsyncWebhookClient,
     text: Option<String>,
-    blocks: Option<Value>,
+    blocks: Option<std::result::Result<Value, String>>,
     attachments: Option<Value>,
     response_type: Option<String>,
     replace_original: Option<bool>,
@@ -576,6 +908,10 @@ pub struct AsyncSendMessageBuilder<'a> {
     unfurl_links: Option<bool>,
     unfurl_media: Option<bool>,
     metadata: Option<Value>,
+    channel: Option<String>,
+    username: Option<String>,
+    icon_emoji: Option<String>,
+    icon_url: Option<String>,
     headers: Option<HashMap<String, String>>,
 }
 
@@ -592,6 +928,10 @@ impl<'a> AsyncSendMessageBuilder<'a> {
             unfurl_links: None,
             unfurl_media: None,
             metadata: None,
+            channel: None,
+            username: None,
+            icon_emoji: None,
+            icon_url: None,
             headers: None,
         }
     }
@@ -604,9 +944,10 @@ impl<'a> AsyncSendMessageBuilder<'a> {
 
     /// Sets the blocks for the message.
     ///
-    /// Accepts either a `Vec<Block>` or raw JSON value.
+    /// Accepts either a `Vec<Block>` or raw JSON value. Blocks given as a
+    /// JSON-encoded string, e.g. copied from a fetched message, are decoded.
     pub fn blocks<T: Serialize>(mut self, blocks: T) -> Self {
-        self.blocks = serde_json::to_value(blocks).ok();
+        self.blocks = Some(builder_blocks(blocks));
         self
     }
 
@@ -652,6 +993,43 @@ impl<'a> AsyncSendMessageBuilder<'a> {
         self
     }
 
+    /// Overrides the channel a legacy incoming webhook posts to.
+    ///
+    /// Webhooks created by Slack apps always post to their configured
+    /// channel and silently ignore this, as they do the username and icon
+    /// overrides.
+    pub fn channel(mut self, channel: impl Into<String>) -> Self {
+        self.channel = Some(channel.into());
+        self
+    }
+
+    /// Overrides the display name of a legacy incoming webhook.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Overrides the icon of a legacy incoming webhook with an emoji, such
+    /// as `:ghost:`.
+    ///
+    /// Only one icon is sent: this replaces any [`icon_url`](Self::icon_url)
+    /// set earlier.
+    pub fn icon_emoji(mut self, icon_emoji: impl Into<String>) -> Self {
+        self.icon_emoji = Some(icon_emoji.into());
+        self.icon_url = None;
+        self
+    }
+
+    /// Overrides the icon of a legacy incoming webhook with an image URL.
+    ///
+    /// Only one icon is sent: this replaces any
+    /// [`icon_emoji`](Self::icon_emoji) set earlier.
+    pub fn icon_url(mut self, icon_url: impl Into<String>) -> Self {
+        self.icon_url = Some(icon_url.into());
+        self.icon_emoji = None;
+        self
+    }
+
     /// Adds a custom header for this request only.
     pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.headers
@@ -661,14 +1039,71 @@ impl<'a> AsyncSendMessageBuilder<'a> {
     }
 
     /// Executes the webhook request.
+    ///
+    /// Blocks are checked with
+    /// [`validate_blocks`](crate::models::blocks::validate_blocks) first.
     pub async fn execute(self) -> Result<WebhookResponse> {
+        let client = self.client;
+        let (body, headers) = self.into_body()?;
+        client.send_dict(&body, headers.as_ref()).await
+    }
+
+    /// Executes the webhook request, re-sending it up to `max_retries`
+    /// times while Slack responds with 429.
+    ///
+    /// Before each retry the task sleeps for the response's
+    /// [`retry_after`](WebhookResponse::retry_after), or one second if it
+    /// has none. The last response is returned, so a request still rate
+    /// limited after the final retry returns its 429 response.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use slack_rs::webhook::AsyncWebhookClient;
+    ///
+    /// # async fn example() -> slack_rs::error::Result<()> {
+    /// let client = AsyncWebhookClient::new("https://hooks.slack.com/services/T00/B00/XXX");
+    /// let response = client
+    ///     .send()
+    ///     .text("Deploy finished")
+    ///     .execute_with_retries(3)
+    ///     .await?;
+    /// assert!(response.is_success());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn execute_with_retries(self, max_retries: usize) -> Result<WebhookResponse> {
+        let client = self.client;
+        let (body, headers) = self.into_body()?;
+
+        let mut attempt = 0;
+        loop {
+            let response = client.send_dict(&body, headers.as_ref()).await?;
+            if !response.is_rate_limited() || attempt >= max_retries {
+                return Ok(response);
+            }
+            attempt += 1;
+            let wait = response.retry_after().unwrap_or(DEFAULT_RETRY_AFTER);
+            debug!(attempt, "Webhook rate limited; retrying after {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Builds the request body and extra headers.
+    fn into_body(
+        self,
+    ) -> Result<(
+        serde_json::Map<String, Value>,
+        Option<HashMap<String, String>>,
+    )> {
         let mut body = serde_json::Map::new();
 
         if let Some(text) = self.text {
             body.insert("text".to_string(), Value::String(text));
         }
-        if let Some(blocks) = self.blocks {
-            body.insert("blocks".to_string(), blocks);
+        if let Some(blocks) = checked_builder_blocks(&self.blocks)? {
+            validate_block_values(blocks)?;
+            body.insert("blocks".to_string(), blocks.clone());
         }
         if let Some(attachments) = self.attachments {
             body.insert("attachments".to_string(), attachments);
@@ -694,8 +1129,17 @@ impl<'a> AsyncSendMessageBuilder<'a> {
         if let Some(metadata) = self.metadata {
             body.insert("metadata".to_string(), metadata);
         }
+        insert_legacy_overrides(
+            &mut body,
+            [
+                ("channel", self.channel),
+                ("username", self.username),
+                ("icon_emoji", self.icon_emoji),
+                ("icon_url", self.icon_url),
+            ],
+        );
 
-        self.client.send_dict(&body, self.headers.as_ref()).await
+        Ok((body, self.headers))
     }
 }
 
@@ -1034,6 +1478,42 @@ mod tests {
         assert_eq!(response.status_code, 200);
     }
 
+    #[tokio::test]
+    async fn test_send_with_legacy_overrides() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({
+                "text": "hello!",
+                "channel": "#alerts",
+                "username": "deploy-bot",
+                "icon_url": "https://example.com/icon.png"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let uri = mock_server.uri();
+        let response = tokio::task::spawn_blocking(move || {
+            let client = WebhookClient::new(uri);
+            client
+                .send()
+                .text("hello!")
+                .channel("#alerts")
+                .username("deploy-bot")
+                .icon_emoji(":rocket:")
+                .icon_url("https://example.com/icon.png")
+                .execute()
+                .unwrap()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.status_code, 200);
+    }
+
     #[tokio::test]
     async fn test_send_with_custom_header() {
         let mock_server = MockServer::start().await;
@@ -1230,6 +1710,73 @@ mod tests {
         assert_eq!(response.body, "ok");
     }
 
+    #[tokio::test]
+    async fn test_async_send_decodes_blocks_string() {
+        let mock_server = MockServer::start().await;
+        let blocks = json!([{"type": "divider"}]);
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"blocks": blocks})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(mock_server.uri());
+        let response = client
+            .send()
+            .blocks(blocks.to_string())
+            .execute()
+            .await
+            .unwrap();
+
+        assert_eq!(response.status_code, 200);
+    }
+
+    #[tokio::test]
+    async fn test_async_send_passes_raw_blocks_through() {
+        let mock_server = MockServer::start().await;
+        // A field the crate's block types do not accept still reaches Slack
+        let blocks = json!([{"type": "section", "text": "plain string"}]);
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"blocks": blocks})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(mock_server.uri());
+        let response = client.send().blocks(blocks).execute().await.unwrap();
+
+        assert_eq!(response.status_code, 200);
+    }
+
+    #[tokio::test]
+    async fn test_async_send_reports_unserializable_blocks() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        // Maps with non-string keys cannot be serialized to JSON
+        let blocks = HashMap::from([((1, 2), "divider")]);
+        let client = AsyncWebhookClient::new(mock_server.uri());
+        let err = client
+            .send()
+            .text("hello!")
+            .blocks(blocks)
+            .execute()
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("blocks could not be serialized"));
+    }
+
     #[tokio::test]
     async fn test_async_send_with_attachments() {
         let mock_server = MockServer::start().await;
@@ -1287,6 +1834,61 @@ mod tests {
         assert_eq!(response.status_code, 200);
     }
 
+    #[tokio::test]
+    async fn test_async_send_with_legacy_overrides() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"text": "one", "channel": "C123"})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"text": "two", "username": "deploy-bot"})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"text": "three", "icon_emoji": ":ghost:"})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({
+                "text": "four",
+                "icon_url": "https://example.com/icon.png"
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(mock_server.uri());
+        let builders = [
+            client.send().text("one").channel("C123"),
+            client.send().text("two").username("deploy-bot"),
+            client
+                .send()
+                .text("three")
+                .icon_url("https://example.com/icon.png")
+                .icon_emoji(":ghost:"),
+            client
+                .send()
+                .text("four")
+                .icon_url("https://example.com/icon.png"),
+        ];
+        for builder in builders {
+            assert_eq!(builder.execute().await.unwrap().status_code, 200);
+        }
+    }
+
     #[tokio::test]
     async fn test_async_error_response() {
         let mock_server = MockServer::start().await;
@@ -1327,6 +1929,27 @@ mod tests {
         assert!(response.headers.contains_key("retry-after"));
     }
 
+    #[tokio::test]
+    async fn test_async_payload_too_large() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(mock_server.uri()).max_payload_bytes(32);
+        let result = client.send().text("x".repeat(64)).execute().await;
+
+        match result {
+            Err(SlackError::Validation(msg)) => {
+                assert!(msg.starts_with("payload too large: 75 bytes"))
+            }
+            other => panic!("Expected validation error, got {:?}", other),
+        }
+    }
+
     #[tokio::test]
     async fn test_async_send_dict() {
         let mock_server = MockServer::start().await;
@@ -1365,4 +1988,213 @@ mod tests {
         assert!(ua.ends_with("async-suffix"));
         assert!(ua.contains("slack-rs"));
     }
+
+    #[test]
+    fn test_validated_accepts_slack_hooks_urls() {
+        assert!(WebhookClient::validated("https://hooks.slack.com/services/T00/B00/XXX").is_ok());
+        assert!(WebhookClient::validated("https://hooks.slack.com/commands/T00/123/abc").is_ok());
+        assert!(
+            AsyncWebhookClient::validated("https://hooks.slack.com/actions/T00/123/abc").is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validated_rejects_other_urls() {
+        for url in [
+            "http://hooks.slack.com/services/T00/B00/XXX",
+            "https://hooks.slack.com/",
+            "https://hooks.slack.com.evil.example/services/T00/B00/XXX",
+            "https://slack.com/api/chat.postMessage",
+            "hooks.slack.com/services/T00/B00/XXX",
+        ] {
+            let err = WebhookClient::validated(url).unwrap_err();
+            assert!(
+                matches!(&err, SlackError::Validation(msg) if msg == "URL does not look like a Slack webhook URL"),
+                "{}",
+                url
+            );
+            assert!(AsyncWebhookClient::validated(url).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validated_for_matches_environment() {
+        let gov_url = "https://hooks.slack-gov.com/services/T00/B00/XXX";
+        let commercial_url = "https://hooks.slack.com/services/T00/B00/XXX";
+
+        assert!(WebhookClient::validated(gov_url).is_ok());
+        assert!(WebhookClient::validated_for(gov_url, &SlackEnvironment::Gov).is_ok());
+        assert!(AsyncWebhookClient::validated_for(gov_url, &SlackEnvironment::Gov).is_ok());
+        assert!(
+            WebhookClient::validated_for(commercial_url, &SlackEnvironment::Commercial).is_ok()
+        );
+
+        for (url, env) in [
+            (commercial_url, SlackEnvironment::Gov),
+            (gov_url, SlackEnvironment::Commercial),
+        ] {
+            assert!(matches!(
+                WebhookClient::validated_for(url, &env),
+                Err(SlackError::Validation(_))
+            ));
+            assert!(AsyncWebhookClient::validated_for(url, &env).is_err());
+        }
+    }
+
+    #[test]
+    fn test_webhook_response_display() {
+        let url = "https://hooks.slack.com/services/T000/B000/XXXX".to_string();
+        let ok = WebhookResponse::new(url.clone(), 200, "ok".to_string(), HashMap::new());
+        assert_eq!(ok.to_string(), "POST https://hooks.slack.com/... -> 200 ok");
+
+        let mut headers = HashMap::new();
+        headers.insert("retry-after".to_string(), "30".to_string());
+        let limited = WebhookResponse::new(url, 429, "rate_limited".to_string(), headers);
+        assert_eq!(
+            limited.to_string(),
+            "POST https://hooks.slack.com/... -> 429 rate_limited (retry-after: 30s)"
+        );
+    }
+
+    #[test]
+    fn test_webhook_response_display_truncates_body() {
+        let response = WebhookResponse::new(
+            "https://hooks.slack.com/services/T000/B000/XXXX".to_string(),
+            400,
+            format!("invalid_token xoxb-1-2-abc {}", "x".repeat(300)),
+            HashMap::new(),
+        );
+        let display = response.to_string();
+
+        assert!(display.starts_with(
+            "POST https://hooks.slack.com/... -> 400 invalid_token xoxb-[REDACTED] xxx"
+        ));
+        assert!(!display.contains("XXXX"));
+        assert!(display.ends_with('…'));
+    }
+
+    #[test]
+    fn test_retry_after_parsing() {
+        let response = |value: &str| {
+            let mut headers = HashMap::new();
+            headers.insert("retry-after".to_string(), value.to_string());
+            WebhookResponse::new(
+                "https://hooks.slack.com/test".to_string(),
+                429,
+                "rate_limited".to_string(),
+                headers,
+            )
+        };
+        let now = chrono::DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        assert_eq!(response("30").retry_after(), Some(Duration::from_secs(30)));
+        assert_eq!(
+            response("Wed, 21 Oct 2015 07:28:45 GMT").retry_after_at(now),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(
+            response("Wed, 21 Oct 2015 07:27:00 GMT").retry_after_at(now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(response("soon").retry_after(), None);
+
+        let no_header = WebhookResponse::new(
+            "https://hooks.slack.com/test".to_string(),
+            429,
+            String::new(),
+            HashMap::new(),
+        );
+        assert_eq!(no_header.retry_after(), None);
+    }
+
+    /// Mounts a webhook that rate-limits the first request and accepts the next.
+    async fn rate_limited_once(mock_server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "1")
+                    .set_body_string("rate_limited"),
+            )
+            .up_to_n_times(1)
+            .with_priority(1)
+            .expect(1)
+            .mount(mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .and(body_json(json!({"text": "hello!"})))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .expect(1)
+            .mount(mock_server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn test_execute_with_retries_waits_out_rate_limit() {
+        let mock_server = MockServer::start().await;
+        rate_limited_once(&mock_server).await;
+
+        let uri = mock_server.uri();
+        let started = std::time::Instant::now();
+        let response = tokio::task::spawn_blocking(move || {
+            WebhookClient::new(uri)
+                .send()
+                .text("hello!")
+                .execute_with_retries(2)
+                .unwrap()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(response.status_code, 200);
+        assert_eq!(response.body, "ok");
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_async_execute_with_retries_waits_out_rate_limit() {
+        let mock_server = MockServer::start().await;
+        rate_limited_once(&mock_server).await;
+
+        let started = std::time::Instant::now();
+        let response = AsyncWebhookClient::new(mock_server.uri())
+            .send()
+            .text("hello!")
+            .execute_with_retries(2)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status_code, 200);
+        assert!(started.elapsed() >= Duration::from_secs(1));
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_async_execute_with_retries_returns_last_rate_limit() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_string("rate_limited"),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let response = AsyncWebhookClient::new(mock_server.uri())
+            .send()
+            .text("hello!")
+            .execute_with_retries(2)
+            .await
+            .unwrap();
+
+        assert!(response.is_rate_limited());
+        assert_eq!(response.retry_after(), Some(Duration::ZERO));
+    }
 }
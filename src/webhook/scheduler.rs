@@ -0,0 +1,192 @@
+//! Periodic webhook delivery.
+//!
+//! Available with the `scheduler` feature. Useful for recurring messages such
+//! as daily standup reminders or hourly reports.
+
+use super::{AsyncSendMessageBuilder, AsyncWebhookClient};
+use std::future::Future;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::error;
+
+impl AsyncWebhookClient {
+    /// Spawns a task that sends a message every `interval`.
+    ///
+    /// The task sleeps for `interval`, then builds a message with `builder_fn`
+    /// and executes it. Failures are logged with `tracing::error!` and the
+    /// schedule continues. Abort the returned handle to stop the task.
+    ///
+    /// Must be called from within a Tokio runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use slack_rs::webhook::AsyncWebhookClient;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let client = AsyncWebhookClient::new("https://hooks.slack.com/services/T00/B00/XXX");
+    ///
+    /// let handle = client.send_periodic(Duration::from_secs(3600), |client| {
+    ///     client.send().text("Hourly report")
+    /// });
+    /// # handle.abort();
+    /// # }
+    /// ```
+    pub fn send_periodic<F>(&self, interval: Duration, builder_fn: F) -> JoinHandle<()>
+    where
+        F: for<'c> Fn(&'c AsyncWebhookClient) -> AsyncSendMessageBuilder<'c> + Send + 'static,
+    {
+        self.send_periodic_until(interval, builder_fn, std::future::pending())
+    }
+
+    /// Spawns a task that sends a message every `interval` until `shutdown` resolves.
+    ///
+    /// Behaves like [`send_periodic`](Self::send_periodic), but the task exits
+    /// cleanly once the `shutdown` future completes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use slack_rs::webhook::AsyncWebhookClient;
+    /// use std::time::Duration;
+    ///
+    /// # async fn example() {
+    /// let client = AsyncWebhookClient::new("https://hooks.slack.com/services/T00/B00/XXX");
+    /// let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+    ///
+    /// let handle = client.send_periodic_until(
+    ///     Duration::from_secs(86400),
+    ///     |client| client.send().text("Standup time!"),
+    ///     async move {
+    ///         let _ = rx.await;
+    ///     },
+    /// );
+    ///
+    /// // Later...
+    /// let _ = tx.send(());
+    /// handle.await.unwrap();
+    /// # }
+    /// ```
+    pub fn send_periodic_until<F, S>(
+        &self,
+        interval: Duration,
+        builder_fn: F,
+        shutdown: S,
+    ) -> JoinHandle<()>
+    where
+        F: for<'c> Fn(&'c AsyncWebhookClient) -> AsyncSendMessageBuilder<'c> + Send + 'static,
+        S: Future<Output = ()> + Send + 'static,
+    {
+        let client = self.clone();
+
+        tokio::spawn(async move {
+            tokio::pin!(shutdown);
+
+            loop {
+                tokio::select! {
+                    _ = &mut shutdown => break,
+                    _ = tokio::time::sleep(interval) => {}
+                }
+
+                match builder_fn(&client).execute().await {
+                    Ok(response) if !response.is_success() => {
+                        error!(
+                            url = %response.url,
+                            status = response.status_code,
+                            body = %response.body,
+                            "Periodic webhook delivery failed"
+                        );
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        error!(url = %client.url, error = %e, "Periodic webhook delivery failed");
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test]
+    async fn test_send_periodic_sends_repeatedly() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(format!("{}/webhook", mock_server.uri()));
+        let handle = client.send_periodic(Duration::from_millis(20), |client| {
+            client.send().text("tick")
+        });
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        handle.abort();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.len() >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_periodic_continues_after_failure() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("error"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(format!("{}/webhook", mock_server.uri()));
+        let handle = client.send_periodic(Duration::from_millis(20), |client| {
+            client.send().text("tick")
+        });
+
+        tokio::time::sleep(Duration::from_millis(150)).await;
+        assert!(!handle.is_finished());
+        handle.abort();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.len() >= 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_periodic_until_stops_on_shutdown() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/webhook"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("ok"))
+            .mount(&mock_server)
+            .await;
+
+        let client = AsyncWebhookClient::new(format!("{}/webhook", mock_server.uri()));
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+
+        let handle = client.send_periodic_until(
+            Duration::from_secs(3600),
+            |client| client.send().text("never sent"),
+            async move {
+                let _ = rx.await;
+            },
+        );
+
+        tx.send(()).unwrap();
+        tokio::time::timeout(Duration::from_secs(1), handle)
+            .await
+            .expect("task should stop after shutdown")
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(requests.is_empty());
+    }
+}